@@ -0,0 +1,486 @@
+# Everything the player reads, one message per id. Chat lines can use the same markup
+# as anywhere else: [gold]...[/gold], [b]...[/b] and icons, which have to be written as
+# {"{money}"} since braces belong to Fluent here.
+
+## Battle
+
+battle-palette-prompt = Everything at once, what will it be
+battle-action-prompt = How do thy wish to occupy thine time
+battle-weapon-prompt = Once more into the frey, choose thy means of destruction
+battle-item-prompt = You dig through your pack for something useful
+battle-command-prompt = Your dog looks up at you expectantly
+battle-target-prompt = Who is the unfortunate soul to bear the brunt of your fury
+battle-location-prompt = Whereabouts
+battle-dog-ignores = You tell your dog to { $command }, it looks at you and does nothing
+battle-dog-barks = Your dog barks up a storm, { $enemy } freezes in place
+battle-dog-fetches = Your dog drags back { $loot }
+battle-dog-stick = Your dog comes back with a stick
+battle-use-item = You use the { $item }
+battle-smoke = By the time the smoke clears, you are long gone
+battle-drops = { $enemy } drops { $loot }
+battle-hit = Using your { $weapon } to deal [gold]{ $damage }[/gold] damage to { $enemy }, it remains steadfast
+battle-kill = With your { $weapon } you rob { $enemy } of it's life
+battle-out-of-resources = Try as you might, your resources ran dry before the crescendo
+battle-out-of-range = Nobody in range for that I'm afraid
+battle-flee-success = Rolled { $roll } against { $target }, you slip away while nobody is looking
+battle-flee-failure = Rolled { $roll } against { $target }, there is no getting away this time
+battle-parley-refused = Talk is cheap, and so are you. They won't hear of it
+battle-stunned = { $enemy } is still shaking off the scare
+battle-creeps-closer = { $enemy } creeps closer
+battle-dog-struck-down = { $enemy } strikes down your dog with the { $weapon }
+battle-dog-hurt = { $enemy } goes for your dog with the { $weapon }, dealing [red]{ $damage }[/red] damage
+battle-killed = { $enemy } takes your life with the { $weapon }
+battle-hurt = { $enemy } uses { $weapon } to deal [red]{ $damage }[/red] damage
+battle-unarmed = { $enemy } seems unarmed, but far from dangerous
+battle-spoils = You gather the spoils: { $loot }
+battle-no-room = No room for { $loot }, you leave it behind
+
+enemy-mystery-foe = Mystery foe
+weapon-element-of-surprise = Element of surprise
+
+palette-move = move { $place }
+palette-attack = attack { $enemy } with { $weapon }
+palette-use = use { $item }
+palette-command = tell your dog to { $command }
+
+weapon-sawn-off-shotgun = Sawn-off shotgun
+weapon-cattle-prod = Cattle prod
+weapon-trusty-sidearm = Trusty sidearm
+weapon-trusty-taser = Still somewhat trusty taser
+weapon-knuckle-sandwich = Knuckle sandwich
+
+## What the player can do
+
+action-move = move
+action-attack = attack
+action-wait = wait
+action-use-item = use item
+action-command = command dog
+action-flee = flee
+action-parley = parley
+action-surrender = surrender
+place-left = left
+place-right = right
+command-bark = bark
+command-fetch = fetch
+
+## Resources
+
+resources-stamina = { $amount } stamina
+resources-money = { $amount } money
+resources-bullets = { $amount } { $amount ->
+        [one] bullet
+       *[other] bullets
+    }
+resources-batteries = { $amount } batteries
+
+## Inventory
+
+inventory-prompt = You rummage through your pack ({ $count }/{ $limit })
+inventory-close = Close
+inventory-weapon = { $weapon } deals { $damage } damage at range { $range }
+inventory-use-it = Use it
+inventory-keep = Keep it
+inventory-drop = Leave it behind
+inventory-use = You use the { $thing }
+inventory-leave = You leave the { $thing } behind
+inventory-empty-handed = Going out there empty handed would be madness
+
+item-bandages = Bandages
+item-jerky = Jerky
+item-smoke-bomb = Smoke bomb
+item-dynamite = Dynamite
+effect-restore = restores { $resources }
+effect-feed = a meal, restores { $stamina } stamina
+effect-smoke = gets you out of any fight
+effect-explode = deals { $damage } damage to every foe
+
+## Shops
+
+shop-road-merchant = The merchant opens his coat to reveal trinkets and baubles of all sorts
+shop-general-store = You see a strangely familiar trader in his shack
+shop-prompt = What'll it be?
+shop-buy = Buy
+shop-sell = Sell
+shop-leave = Leave
+shop-back = Back
+shop-buy-prompt = Take your pick
+shop-sell-prompt = What are you offering?
+shop-entry = { $thing } for { $price }
+shop-entry-sold-out = { $thing } for { $price } (sold out)
+shop-entry-too-dear = { $thing } for { $price } (can't afford)
+shop-bought = You buy the { $thing }
+shop-unpaid = The shopkeeper puts it back on the shelf
+shop-sold-out = Fresh out of those, I'm afraid
+shop-pack-full = Your pack is full, you'd have to leave something behind
+shop-too-dear = You can't afford that, not even on a lucky roll
+shop-sold = You part with the { $thing } for { $offer }
+
+shop-bullets = Bullets, 6 of them
+shop-food = Food, 4 points of stamina
+shop-mystery-box = Mystery box
+shop-battery-box = Battery box
+
+## Paying up
+
+pay-cash = hand over cash
+pay-die = roll a die
+pay-back-out = back out
+pay-price-rolled = The price comes to { $price } ({ $dice } rolled)
+pay-price = The price is { $price }
+pay-prompt = { $owed } still owed, you have { $cash } cash and { $dice } dice
+pay-handed-over = You hand over { $amount } in cash
+pay-rolled = You roll a { $roll }
+pay-backed-out = You back out of the deal
+pay-short = You come up { $owed } short and take your cash back
+pay-forfeit = The { $dice ->
+        [one] die you rolled stays
+       *[other] { $dice } dice you rolled stay
+    } on the table
+receipt-paid = { $paid } of { $price } paid with { $spent }
+receipt-with-change = { $paid } of { $price } paid with { $spent }, { $change } back in change
+receipt-cash = { $amount } in cash
+receipt-die = a rolled { $roll }
+receipt-nothing = nothing
+loss-money = Your money: { $receipt }
+loss-all-money = Your money: { $receipt }, that was all of it
+loss-batteries = Your batteries: { $receipt }
+loss-all-batteries = Your batteries: { $receipt }, that was all of it
+
+## Games of chance
+
+minigame-craps = craps
+minigame-liars-dice = liar's dice
+minigame-revolver = the revolver game
+minigame-stake = The stake is { $stake }
+minigame-put-up = Put it up
+minigame-walk-away = Walk away
+minigame-walked-away = You leave the table to braver souls
+minigame-cannot-cover = You can't cover the stake, the table laughs you off
+minigame-won = You win { $winnings } at { $game }
+minigame-lost = The house takes your stake of { $stake }
+craps-rules = 7 or 11 wins, 2, 3 or 12 loses. Anything else is your point, hit it again before a 7
+craps-prompt = The dice are yours
+craps-throw-option = Throw
+craps-throw = You throw a { $first } and a { $second }
+craps-point = Your point is { $point }
+liars-rules = Five dice each, bid on how many of a face are on the table. Call a liar or raise
+liars-prompt = Your dice: { $dice }
+liars-call = Call liar
+liars-raise = Bid { $bid }
+liars-bid = { $quantity } { $face }s
+liars-peek = You peek at your dice: { $dice }
+liars-house-opens = The house opens with { $bid }
+liars-you-bid = You bid { $bid }
+liars-house-bids = The house bids { $bid }
+liars-called = You call it. The house had { $dice }
+liars-house-calls = "Liar!" The house had { $dice }
+revolver-rules = One shell of rock salt, six chambers. Take turns until somebody flinches
+revolver-prompt = { $chambers } chambers left
+revolver-pull = Pull the trigger
+revolver-fold = Fold
+revolver-folded = You set the revolver down. Nobody thinks less of you
+revolver-shot = BANG! The salt bites deep
+revolver-click = Click. Your turn is over
+revolver-house-folds = The house looks at the odds and folds
+revolver-house-shot = BANG! The house tumbles off its chair
+revolver-house-click = Click. The house slides the revolver back to you
+
+## The road
+
+travel-clock = Day { $day }, { $time }, { $weather }
+travel-status = { $clock } | Stop { $stop }/{ $stops }
+travel-upkeep = The road takes { $stamina } stamina out of you
+travel-exhausted = You're bone tired, a night by the fire would do wonders
+travel-hungry = Your stomach growls, the road is harder on an empty belly
+travel-set-off = You set off for the { $place }
+travel-weather = The weather turns, { $weather } ahead
+travel-looking-for-trouble = You start looking for trouble
+travel-new-road = Beyond the town, a new stretch of road unfolds
+travel-rest-prompt = The road stretches on. Next leg costs { $stamina } stamina
+travel-route-prompt = Which way? Press M for the map
+travel-towards = Towards the { $place } ({ $stop } on the map)
+rest-press-on = press on
+rest-breather = rest a while
+rest-camp = make camp
+rest-recovered-press-on = You press on and recover { $stamina } stamina
+rest-recovered-breather = You rest a while and recover { $stamina } stamina
+rest-recovered-camp = You make camp and recover { $stamina } stamina
+rest-meal = You eat the { $food } by the fire
+rest-no-meal = Nothing to eat tonight, you go to sleep hungry
+place-town = Town
+place-wilds = Wilds
+place-trading-post = Trading post
+place-badlands = Badlands
+weather-clear = clear skies
+weather-rain = rain
+weather-dust = dust storm
+weather-heat = scorching heat
+time-morning = morning
+time-afternoon = afternoon
+time-evening = evening
+time-night = night
+event-tumbleweed = A tumbleweed rolls by. That's it, that's the event
+event-dropped-die = Something glints in the dirt, a die somebody dropped
+event-clear = Not a cloud in sight, the walking is easy
+event-rain = Rain seeps into your pack and shorts out a battery
+event-dust = The storm uncovers a live round half buried in the sand
+event-heat = The sun beats down without mercy
+event-morning = Dew on the cactus. You drink your fill
+event-afternoon = A hawk circles overhead, then loses interest
+event-night = Coyotes howl in the dark and keep you on edge
+
+## Menus
+
+game-title = Pachinko and the cub
+menu-prompt = Saddle up?
+menu-new-game = New game
+menu-continue = Continue
+menu-settings = Settings
+menu-quit = Quit
+menu-continued = You pick up where you left off
+menu-paused = Paused
+menu-resume = Resume
+menu-quit-to-menu = Quit to main menu
+menu-quit-game = Quit game
+menu-left-the-road = You leave the road for now, it'll wait at the last crossroads
+menu-game-over = The dice have stopped rolling
+menu-ride-again = Ride again
+menu-main-menu = Main menu
+menu-ride-again-line = A new day, a new cowboy, the same old road
+settings-pace = Travel pace: { $pace }
+settings-road-events = Road events: { $frequency }
+settings-text-speed = Text speed: { $speed }
+settings-continue = Continue between lines: { $setting }
+settings-language = Language: { $language }
+settings-controls = Controls
+settings-back = Back
+settings-on = on
+settings-off = off
+pace-brisk = brisk
+pace-steady = steady
+pace-leisurely = leisurely
+road-events-rare = rare
+road-events-occasional = occasional
+road-events-frequent = frequent
+text-speed-slow = slow
+text-speed-normal = normal
+text-speed-fast = fast
+text-speed-instant = instant
+
+## Controls
+
+controls-prompt = Controls
+controls-choosing = Choosing
+controls-screens = Screens
+controls-road = Road and chat
+controls-reset = Reset to defaults
+controls-back = Back
+controls-group-prompt = { $group }, rebind which?
+controls-reset-done = The controls are back to how they came
+controls-listening = Press the new key or button for { $action }, or one it already has to keep it
+controls-taken = { $binding } is already used for { $action }
+controls-reserved = { $binding } can't be rebound
+controls-bound = { $action }: { $bindings }
+controls-unbound = { $action }: unbound
+input-prev = Previous option
+input-next = Next option
+input-confirm = Confirm
+input-back = Back
+input-pause = Pause
+input-scroll-up = Scroll up
+input-scroll-down = Scroll down
+input-journal = Journal
+input-inventory = Inventory
+input-map = Map
+input-hurry = Hurry along
+input-skip = Skip ahead
+input-palette = Command palette
+input-point = Point at option { $option }
+input-choose = Pick option { $option }
+
+## The top bar and chat
+
+stat-line = { $label }: { $value }
+stat-exhausted = (exhausted)
+stat-stamina = Stamina
+stat-money = Money
+stat-bullets = Bullets
+stat-batteries = Batteries
+stat-noun-stamina = stamina
+stat-noun-money = money
+stat-noun-bullets = bullets
+stat-noun-batteries = batteries
+tooltip-dice = { $dice } { $stat }: anywhere from { $lowest } to { $highest }, { $average } on average
+tooltip-no-dice = { $count } { $stat }, no dice to roll
+tooltip-count = { $count } { $stat }
+top-dog = Dog: { $health } (trust { $trust })
+chat-more-below = More below, PageDown to catch up
+chat-click = click
+chat-continue = Press { $key } to continue
+journal-header = Journal, page { $page } of { $pages }. PageUp for older, PageDown for newer, J to close
+map-header = The road ahead, each stop with the stops it leads to and the one you're at in brackets. M to close
+speaker-cowboy = You
+speaker-dog = Your dog
+speaker-merchant = Merchant
+speaker-dandy = Fancy coat
+speaker-stranger = Stranger
+
+## Encounters
+
+encounter-trouble = Trouble has found you. Fight it or find a way out!
+encounter-pack-full = Your pack is full, you leave the { $thing } behind
+encounter-dog-finds = Your dog turns up { $loot }
+encounter-dog-finds-nothing = Your dog comes back with nothing but muddy paws
+
+weapon-jaws-that-bite = The Jaws that Bite
+weapon-claws-that-catch = The Claws that Catch
+enemy-gamma-wolf = Gamma wolf
+enemy-beta-wolf = Beta wolf
+enemy-alpha-wolf = Scientifically accurate alpha wolf
+enemy-getaway-car = Getaway car
+enemy-electric-sheep = Half sheep half machine
+weapon-hoofs-of-steel = Hoofs of steel
+weapon-philosophical-quandaries = Philosophical quandries
+weapon-pickaxe = Pickaxe
+enemy-thug = Thug
+weapon-brass-knuckles = Brass knuckles
+weapon-rusty-revolver = Rusty revolver
+enemy-ringleader = Ringleader
+
+gamblers-prompt = Pick your poison
+gamblers-craps = Craps, 5 a throw
+gamblers-liars-dice = Liar's dice, 10 a hand
+gamblers-revolver = The revolver, 10 a round
+gamblers-leave = Call it a night
+gamblers-intro = Lantern light and the rattle of dice spill out of a roadside tent
+gamblers-wave = Gamblers. They wave you in, eyeing your purse
+gamblers-outro = You stumble back out into the night
+
+town-store-leave = You leave, somehow feeling emptier than when you came in.
+town-bar = You head to the closest hole in the wall, looking for a drink
+town-bar-prompt = 10 dice points for a bit of relief
+town-bar-pay = Yeah that seems fair
+town-bar-drink = You feel like you were born yesterday
+town-bar-broke = Unfortunately, capitalism has been deeply rooted here and you need to pay
+town-bar-refuse = Eh, that's a bit steep
+town-bar-stay = You could stay here forever, maybe just one more day
+town-prompt = Where to?
+town-drink = Get a drink
+town-stock-up = Stock up
+town-head-out = head out
+town-arrive = You arrive in a sleepy town
+town-outro = This is a place worth visiting.
+
+chase-pursue = You pick up the pace and pursue
+chase-parley = You toss them a cut of your own. The driver tips his hat and floors it
+chase-surrender = Hands up. They empty your pockets and leave you coughing in the dust
+chase-won-quickly = You catch them before they can stash the loot, bags of it
+chase-won = You rummage the remains of the cart and find a fistful of [gold]dollars[/gold]
+chase-fled = The robbers are long gone by the time you dare to look back
+chase-surrendered = Robbing the robbers, what were you thinking
+chase-survived = Lighter in the pocket, but still breathing
+chase-intro = A getaway car kicks up dust as it almost runs you over
+chase-prompt = Pursue
+chase-rob = Rob the robbers!
+chase-let-go = Let them get away
+chase-gone = You watch as the car leaves
+
+test-intro = This is a test encounter
+test-prompt = Would you like to fight?
+test-fight = Hell yeah!
+test-avoid = Would rather not
+test-avoided = You avoid the pointless fight, have a money
+test-outro = This encounter is over
+
+wolves-attack = Wolves attack!
+wolves-near-death = You lick your wounds. One more bite and that would have been it
+
+sheep-intro = A herd of electric sheep are barreling at you
+sheep-prompt = What to do in time of crisis?
+sheep-fight = Stand your ground
+sheep-count = Attempt to count them
+sheep-counted = You start feeling drowsy, briefly dream of androids and the herd tramples you
+sheep-spook = Spook them with your gun (6{"{bullet}"})
+sheep-spooked = You rattle a few shots into the air, they seem unimpressed
+sheep-no-bullets = You think of shooting, but don't have enough bullets. They seem to ignore you nonetheless.
+sheep-salvage = You salvage what you can from the wreckage
+sheep-outro = The semimechanical bovine have been dealt with, but at what cost
+
+merchant-intro = You see a merchant travelling down the road
+merchant-howdy = Howdy, stranger. Fine day for the road
+merchant-prompt = Interested in trade, are we?
+merchant-trade = Sure, why not.
+merchant-decline = Maybe some other time.
+merchant-shrugs = The merchant shrugs.
+merchant-outro = The merchant continues his journey.
+
+prospector-share = You hand over some of your rations
+prospector-pickaxe = He insists you take his old pickaxe, says he struck all the gold he'll ever need
+prospector-jerky = And a strip of jerky for the road
+prospector-tinker = He files down the sights of your sidearm. It feels truer now
+prospector-quick = He's quicker than he looks
+prospector-snatch = He snatches your taser and disappears into the night
+prospector-intro = An old prospector sits alone by his campfire
+prospector-prompt = He eyes you and your gear
+prospector-meal = Share a meal
+prospector-ask = Ask him to look at your sidearm
+prospector-rob = Rob him
+prospector-outro = You leave the prospector to his fire
+
+dog-buyer-refuse = He's not for sale
+dog-buyer-insist = I have to insist
+dog-buyer-loyal = Your dog presses against your leg
+dog-buyer-paid = He presses a handful of [gold]dice[/gold] into your palm
+dog-buyer-dragged = Your dog looks back at you as it's dragged away
+dog-buyer-regret = Shit... You forgot you had to take care of the dog
+dog-buyer-back = Your dog comes back to you, warily
+dog-buyer-whimpers = *whimpers*
+dog-buyer-intro = A man in a fancy coat looks your dog up and down
+dog-buyer-prompt = Yo, would you sell the dog for a handful?
+dog-buyer-no = No
+dog-buyer-yes = Yes
+
+scent-intro = Your dog stops dead in its tracks, nose to the ground
+scent-woof = Woof!
+scent-prompt = It has picked up a scent
+scent-follow = Let it lead the way
+scent-followed = You follow your dog off the trail
+scent-pull = Pull it along
+scent-pulled = Your dog whines, but follows
+
+intro-welcome = Welcome to the frontier. You are a cowboy in charge of a pupper
+intro-arrows = Use the arrow keys to navigate and space or enter to select
+intro-numbers = Number keys pick an option outright, and the mouse or a gamepad work too
+intro-inventory = Press I while travelling to rummage through your pack
+intro-map = Press M while travelling to look at the map
+intro-chat = PageUp and PageDown scroll the chat, J opens your journal
+intro-hurry = Hold F to hurry along the road, or press S to skip ahead
+intro-battle = In a fight, Backspace takes back a choice and Tab lists every move
+intro-skip-chat = Space or a click hurries the chat along when it's talking
+intro-rebind = Any of these can be rebound under Settings, Controls
+intro-adventure = Adventure awaits
+intro-prompt = Start your adventure?
+intro-simple-yes = Simple yes
+intro-simple-yes-reply = That's the sprit!
+intro-sarcastic-yes = Sarcastic yes
+intro-sarcastic-yes-reply = Aren't you a rascal! Too bad you have no agency
+intro-no-yes = No but actually yes
+intro-no-yes-reply = Denying the quest, how heroic. Too bad there is a game to be played.
+intro-confused = I got confused
+intro-confused-reply = Yeah sometimes it be like that. Hopefully you'll figure it out
+intro-onwards = Onwards!
+
+death-comes = Death comes to all. And did any of it [b]matter[/b] in the end.
+death-dice-fall = Well, sometimes it that's how the dice fall. Can't win them all.
+death-win-any = Some people can't win them any.
+death-go = Hopefully you found some enjoyment out of this. But I have to go now.
+death-prompt = Wait what?
+death-who = Who are you?
+death-who-reply = Does it matter? Why? Do you really crave meaning that badly?
+death-cheers = Cheers
+death-cheers-reply = It was fun while it lasted
+death-nod = *Nod and tip your hat
+death-nod-reply = Pardner, *tips back
+death-confused = I'm still confused, even moreso than before
+death-confused-reply = I sincerely hope you figure it out
@@ -0,0 +1,487 @@
+# Kaikki mitä pelaaja lukee, viesti tunnusta kohden. Rivit voivat käyttää samaa merkintää
+# kuin muuallakin: [gold]...[/gold], [b]...[/b] ja kuvakkeet, jotka kirjoitetaan muodossa
+# {"{money}"}, koska aaltosulkeet kuuluvat täällä Fluentille.
+# Nimet ja esineet tulevat viesteihin perusmuodossa, joten lauseet on rakennettu sen mukaan.
+
+## Taistelu
+
+battle-palette-prompt = Kaikki kerralla, mitä saisi olla
+battle-action-prompt = Kuinka aiot aikasi käyttää
+battle-weapon-prompt = Vielä kerran rynnäkköön, valitse tuhon välineesi
+battle-item-prompt = Kaivelet repustasi jotain hyödyllistä
+battle-command-prompt = Koirasi katsoo sinua odottavasti
+battle-target-prompt = Kuka onneton sielu saa vihasi niskaansa
+battle-location-prompt = Mihin suuntaan
+battle-dog-ignores = Käsket koiraa: { $command }. Se katsoo sinua eikä tee mitään
+battle-dog-barks = Koirasi haukkuu kuin viimeistä päivää, { $enemy } jähmettyy paikalleen
+battle-dog-fetches = Koirasi raahaa takaisin: { $loot }
+battle-dog-stick = Koirasi palaa keppi suussaan
+battle-use-item = Käytät: { $item }
+battle-smoke = Kun savu hälvenee, olet jo kaukana
+battle-drops = { $enemy } pudottaa: { $loot }
+battle-hit = Ase { $weapon } tekee [gold]{ $damage }[/gold] vahinkoa. { $enemy } pysyy yhä pystyssä
+battle-kill = Ase { $weapon } vie hengen: { $enemy } kaatuu
+battle-out-of-resources = Yritit parhaasi, mutta varusteet loppuivat ennen huipennusta
+battle-out-of-range = Valitettavasti kukaan ei ole sen kantamalla
+battle-flee-success = Heitit { $roll }, tarvittiin { $target }. Livahdat pois kenenkään huomaamatta
+battle-flee-failure = Heitit { $roll }, tarvittiin { $target }. Tällä kertaa ei pääse karkuun
+battle-parley-refused = Puhe on halpaa, ja niin olet sinäkin. He eivät halua kuulla siitä
+battle-stunned = { $enemy } toipuu yhä säikähdyksestä
+battle-creeps-closer = { $enemy } hiipii lähemmäs
+battle-dog-struck-down = { $enemy } kaataa koirasi. Ase: { $weapon }
+battle-dog-hurt = { $enemy } käy koirasi kimppuun ja tekee [red]{ $damage }[/red] vahinkoa. Ase: { $weapon }
+battle-killed = { $enemy } vie henkesi. Ase: { $weapon }
+battle-hurt = { $enemy } tekee [red]{ $damage }[/red] vahinkoa. Ase: { $weapon }
+battle-unarmed = { $enemy } näyttää aseettomalta, muttei lainkaan vaarattomalta
+battle-spoils = Keräät saaliin: { $loot }
+battle-no-room = Ei tilaa, jätät jälkeesi: { $loot }
+
+enemy-mystery-foe = Arvoituksellinen vihollinen
+weapon-element-of-surprise = Yllätyksen voima
+
+palette-move = liiku { $place }
+palette-attack = hyökkää: { $enemy }, ase { $weapon }
+palette-use = käytä: { $item }
+palette-command = käske koiraa: { $command }
+
+weapon-sawn-off-shotgun = Katkaistu haulikko
+weapon-cattle-prod = Karjapiikki
+weapon-trusty-sidearm = Luotettava sivuase
+weapon-trusty-taser = Yhä jokseenkin luotettava etälamautin
+weapon-knuckle-sandwich = Nyrkkivoileipä
+
+## Mitä pelaaja voi tehdä
+
+action-move = liiku
+action-attack = hyökkää
+action-wait = odota
+action-use-item = käytä tavaraa
+action-command = käske koiraa
+action-flee = pakene
+action-parley = neuvottele
+action-surrender = antaudu
+place-left = vasemmalle
+place-right = oikealle
+command-bark = hauku
+command-fetch = nouda
+
+## Varat
+
+resources-stamina = { $amount } kestävyyttä
+resources-money = { $amount } rahaa
+resources-bullets = { $amount } { $amount ->
+        [one] luoti
+       *[other] luotia
+    }
+resources-batteries = { $amount } paristoa
+
+## Reppu
+
+inventory-prompt = Penkot reppuasi ({ $count }/{ $limit })
+inventory-close = Sulje
+inventory-weapon = { $weapon }: { $damage } vahinkoa, kantama { $range }
+inventory-use-it = Käytä
+inventory-keep = Pidä
+inventory-drop = Jätä se
+inventory-use = Käytät: { $thing }
+inventory-leave = Jätät jälkeesi: { $thing }
+inventory-empty-handed = Lähteä tuonne tyhjin käsin olisi hulluutta
+
+item-bandages = Siteet
+item-jerky = Kuivalihaa
+item-smoke-bomb = Savupommi
+item-dynamite = Dynamiittia
+effect-restore = palauttaa { $resources }
+effect-feed = ateria, palauttaa { $stamina } kestävyyttä
+effect-smoke = vie sinut pois mistä tahansa taistelusta
+effect-explode = tekee { $damage } vahinkoa jokaiselle viholliselle
+
+## Kaupat
+
+shop-road-merchant = Kauppias avaa takkinsa ja paljastaa kaikenlaista rihkamaa ja koruja
+shop-general-store = Näet oudon tutun kauppiaan hökkelissään
+shop-prompt = Mitä saisi olla?
+shop-buy = Osta
+shop-sell = Myy
+shop-leave = Lähde
+shop-back = Takaisin
+shop-buy-prompt = Valitse mieleisesi
+shop-sell-prompt = Mitä tarjoat?
+shop-entry = { $thing }, hinta { $price }
+shop-entry-sold-out = { $thing }, hinta { $price } (loppuunmyyty)
+shop-entry-too-dear = { $thing }, hinta { $price } (ei varaa)
+shop-bought = Ostat: { $thing }
+shop-unpaid = Kauppias nostaa sen takaisin hyllylle
+shop-sold-out = Ne ovat valitettavasti loppu
+shop-pack-full = Reppusi on täynnä, sinun pitäisi jättää jotain jälkeesi
+shop-too-dear = Sinulla ei ole siihen varaa, ei edes onnekkaalla heitolla
+shop-sold = Luovut tavarasta { $thing }, saat { $offer }
+
+shop-bullets = Luoteja, kuusi kappaletta
+shop-food = Ruokaa, 4 pistettä kestävyyttä
+shop-mystery-box = Arvoituslaatikko
+shop-battery-box = Paristolaatikko
+
+## Maksaminen
+
+pay-cash = anna käteistä
+pay-die = heitä noppaa
+pay-back-out = peräänny
+pay-price-rolled = Hinnaksi tulee { $price } (heitettiin { $dice })
+pay-price = Hinta on { $price }
+pay-prompt = Velkaa vielä { $owed }, sinulla on { $cash } käteistä ja { $dice } noppaa
+pay-handed-over = Annat { $amount } käteisenä
+pay-rolled = Heität { $roll }
+pay-backed-out = Vetäydyt kaupasta
+pay-short = Jäät { $owed } vajaaksi ja otat käteisesi takaisin
+pay-forfeit = { $dice ->
+        [one] Heittämäsi noppa jää
+       *[other] Heittämäsi { $dice } noppaa jää
+    } pöydälle
+receipt-paid = { $paid }/{ $price } maksettu: { $spent }
+receipt-with-change = { $paid }/{ $price } maksettu: { $spent }, vaihtorahaa { $change }
+receipt-cash = { $amount } käteisenä
+receipt-die = heitetty { $roll }
+receipt-nothing = ei mitään
+loss-money = Rahasi: { $receipt }
+loss-all-money = Rahasi: { $receipt }, siinä olivat kaikki
+loss-batteries = Paristosi: { $receipt }
+loss-all-batteries = Paristosi: { $receipt }, siinä olivat kaikki
+
+## Uhkapelit
+
+minigame-craps = craps
+minigame-liars-dice = valehtelijan noppa
+minigame-revolver = revolveripeli
+minigame-stake = Panos on { $stake }
+minigame-put-up = Pane likoon
+minigame-walk-away = Kävele pois
+minigame-walked-away = Jätät pöydän rohkeammille
+minigame-cannot-cover = Et pysty kattamaan panosta, pöytä nauraa sinut ulos
+minigame-won = Voitat { $winnings }, peli: { $game }
+minigame-lost = Talo vie { $stake } panoksesi
+craps-rules = 7 tai 11 voittaa, 2, 3 tai 12 häviää. Muu luku on pisteesi, heitä se uudestaan ennen seiskaa
+craps-prompt = Nopat ovat sinun
+craps-throw-option = Heitä
+craps-throw = Heität { $first } ja { $second }
+craps-point = Pisteesi on { $point }
+liars-rules = Viisi noppaa kullakin, huuda montako tiettyä silmälukua pöydällä on. Syytä valheesta tai korota
+liars-prompt = Noppasi: { $dice }
+liars-call = Syytä valheesta
+liars-raise = Huuda { $bid }
+liars-bid = { $quantity } × { $face }
+liars-peek = Kurkistat noppiasi: { $dice }
+liars-house-opens = Talo aloittaa: { $bid }
+liars-you-bid = Huudat { $bid }
+liars-house-bids = Talo huutaa { $bid }
+liars-called = Syytät valheesta. Talolla oli { $dice }
+liars-house-calls = "Valehtelija!" Talolla oli { $dice }
+revolver-rules = Yksi vuorisuolapatruuna, kuusi pesää. Vuoroja jatketaan, kunnes joku säpsähtää
+revolver-prompt = Pesiä jäljellä { $chambers }
+revolver-pull = Vedä liipaisimesta
+revolver-fold = Luovuta
+revolver-folded = Lasket revolverin pöytään. Kukaan ei ajattele sinusta huonommin
+revolver-shot = PAM! Suola puree syvälle
+revolver-click = Naks. Vuorosi on ohi
+revolver-house-folds = Talo katsoo todennäköisyyksiä ja luovuttaa
+revolver-house-shot = PAM! Talo kaatuu tuoliltaan
+revolver-house-click = Naks. Talo liu'uttaa revolverin takaisin sinulle
+
+## Tie
+
+travel-clock = Päivä { $day }, { $time }, { $weather }
+travel-status = { $clock } | Pysähdys { $stop }/{ $stops }
+travel-upkeep = Tie vie sinusta { $stamina } kestävyyttä
+travel-exhausted = Olet lopen uupunut, yö nuotion äärellä tekisi ihmeitä
+travel-hungry = Vatsasi kurnii, tie käy raskaammaksi tyhjin vatsoin
+travel-set-off = Lähdet kohti määränpäätä: { $place }
+travel-weather = Sää kääntyy, edessä { $weather }
+travel-looking-for-trouble = Alat etsiä hankaluuksia
+travel-new-road = Kaupungin takana aukeaa uusi tienpätkä
+travel-rest-prompt = Tie jatkuu. Seuraava osuus vie { $stamina } kestävyyttä
+travel-route-prompt = Mihin päin? Paina M nähdäksesi kartan
+travel-towards = Kohti: { $place } (kartalla { $stop })
+rest-press-on = jatka matkaa
+rest-breather = lepää hetki
+rest-camp = leiriydy
+rest-recovered-press-on = Jatkat matkaa ja saat takaisin { $stamina } kestävyyttä
+rest-recovered-breather = Lepäät hetken ja saat takaisin { $stamina } kestävyyttä
+rest-recovered-camp = Leiriydyt ja saat takaisin { $stamina } kestävyyttä
+rest-meal = Syöt nuotion ääressä: { $food }
+rest-no-meal = Tänä iltana ei ole mitään syötävää, menet nukkumaan nälkäisenä
+place-town = Kaupunki
+place-wilds = Erämaa
+place-trading-post = Kauppapaikka
+place-badlands = Autiomaa
+weather-clear = selkeää
+weather-rain = sadetta
+weather-dust = hiekkamyrsky
+weather-heat = paahtavaa kuumuutta
+time-morning = aamu
+time-afternoon = iltapäivä
+time-evening = ilta
+time-night = yö
+event-tumbleweed = Pallohyppyrä vierii ohi. Siinä se, se oli tapahtuma
+event-dropped-die = Jokin kimaltaa hiekassa, joku on pudottanut nopan
+event-clear = Ei pilveä taivaalla, kävely käy kevyesti
+event-rain = Sade tihkuu reppuusi ja oikosulkee pariston
+event-dust = Myrsky paljastaa hiekkaan puoliksi hautautuneen patruunan
+event-heat = Aurinko paahtaa armotta
+event-morning = Kastetta kaktuksella. Juot kyllältäsi
+event-afternoon = Haukka kaartelee yläpuolella ja menettää sitten kiinnostuksensa
+event-night = Kojootit ulvovat pimeässä ja pitävät sinut varpaillasi
+
+## Valikot
+
+game-title = Pachinko ja pentu
+menu-prompt = Satuloidaanko?
+menu-new-game = Uusi peli
+menu-continue = Jatka
+menu-settings = Asetukset
+menu-quit = Lopeta
+menu-continued = Jatkat siitä mihin jäit
+menu-paused = Tauko
+menu-resume = Jatka
+menu-quit-to-menu = Palaa päävalikkoon
+menu-quit-game = Lopeta peli
+menu-left-the-road = Jätät tien toistaiseksi, se odottaa viimeisellä risteyksellä
+menu-game-over = Nopat ovat lakanneet vierimästä
+menu-ride-again = Ratsasta uudestaan
+menu-main-menu = Päävalikko
+menu-ride-again-line = Uusi päivä, uusi cowboy, sama vanha tie
+settings-pace = Matkavauhti: { $pace }
+settings-road-events = Tapahtumat tiellä: { $frequency }
+settings-text-speed = Tekstin nopeus: { $speed }
+settings-continue = Jatka rivien välillä: { $setting }
+settings-language = Kieli: { $language }
+settings-controls = Ohjaimet
+settings-back = Takaisin
+settings-on = päällä
+settings-off = pois
+pace-brisk = reipas
+pace-steady = tasainen
+pace-leisurely = verkkainen
+road-events-rare = harvoin
+road-events-occasional = silloin tällöin
+road-events-frequent = usein
+text-speed-slow = hidas
+text-speed-normal = normaali
+text-speed-fast = nopea
+text-speed-instant = välitön
+
+## Ohjaimet
+
+controls-prompt = Ohjaimet
+controls-choosing = Valitseminen
+controls-screens = Näkymät
+controls-road = Tie ja keskustelu
+controls-reset = Palauta oletukset
+controls-back = Takaisin
+controls-group-prompt = { $group }, mikä vaihdetaan?
+controls-reset-done = Ohjaimet ovat taas niin kuin ne tulivat
+controls-listening = Paina uutta näppäintä tai nappia toiminnolle { $action }, tai jo asetettua pitääksesi sen
+controls-taken = { $binding } on jo käytössä: { $action }
+controls-reserved = { $binding } ei ole vaihdettavissa
+controls-bound = { $action }: { $bindings }
+controls-unbound = { $action }: ei asetettu
+input-prev = Edellinen vaihtoehto
+input-next = Seuraava vaihtoehto
+input-confirm = Vahvista
+input-back = Takaisin
+input-pause = Tauko
+input-scroll-up = Vieritä ylös
+input-scroll-down = Vieritä alas
+input-journal = Päiväkirja
+input-inventory = Reppu
+input-map = Kartta
+input-hurry = Kiirehdi
+input-skip = Hyppää eteenpäin
+input-palette = Komentopaletti
+input-point = Osoita vaihtoehtoa { $option }
+input-choose = Valitse vaihtoehto { $option }
+
+## Yläpalkki ja keskustelu
+
+stat-line = { $label }: { $value }
+stat-exhausted = (uupunut)
+stat-stamina = Kestävyys
+stat-money = Raha
+stat-bullets = Luodit
+stat-batteries = Paristot
+stat-noun-stamina = kestävyyttä
+stat-noun-money = rahaa
+stat-noun-bullets = luotia
+stat-noun-batteries = paristoa
+tooltip-dice = { $dice } { $stat }: mitä tahansa väliltä { $lowest }–{ $highest }, keskimäärin { $average }
+tooltip-no-dice = { $count } { $stat }, ei heitettäviä noppia
+tooltip-count = { $count } { $stat }
+top-dog = Koira: { $health } (luottamus { $trust })
+chat-more-below = Lisää alempana, PageDown vie perille
+chat-click = napsauta
+chat-continue = Jatka: { $key }
+journal-header = Päiväkirja, sivu { $page }/{ $pages }. PageUp vanhempiin, PageDown uudempiin, J sulkee
+map-header = Tie edessä, jokainen pysähdyspaikka niillä, joihin se johtaa, nykyinen hakasulkeissa. M sulkee
+speaker-cowboy = Sinä
+speaker-dog = Koirasi
+speaker-merchant = Kauppias
+speaker-dandy = Hieno takki
+speaker-stranger = Muukalainen
+
+## Kohtaamiset
+
+encounter-trouble = Hankaluudet löysivät sinut. Taistele tai keksi tie ulos!
+encounter-pack-full = Reppusi on täynnä, jätät jälkeesi: { $thing }
+encounter-dog-finds = Koirasi löytää: { $loot }
+encounter-dog-finds-nothing = Koirasi palaa pelkät mutaiset tassut mukanaan
+
+weapon-jaws-that-bite = Purevat leuat
+weapon-claws-that-catch = Tarttuvat kynnet
+enemy-gamma-wolf = Gammasusi
+enemy-beta-wolf = Beetasusi
+enemy-alpha-wolf = Tieteellisesti paikkansapitävä alfasusi
+enemy-getaway-car = Pakoauto
+enemy-electric-sheep = Puoliksi lammas, puoliksi kone
+weapon-hoofs-of-steel = Teräskaviot
+weapon-philosophical-quandaries = Filosofiset pulmat
+weapon-pickaxe = Hakku
+enemy-thug = Roisto
+weapon-brass-knuckles = Nyrkkirauta
+weapon-rusty-revolver = Ruosteinen revolveri
+enemy-ringleader = Johtaja
+
+gamblers-prompt = Valitse myrkkysi
+gamblers-craps = Craps, 5 heitolta
+gamblers-liars-dice = Valehtelijan noppa, 10 kädeltä
+gamblers-revolver = Revolveri, 10 kierrokselta
+gamblers-leave = Riittää tältä illalta
+gamblers-intro = Lyhdyn valo ja noppien kalina valuvat tienvarren teltasta
+gamblers-wave = Pelureita. He viittovat sinut sisään kukkaroasi vilkuillen
+gamblers-outro = Horjut takaisin ulos yöhön
+
+town-store-leave = Lähdet, jotenkin tyhjempänä kuin tullessasi
+town-bar = Suuntaat lähimpään räkälään juotavan perässä
+town-bar-prompt = 10 noppapistettä pienestä helpotuksesta
+town-bar-pay = Kuulostaa reilulta
+town-bar-drink = Tunnet itsesi vastasyntyneeksi
+town-bar-broke = Valitettavasti kapitalismi on juurtunut tänne syvälle ja sinun on maksettava
+town-bar-refuse = Hieman tyyris
+town-bar-stay = Voisit jäädä tänne ikuisiksi ajoiksi, ehkä vielä yksi päivä
+town-prompt = Minne?
+town-drink = Ota juotavaa
+town-stock-up = Täydennä varastoja
+town-head-out = lähde
+town-arrive = Saavut uneliaaseen kaupunkiin
+town-outro = Tässä paikassa kannattaa käydä.
+
+chase-pursue = Kiristät tahtia ja lähdet perään
+chase-parley = Heität heille osuuden omistasi. Kuski kohottaa hattuaan ja painaa kaasun pohjaan
+chase-surrender = Kädet ylös. He tyhjentävät taskusi ja jättävät sinut yskimään pölyyn
+chase-won-quickly = Saat heidät kiinni ennen kuin he ehtivät kätkeä saaliin, säkkikaupalla
+chase-won = Penkot kärryn jäänteitä ja löydät kourallisen [gold]dollareita[/gold]
+chase-fled = Ryöstäjät ovat kaukana siinä vaiheessa, kun uskallat katsoa taaksesi
+chase-surrendered = Ryöstää ryöstäjiä, mitä oikein ajattelit
+chase-survived = Taskut kevyempinä, mutta yhä hengissä
+chase-intro = Pakoauto pöllyttää tomua ja on vähällä ajaa ylitsesi
+chase-prompt = Ajetaanko takaa
+chase-rob = Ryöstä ryöstäjät!
+chase-let-go = Anna heidän mennä
+chase-gone = Katsot, kun auto katoaa
+
+test-intro = Tämä on testikohtaaminen
+test-prompt = Haluaisitko taistella?
+test-fight = Totta helvetissä!
+test-avoid = Mieluummin en
+test-avoided = Vältät turhan tappelun, ota vähän rahaa
+test-outro = Tämä kohtaaminen on ohi
+
+wolves-attack = Sudet hyökkäävät!
+wolves-near-death = Nuolet haavojasi. Yksi purema lisää, ja se olisi ollut siinä
+
+sheep-intro = Lauma sähkölampaita rynnii sinua kohti
+sheep-prompt = Mitä tehdä kriisin hetkellä?
+sheep-fight = Pidä pääsi
+sheep-count = Yritä laskea ne
+sheep-counted = Alat torkahdella, näet lyhyen unen androideista ja lauma tallaa sinut
+sheep-spook = Säikytä ne aseellasi (6{"{bullet}"})
+sheep-spooked = Ammut muutaman laukauksen ilmaan, ne eivät vaikuta vaikuttuneilta
+sheep-no-bullets = Mietit ampumista, mutta luodit eivät riitä. Ne tuntuvat silti välittävän sinusta viis.
+sheep-salvage = Pelastat raunioista minkä voit
+sheep-outro = Puolimekaaniset märehtijät on hoidettu, mutta mihin hintaan
+
+merchant-intro = Näet kauppiaan kulkemassa tietä pitkin
+merchant-howdy = Päivää, muukalainen. Mainio päivä tienpäälle
+merchant-prompt = Kiinnostaisiko kaupankäynti?
+merchant-trade = Mikäs siinä.
+merchant-decline = Ehkä joskus toiste.
+merchant-shrugs = Kauppias kohauttaa olkiaan.
+merchant-outro = Kauppias jatkaa matkaansa.
+
+prospector-share = Annat osan evääistäsi
+prospector-pickaxe = Hän vaatii sinua ottamaan hänen vanhan hakkunsa, sanoo löytäneensä jo kaiken tarvitsemansa kullan
+prospector-jerky = Ja suikale kuivalihaa matkalle
+prospector-tinker = Hän viilaa sivuaseesi tähtäimet. Se tuntuu nyt tarkemmalta
+prospector-quick = Hän on nopeampi kuin miltä näyttää
+prospector-snatch = Hän nappaa etälamauttimesi ja katoaa yöhön
+prospector-intro = Vanha kullankaivaja istuu yksin nuotionsa ääressä
+prospector-prompt = Hän silmäilee sinua ja varusteitasi
+prospector-meal = Jaa ateria
+prospector-ask = Pyydä häntä katsomaan sivuasettasi
+prospector-rob = Ryöstä hänet
+prospector-outro = Jätät kullankaivajan nuotionsa ääreen
+
+dog-buyer-refuse = Se ei ole myytävänä
+dog-buyer-insist = Minun on pakko vaatia
+dog-buyer-loyal = Koirasi painautuu jalkaasi vasten
+dog-buyer-paid = Hän painaa kourallisen [gold]noppia[/gold] kämmeneesi
+dog-buyer-dragged = Koirasi katsoo sinua, kun sitä raahataan pois
+dog-buyer-regret = Paska... Unohdit, että sinun piti pitää huolta koirasta
+dog-buyer-back = Koirasi palaa luoksesi varuillaan
+dog-buyer-whimpers = *vinkuu*
+dog-buyer-intro = Hienoon takkiin pukeutunut mies katselee koiraasi päästä varpaisiin
+dog-buyer-prompt = Hei, myisitkö koiran kourallisesta?
+dog-buyer-no = En
+dog-buyer-yes = Kyllä
+
+scent-intro = Koirasi pysähtyy kuin seinään, kuono maassa
+scent-woof = Hau!
+scent-prompt = Se on saanut vainun
+scent-follow = Anna sen näyttää tietä
+scent-followed = Seuraat koiraasi pois polulta
+scent-pull = Vedä se mukaasi
+scent-pulled = Koirasi vinkaisee, mutta seuraa
+
+intro-welcome = Tervetuloa rajaseudulle. Olet cowboy, jonka vastuulla on pentu
+intro-arrows = Liiku nuolinäppäimillä ja valitse välilyönnillä tai enterillä
+intro-numbers = Numeronäppäimet valitsevat vaihtoehdon suoraan, ja hiiri tai peliohjain käyvät myös
+intro-inventory = Paina I matkalla penkoaksesi reppuasi
+intro-map = Paina M matkalla katsoaksesi karttaa
+intro-chat = PageUp ja PageDown vierittävät keskustelua, J avaa päiväkirjan
+intro-hurry = Pidä F pohjassa kiirehtiäksesi tietä pitkin, tai paina S hypätäksesi eteenpäin
+intro-battle = Taistelussa Backspace peruu valinnan ja Tab luettelee kaikki siirrot
+intro-skip-chat = Välilyönti tai napsautus kiirehtii keskustelua, kun se on kesken
+intro-rebind = Kaikki nämä voi vaihtaa kohdasta Asetukset, Ohjaimet
+intro-adventure = Seikkailu odottaa
+intro-prompt = Aloitetaanko seikkailu?
+intro-simple-yes = Yksinkertainen kyllä
+intro-simple-yes-reply = Sitä henkeä!
+intro-sarcastic-yes = Sarkastinen kyllä
+intro-sarcastic-yes-reply = Mikä veijari! Harmi, ettei sinulla ole toimijuutta
+intro-no-yes = Ei, mutta oikeasti kyllä
+intro-no-yes-reply = Torjut tehtävän, kuinka sankarillista. Harmi, että on peli pelattavana.
+intro-confused = Menin hämilleni
+intro-confused-reply = Niin se joskus menee. Toivottavasti keksit sen
+intro-onwards = Eteenpäin!
+
+death-comes = Kuolema tulee kaikille. Ja [b]merkitsikö[/b] mikään siitä lopulta mitään.
+death-dice-fall = No, joskus nopat vain putoavat niin. Kaikkia ei voi voittaa.
+death-win-any = Jotkut eivät voita yhtäkään.
+death-go = Toivottavasti sait tästä jotain iloa. Mutta minun on mentävä nyt.
+death-prompt = Häh, mitä?
+death-who = Kuka sinä olet?
+death-who-reply = Onko sillä väliä? Miksi? Kaipaatko merkitystä noin kovasti?
+death-cheers = Kippis
+death-cheers-reply = Hauskaa oli niin kauan kuin sitä kesti
+death-nod = *Nyökkää ja kohota hattuasi
+death-nod-reply = Pardner, *kohottaa takaisin
+death-confused = Olen yhä hämmentyneempi kuin ennen
+death-confused-reply = Toivon vilpittömästi, että keksit sen
@@ -0,0 +1,456 @@
+use core::fmt;
+
+use bevy::prelude::*;
+
+use crate::locale::t;
+
+use super::InputAction;
+
+/// Keys that can be bound and written to the controls file
+const KEYS: [KeyCode; 77] = [
+    KeyCode::A,
+    KeyCode::B,
+    KeyCode::C,
+    KeyCode::D,
+    KeyCode::E,
+    KeyCode::F,
+    KeyCode::G,
+    KeyCode::H,
+    KeyCode::I,
+    KeyCode::J,
+    KeyCode::K,
+    KeyCode::L,
+    KeyCode::M,
+    KeyCode::N,
+    KeyCode::O,
+    KeyCode::P,
+    KeyCode::Q,
+    KeyCode::R,
+    KeyCode::S,
+    KeyCode::T,
+    KeyCode::U,
+    KeyCode::V,
+    KeyCode::W,
+    KeyCode::X,
+    KeyCode::Y,
+    KeyCode::Z,
+    KeyCode::Key0,
+    KeyCode::Key1,
+    KeyCode::Key2,
+    KeyCode::Key3,
+    KeyCode::Key4,
+    KeyCode::Key5,
+    KeyCode::Key6,
+    KeyCode::Key7,
+    KeyCode::Key8,
+    KeyCode::Key9,
+    KeyCode::Numpad0,
+    KeyCode::Numpad1,
+    KeyCode::Numpad2,
+    KeyCode::Numpad3,
+    KeyCode::Numpad4,
+    KeyCode::Numpad5,
+    KeyCode::Numpad6,
+    KeyCode::Numpad7,
+    KeyCode::Numpad8,
+    KeyCode::Numpad9,
+    KeyCode::F1,
+    KeyCode::F2,
+    KeyCode::F3,
+    KeyCode::F4,
+    KeyCode::F5,
+    KeyCode::F6,
+    KeyCode::F7,
+    KeyCode::F8,
+    KeyCode::F9,
+    KeyCode::F10,
+    KeyCode::F11,
+    KeyCode::F12,
+    KeyCode::Escape,
+    KeyCode::Space,
+    KeyCode::Return,
+    KeyCode::Back,
+    KeyCode::Tab,
+    KeyCode::Left,
+    KeyCode::Right,
+    KeyCode::Up,
+    KeyCode::Down,
+    KeyCode::PageUp,
+    KeyCode::PageDown,
+    KeyCode::Home,
+    KeyCode::End,
+    KeyCode::Insert,
+    KeyCode::Delete,
+    KeyCode::LShift,
+    KeyCode::RShift,
+    KeyCode::LControl,
+    KeyCode::RControl,
+];
+
+const BUTTONS: [GamepadButtonType; 19] = [
+    GamepadButtonType::South,
+    GamepadButtonType::East,
+    GamepadButtonType::North,
+    GamepadButtonType::West,
+    GamepadButtonType::C,
+    GamepadButtonType::Z,
+    GamepadButtonType::LeftTrigger,
+    GamepadButtonType::LeftTrigger2,
+    GamepadButtonType::RightTrigger,
+    GamepadButtonType::RightTrigger2,
+    GamepadButtonType::Select,
+    GamepadButtonType::Start,
+    GamepadButtonType::Mode,
+    GamepadButtonType::LeftThumb,
+    GamepadButtonType::RightThumb,
+    GamepadButtonType::DPadUp,
+    GamepadButtonType::DPadDown,
+    GamepadButtonType::DPadLeft,
+    GamepadButtonType::DPadRight,
+];
+
+/// Number keys always pick options outright, nothing else can have them
+const NUMBER_KEYS: [(KeyCode, KeyCode); 9] = [
+    (KeyCode::Key1, KeyCode::Numpad1),
+    (KeyCode::Key2, KeyCode::Numpad2),
+    (KeyCode::Key3, KeyCode::Numpad3),
+    (KeyCode::Key4, KeyCode::Numpad4),
+    (KeyCode::Key5, KeyCode::Numpad5),
+    (KeyCode::Key6, KeyCode::Numpad6),
+    (KeyCode::Key7, KeyCode::Numpad7),
+    (KeyCode::Key8, KeyCode::Numpad8),
+    (KeyCode::Key9, KeyCode::Numpad9),
+];
+
+/// The option a number key picks, if it is one
+pub fn number_key(key: KeyCode) -> Option<usize> {
+    NUMBER_KEYS
+        .iter()
+        .position(|(row, pad)| key == *row || key == *pad)
+}
+
+/// Something on a keyboard or a gamepad an action can be bound to
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Binding {
+    Key(KeyCode),
+    Button(GamepadButtonType),
+}
+impl fmt::Display for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Binding::Key(key) => write!(f, "{:?}", key),
+            Binding::Button(button) => write!(f, "Pad:{:?}", button),
+        }
+    }
+}
+impl Binding {
+    /// Reads back what Display wrote
+    fn parse(name: &str) -> Option<Self> {
+        if let Some(button) = name.strip_prefix("Pad:") {
+            BUTTONS
+                .iter()
+                .find(|candidate| format!("{:?}", candidate) == button)
+                .map(|button| Binding::Button(*button))
+        } else {
+            KEYS.iter()
+                .find(|candidate| format!("{:?}", candidate) == name)
+                .map(|key| Binding::Key(*key))
+        }
+    }
+
+    /// Whether it can be bound at all, some keys can't be written down or are spoken for
+    pub fn is_bindable(&self) -> bool {
+        match self {
+            Binding::Key(key) => KEYS.contains(key) && number_key(*key).is_none(),
+            Binding::Button(button) => BUTTONS.contains(button),
+        }
+    }
+
+    fn same_kind(&self, other: &Binding) -> bool {
+        matches!(
+            (self, other),
+            (Binding::Key(_), Binding::Key(_)) | (Binding::Button(_), Binding::Button(_))
+        )
+    }
+}
+
+/// Why a binding was refused
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Conflict {
+    /// Already bound to another action
+    Taken(InputAction),
+    /// Number keys, or keys that can't be saved
+    Reserved,
+}
+
+/// Which keys and buttons do what. Insert one before the plugins to skip the controls file
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Bindings {
+    bound: Vec<(Binding, InputAction)>,
+}
+impl Default for Bindings {
+    fn default() -> Self {
+        use Binding::{Button, Key};
+        Self {
+            bound: vec![
+                (Key(KeyCode::Left), InputAction::Prev),
+                (Button(GamepadButtonType::DPadLeft), InputAction::Prev),
+                (Button(GamepadButtonType::LeftTrigger), InputAction::Prev),
+                (Key(KeyCode::Right), InputAction::Next),
+                (Button(GamepadButtonType::DPadRight), InputAction::Next),
+                (Button(GamepadButtonType::RightTrigger), InputAction::Next),
+                (Key(KeyCode::Space), InputAction::Confirm),
+                (Key(KeyCode::Return), InputAction::Confirm),
+                (Button(GamepadButtonType::South), InputAction::Confirm),
+                (Key(KeyCode::Back), InputAction::Back),
+                (Button(GamepadButtonType::East), InputAction::Back),
+                (Key(KeyCode::Escape), InputAction::Pause),
+                (Button(GamepadButtonType::Start), InputAction::Pause),
+                (Key(KeyCode::PageUp), InputAction::ScrollUp),
+                (Button(GamepadButtonType::DPadUp), InputAction::ScrollUp),
+                (Key(KeyCode::PageDown), InputAction::ScrollDown),
+                (Button(GamepadButtonType::DPadDown), InputAction::ScrollDown),
+                (Key(KeyCode::J), InputAction::Journal),
+                (Button(GamepadButtonType::Select), InputAction::Journal),
+                (Key(KeyCode::I), InputAction::Inventory),
+                (Button(GamepadButtonType::North), InputAction::Inventory),
+                (Key(KeyCode::M), InputAction::Map),
+                (Button(GamepadButtonType::West), InputAction::Map),
+                (Key(KeyCode::F), InputAction::Hurry),
+                (Button(GamepadButtonType::RightTrigger2), InputAction::Hurry),
+                (Key(KeyCode::S), InputAction::Skip),
+                (Button(GamepadButtonType::LeftTrigger2), InputAction::Skip),
+                (Key(KeyCode::Tab), InputAction::Palette),
+                (Button(GamepadButtonType::RightThumb), InputAction::Palette),
+            ],
+        }
+    }
+}
+impl Bindings {
+    /// Everything bound to `binding`, more than one only if the controls file says so
+    pub fn actions(&self, binding: Binding) -> impl Iterator<Item = InputAction> + '_ {
+        self.bound
+            .iter()
+            .filter(move |(bound, _)| *bound == binding)
+            .map(|(_, action)| *action)
+    }
+
+    pub fn bindings(&self, action: InputAction) -> Vec<Binding> {
+        self.bound
+            .iter()
+            .filter(|(_, bound)| *bound == action)
+            .map(|(binding, _)| *binding)
+            .collect()
+    }
+
+    /// What the controls screen shows for an action
+    pub fn describe(&self, action: InputAction) -> String {
+        let bindings: Vec<String> = self
+            .bindings(action)
+            .iter()
+            .map(|binding| binding.to_string())
+            .collect();
+        if bindings.is_empty() {
+            t!("controls-unbound", action = action.to_string())
+        } else {
+            t!(
+                "controls-bound",
+                action = action.to_string(),
+                bindings = bindings.join(", ")
+            )
+        }
+    }
+
+    /// Everything bound to more than one action, or to something that can't be bound
+    pub fn conflicts(&self) -> Vec<(Binding, Conflict)> {
+        let mut conflicts = vec![];
+        for (index, (binding, action)) in self.bound.iter().enumerate() {
+            if !binding.is_bindable() {
+                conflicts.push((*binding, Conflict::Reserved));
+            } else if let Some((_, other)) = self.bound[..index]
+                .iter()
+                .find(|(earlier, other)| earlier == binding && other != action)
+            {
+                conflicts.push((*binding, Conflict::Taken(*other)));
+            }
+        }
+        conflicts
+    }
+
+    /// Makes `binding` the only key, or the only button, for `action`.
+    /// Refused if that would give it two meanings
+    pub fn rebind(&mut self, action: InputAction, binding: Binding) -> Result<(), Conflict> {
+        if !binding.is_bindable() {
+            return Err(Conflict::Reserved);
+        }
+        if let Some(other) = self.actions(binding).find(|other| *other != action) {
+            return Err(Conflict::Taken(other));
+        }
+        self.bound
+            .retain(|(bound, bound_to)| *bound_to != action || !bound.same_kind(&binding));
+        self.bound.push((binding, action));
+        Ok(())
+    }
+
+    /// The controls file, one line per action: `confirm = Space, Return, Pad:South`
+    pub fn render(&self) -> String {
+        let mut text = String::from("# action = keys and Pad:buttons, separated by commas\n");
+        for action in InputAction::BINDABLE {
+            let bindings: Vec<String> = self
+                .bindings(action)
+                .iter()
+                .map(|binding| binding.to_string())
+                .collect();
+            text.push_str(&format!("{} = {}\n", action.name(), bindings.join(", ")));
+        }
+        text
+    }
+
+    /// Reads a controls file. Actions it doesn't mention keep their defaults,
+    /// anything it gets wrong is skipped and complained about
+    pub fn parse(text: &str) -> (Self, Vec<String>) {
+        let mut bindings = Self::default();
+        let mut complaints = vec![];
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (name, inputs) = if let Some(split) = line.split_once('=') {
+                split
+            } else {
+                complaints.push(format!("line {}: expected `action = keys`", number + 1));
+                continue;
+            };
+            let action = if let Some(action) = InputAction::from_name(name.trim()) {
+                action
+            } else {
+                complaints.push(format!(
+                    "line {}: no action called {}",
+                    number + 1,
+                    name.trim()
+                ));
+                continue;
+            };
+            bindings.bound.retain(|(_, bound)| *bound != action);
+            for input in inputs
+                .split(',')
+                .map(str::trim)
+                .filter(|input| !input.is_empty())
+            {
+                match Binding::parse(input) {
+                    Some(binding) => bindings.bound.push((binding, action)),
+                    None => complaints.push(format!("line {}: unknown key {}", number + 1, input)),
+                }
+            }
+        }
+        (bindings, complaints)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::prelude::*;
+
+    use super::{number_key, Binding, Bindings, Conflict};
+    use crate::actions::InputAction;
+
+    #[test]
+    fn number_keys_pick_options() {
+        assert_eq!(number_key(KeyCode::Key1), Some(0));
+        assert_eq!(number_key(KeyCode::Numpad9), Some(8));
+        assert_eq!(number_key(KeyCode::Key0), None);
+    }
+
+    #[test]
+    fn defaults_dont_conflict() {
+        let bindings = Bindings::default();
+        assert!(bindings.conflicts().is_empty());
+        for action in InputAction::BINDABLE {
+            assert!(!bindings.bindings(action).is_empty(), "{:?}", action);
+        }
+    }
+
+    #[test]
+    fn rebinding_refuses_taken_keys() {
+        let mut bindings = Bindings::default();
+        assert_eq!(
+            bindings.rebind(InputAction::Confirm, Binding::Key(KeyCode::J)),
+            Err(Conflict::Taken(InputAction::Journal))
+        );
+        assert_eq!(
+            bindings.rebind(InputAction::Confirm, Binding::Key(KeyCode::Key3)),
+            Err(Conflict::Reserved)
+        );
+        assert_eq!(bindings, Bindings::default());
+
+        // Rebinding to what's already there changes nothing
+        assert_eq!(
+            bindings.rebind(InputAction::Journal, Binding::Key(KeyCode::J)),
+            Ok(())
+        );
+        assert!(bindings.conflicts().is_empty());
+    }
+
+    #[test]
+    fn rebinding_replaces_keys_but_not_buttons() {
+        let mut bindings = Bindings::default();
+        assert_eq!(
+            bindings.rebind(InputAction::Confirm, Binding::Key(KeyCode::E)),
+            Ok(())
+        );
+        assert_eq!(
+            bindings.bindings(InputAction::Confirm),
+            vec![
+                Binding::Button(GamepadButtonType::South),
+                Binding::Key(KeyCode::E)
+            ]
+        );
+        // Space is free again
+        assert_eq!(
+            bindings.rebind(InputAction::Map, Binding::Key(KeyCode::Space)),
+            Ok(())
+        );
+        assert!(bindings.conflicts().is_empty());
+    }
+
+    #[test]
+    fn the_file_reads_back() {
+        let mut bindings = Bindings::default();
+        bindings
+            .rebind(InputAction::Hurry, Binding::Button(GamepadButtonType::Z))
+            .unwrap();
+        bindings
+            .rebind(InputAction::Skip, Binding::Key(KeyCode::Home))
+            .unwrap();
+        let (read, complaints) = Bindings::parse(&bindings.render());
+        assert!(complaints.is_empty());
+        for action in InputAction::BINDABLE {
+            assert_eq!(read.bindings(action), bindings.bindings(action));
+        }
+    }
+
+    #[test]
+    fn conflicts_in_the_file_are_caught() {
+        let (bindings, complaints) = Bindings::parse(
+            "# comment\nconfirm = Space, Pad:South\nmap = Space, Key2\nnonsense\nhop = H\nskip = Banana",
+        );
+        assert_eq!(complaints.len(), 3);
+        assert_eq!(
+            bindings.conflicts(),
+            vec![
+                (
+                    Binding::Key(KeyCode::Space),
+                    Conflict::Taken(InputAction::Confirm)
+                ),
+                (Binding::Key(KeyCode::Key2), Conflict::Reserved),
+            ]
+        );
+        // Mentioned actions lose their defaults, the rest keep them
+        assert!(bindings.bindings(InputAction::Skip).is_empty());
+        assert_eq!(
+            bindings.bindings(InputAction::Journal),
+            Bindings::default().bindings(InputAction::Journal)
+        );
+    }
+}
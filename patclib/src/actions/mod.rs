@@ -0,0 +1,245 @@
+use core::fmt;
+
+use bevy::{input::InputSystem, prelude::*, ui::UiSystem};
+
+use crate::{
+    files::{read_user_file, write_user_file},
+    locale::t,
+};
+
+mod bindings;
+use bindings::number_key;
+pub use bindings::{Binding, Bindings, Conflict};
+
+/// Where the player's controls are kept, in the game's config folder
+const CONTROLS_FILE: &str = "controls.cfg";
+
+/// What the player wants, whether they said it with a keyboard, a mouse or a gamepad
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum InputAction {
+    Prev,
+    Next,
+    Confirm,
+    Back,
+    Pause,
+    ScrollUp,
+    ScrollDown,
+    Journal,
+    Inventory,
+    Map,
+    Hurry,
+    Skip,
+    Palette,
+    /// Highlight an option without picking it
+    Point(usize),
+    /// Pick an option straight away
+    Choose(usize),
+}
+impl fmt::Display for InputAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            InputAction::Prev => t!("input-prev"),
+            InputAction::Next => t!("input-next"),
+            InputAction::Confirm => t!("input-confirm"),
+            InputAction::Back => t!("input-back"),
+            InputAction::Pause => t!("input-pause"),
+            InputAction::ScrollUp => t!("input-scroll-up"),
+            InputAction::ScrollDown => t!("input-scroll-down"),
+            InputAction::Journal => t!("input-journal"),
+            InputAction::Inventory => t!("input-inventory"),
+            InputAction::Map => t!("input-map"),
+            InputAction::Hurry => t!("input-hurry"),
+            InputAction::Skip => t!("input-skip"),
+            InputAction::Palette => t!("input-palette"),
+            InputAction::Point(index) => t!("input-point", option = index + 1),
+            InputAction::Choose(index) => t!("input-choose", option = index + 1),
+        };
+        write!(f, "{}", name)
+    }
+}
+impl InputAction {
+    /// Everything that can be rebound, pointing and picking come with the mouse and number keys
+    pub const BINDABLE: [InputAction; 13] = [
+        InputAction::Prev,
+        InputAction::Next,
+        InputAction::Confirm,
+        InputAction::Back,
+        InputAction::Pause,
+        InputAction::ScrollUp,
+        InputAction::ScrollDown,
+        InputAction::Journal,
+        InputAction::Inventory,
+        InputAction::Map,
+        InputAction::Hurry,
+        InputAction::Skip,
+        InputAction::Palette,
+    ];
+
+    /// How it's called in the controls file
+    fn name(self) -> &'static str {
+        match self {
+            InputAction::Prev => "prev",
+            InputAction::Next => "next",
+            InputAction::Confirm => "confirm",
+            InputAction::Back => "back",
+            InputAction::Pause => "pause",
+            InputAction::ScrollUp => "scroll_up",
+            InputAction::ScrollDown => "scroll_down",
+            InputAction::Journal => "journal",
+            InputAction::Inventory => "inventory",
+            InputAction::Map => "map",
+            InputAction::Hurry => "hurry",
+            InputAction::Skip => "skip",
+            InputAction::Palette => "palette",
+            InputAction::Point(_) | InputAction::Choose(_) => "",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        InputAction::BINDABLE
+            .into_iter()
+            .find(|action| action.name() == name)
+    }
+}
+
+/// An option of the decision on screen, clickable
+#[derive(Debug, Component)]
+pub struct DecisionOption(pub usize);
+
+/// Actions started this frame, and the ones still held down
+#[derive(Debug, Default)]
+pub struct Actions {
+    pressed: Vec<InputAction>,
+    held: Vec<InputAction>,
+}
+impl Actions {
+    pub fn just_pressed(&self, action: InputAction) -> bool {
+        self.pressed.contains(&action)
+    }
+
+    pub fn held(&self, action: InputAction) -> bool {
+        self.held.contains(&action)
+    }
+
+    /// The option pointed at, if any
+    pub fn pointed(&self) -> Option<usize> {
+        self.pressed.iter().find_map(|action| match action {
+            InputAction::Point(index) => Some(*index),
+            _ => None,
+        })
+    }
+
+    /// The option picked directly, if any
+    pub fn chosen(&self) -> Option<usize> {
+        self.pressed.iter().find_map(|action| match action {
+            InputAction::Choose(index) => Some(*index),
+            _ => None,
+        })
+    }
+}
+
+pub struct ActionPlugin;
+
+impl Plugin for ActionPlugin {
+    fn build(&self, app: &mut App) {
+        if app.world.get_resource::<Bindings>().is_none() {
+            app.insert_resource(load_bindings());
+        }
+        app.init_resource::<Actions>().add_system_to_stage(
+            CoreStage::PreUpdate,
+            gather_actions.after(InputSystem).after(UiSystem::Focus),
+        );
+    }
+}
+
+/// The player's controls, or the defaults if they never changed any
+fn load_bindings() -> Bindings {
+    let text = if let Some(text) = read_user_file(CONTROLS_FILE) {
+        text
+    } else {
+        return Bindings::default();
+    };
+    let (bindings, complaints) = Bindings::parse(&text);
+    for complaint in complaints {
+        warn!("{}: {}", CONTROLS_FILE, complaint);
+    }
+    for (binding, conflict) in bindings.conflicts() {
+        warn!("{}: {} is {:?}", CONTROLS_FILE, binding, conflict);
+    }
+    bindings
+}
+
+/// Keeps the controls for next time
+pub fn save_bindings(bindings: &Bindings) {
+    if let Err(error) = write_user_file(CONTROLS_FILE, &bindings.render()) {
+        warn!("Couldn't save {}: {}", CONTROLS_FILE, error);
+    }
+}
+
+fn gather_actions(
+    mut actions: ResMut<Actions>,
+    bindings: Res<Bindings>,
+    (kb_inputs, gamepad_inputs): (Res<Input<KeyCode>>, Res<Input<GamepadButton>>),
+    options: Query<(&Interaction, &DecisionOption), Changed<Interaction>>,
+) {
+    let actions = &mut *actions;
+    actions.pressed.clear();
+    actions.held.clear();
+    for key in kb_inputs.get_just_pressed() {
+        actions.pressed.extend(bindings.actions(Binding::Key(*key)));
+        actions
+            .pressed
+            .extend(number_key(*key).map(InputAction::Choose));
+    }
+    for GamepadButton(_, button) in gamepad_inputs.get_just_pressed() {
+        actions
+            .pressed
+            .extend(bindings.actions(Binding::Button(*button)));
+    }
+    for key in kb_inputs.get_pressed() {
+        actions.held.extend(bindings.actions(Binding::Key(*key)));
+    }
+    for GamepadButton(_, button) in gamepad_inputs.get_pressed() {
+        actions
+            .held
+            .extend(bindings.actions(Binding::Button(*button)));
+    }
+    for (interaction, option) in options.iter() {
+        match interaction {
+            Interaction::Clicked => actions.pressed.push(InputAction::Choose(option.0)),
+            Interaction::Hovered => actions.pressed.push(InputAction::Point(option.0)),
+            Interaction::None => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Actions, InputAction};
+
+    #[test]
+    fn names_read_back() {
+        for action in InputAction::BINDABLE {
+            assert_eq!(InputAction::from_name(action.name()), Some(action));
+        }
+        assert_eq!(InputAction::from_name(""), None);
+    }
+
+    #[test]
+    fn picking_out_options() {
+        let actions = Actions {
+            pressed: vec![
+                InputAction::Next,
+                InputAction::Point(2),
+                InputAction::Choose(1),
+            ],
+            held: vec![InputAction::Hurry],
+        };
+        assert!(actions.just_pressed(InputAction::Next));
+        assert!(!actions.just_pressed(InputAction::Confirm));
+        assert!(actions.held(InputAction::Hurry));
+        assert_eq!(actions.pointed(), Some(2));
+        assert_eq!(actions.chosen(), Some(1));
+        assert_eq!(Actions::default().chosen(), None);
+    }
+}
@@ -12,6 +12,9 @@ pub struct Colors {
     pub basic_text: Color,
     pub highlight_text: Color,
     pub crucial_text: Color,
+    pub faded_text: Color,
+    pub green_text: Color,
+    pub blue_text: Color,
     pub battle_tile: Color,
     pub dark_background: UiColor,
     pub gray_background: UiColor,
@@ -19,9 +22,12 @@ pub struct Colors {
 
 #[derive(Debug)]
 pub struct Images {
+    /// The stranger who shows up once it's all over only ever shows a hand
     pub open_hand: Handle<Image>,
     pub dog: Handle<Image>,
     pub cowboy: Handle<Image>,
+    /// Merchants and dandies, there's one face for the folk along the road so far
+    pub townsfolk: Handle<Image>,
 }
 
 pub struct AssetPlugin;
@@ -39,11 +45,15 @@ fn load(mut commands: Commands, asset_server: Res<AssetServer>) {
             open_hand: asset_server.load("kammenAvoin.png"),
             dog: asset_server.load("dog.png"),
             cowboy: asset_server.load("cowboy.png"),
+            townsfolk: asset_server.load("cowboy1.png"),
         },
         colors: Colors {
             basic_text: Color::WHITE,
             highlight_text: Color::GOLD,
             crucial_text: Color::CRIMSON,
+            faded_text: Color::GRAY,
+            green_text: Color::LIME_GREEN,
+            blue_text: Color::CYAN,
             battle_tile: Color::BEIGE,
             dark_background: Color::rgb(0.13, 0.13, 0.13).into(),
             gray_background: Color::rgb(0.23, 0.23, 0.23).into(),
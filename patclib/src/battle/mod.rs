@@ -1,23 +1,52 @@
+mod palette;
+mod result;
 mod visuals;
+mod weapons;
 use bevy::prelude::*;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::{thread_rng, Rng};
 
 use crate::{
+    actions::{Actions, InputAction},
     assets::AssetHandles,
+    companion::DogCommand,
     dice_value::DiceValue,
-    flow::AppState,
+    flow::{AppState, FlowEvent},
+    inventory::{Item, ItemEffect},
+    locale::{t, tr},
+    loot::{Loot, LootTable},
+    payment::{start_payment, PaymentResult},
     player::{BattleAction, Player, PlayerResources},
     ui::UIHelper,
 };
 
+pub use result::{BattleCondition, BattleOutcome, BattleResult, BattleStats};
+pub use weapons::{cattle_prod, sawn_off_shotgun};
+
 pub const BATTLE_ARENA_WIDTH: u32 = 4;
 pub const BATTLE_ARENA_DEPTH: u32 = 3;
+const ESCAPE_BASE_TARGET: u32 = 4;
+
+/// Terms for ending a battle without bloodshed
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct BattleTerms {
+    /// Message id of what's said once it's settled
+    pub line: &'static str,
+    pub cost: PlayerResources,
+}
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Battle {
     unused_enemies: Vec<Enemy>,
     lanes: Vec<Option<Enemy>>,
+    parley: Option<BattleTerms>,
+    surrender: Option<BattleTerms>,
+    /// The rest of the parley terms, held while the player counts out the money
+    parley_held: Option<PlayerResources>,
+    stats: BattleStats,
+    loot: LootTable,
+    /// Dropped by fallen enemies, handed over once the battle ends
+    spoils: Vec<Loot>,
 }
 impl Battle {
     /// Used to create a battle without any info on player state
@@ -25,21 +54,109 @@ impl Battle {
         Self {
             unused_enemies: enemies,
             lanes: vec![None; BATTLE_ARENA_WIDTH as usize],
+            parley: None,
+            surrender: None,
+            parley_held: None,
+            stats: default(),
+            loot: default(),
+            spoils: vec![],
         }
     }
 
+    /// Rolled once the battle is won
+    pub fn with_loot(mut self, loot: LootTable) -> Self {
+        self.loot = loot;
+        self
+    }
+
+    /// Enemies will call it off if the player pays up
+    pub fn with_parley(mut self, line: &'static str, cost: PlayerResources) -> Self {
+        self.parley = Some(BattleTerms { line, cost });
+        self
+    }
+
+    /// Enemies will accept a surrender, taking what they want by force
+    pub fn with_surrender(mut self, line: &'static str, cost: PlayerResources) -> Self {
+        self.surrender = Some(BattleTerms { line, cost });
+        self
+    }
+
+    pub fn can_parley(&self) -> bool {
+        self.parley.is_some()
+    }
+
+    pub fn can_surrender(&self) -> bool {
+        self.surrender.is_some()
+    }
+
+    fn result(&self, outcome: BattleOutcome) -> BattleResult {
+        BattleResult {
+            outcome,
+            stats: BattleStats {
+                enemies_left: (self.lanes.iter().flatten().count() + self.unused_enemies.len())
+                    as u32,
+                ..self.stats
+            },
+        }
+    }
+
+    /// What a 1d6 needs to roll at least for the player to get away
+    fn escape_target(&self, player_position: u32) -> u32 {
+        // Every enemy breathing down your neck makes it harder
+        let enemies_close = self
+            .lanes
+            .iter()
+            .flatten()
+            .filter(|enemy| enemy.position_y <= 1)
+            .count() as u32;
+
+        // Easier to slip away from the sides
+        let at_edge = player_position == 0 || player_position == BATTLE_ARENA_WIDTH - 1;
+
+        (ESCAPE_BASE_TARGET + enemies_close - at_edge as u32).clamp(2, 6)
+    }
+
     pub fn is_over(&self) -> bool {
         self.lanes.iter().all(|lane| lane.is_none()) && self.unused_enemies.len() == 0
     }
 
-    fn clean_out_dead(&mut self) {
+    /// Returns the name of the closest enemy, who is now too scared to act
+    fn stun_closest(&mut self) -> Option<&'static str> {
+        let enemy = self
+            .lanes
+            .iter_mut()
+            .flatten()
+            .min_by_key(|enemy| enemy.position_y)?;
+        enemy.stunned = true;
+        Some(enemy.name)
+    }
+
+    /// Hits every enemy on the field with a separate roll
+    fn damage_all(&mut self, damage: DiceValue) {
+        for enemy in self.lanes.iter_mut().flatten() {
+            let damage_dealt = damage.roll().min(enemy.health);
+            enemy.health -= damage_dealt;
+            self.stats.damage_dealt += damage_dealt;
+        }
+    }
+
+    /// Returns what the fallen dropped
+    fn clean_out_dead(&mut self) -> Vec<(&'static str, Loot)> {
+        let mut drops = vec![];
         for lane in self.lanes.iter_mut() {
             if let Some(enemy) = lane {
                 if enemy.health == 0 {
+                    if let Some(loot) = enemy.loot.roll() {
+                        drops.push((enemy.name, loot));
+                    }
                     *lane = None;
+                    self.stats.enemies_killed += 1;
                 }
             }
         }
+        self.spoils
+            .extend(drops.iter().map(|(_, loot)| loot.clone()));
+        drops
     }
 
     fn place_enemies(&mut self) {
@@ -110,6 +227,7 @@ impl Battle {
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Weapon {
+    /// Message id
     pub name: &'static str,
     pub damage: DiceValue,
     pub range: u32,
@@ -129,27 +247,33 @@ impl Default for Weapon {
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Enemy {
+    /// Message id
     pub name: &'static str,
     pub health: u32,
     pub weapons: Vec<Weapon>,
     pub position_x: u32,
     pub position_y: u32,
     pub handle_image: Handle<Image>,
+    pub loot: LootTable,
+    /// Skips its next turn
+    pub stunned: bool,
 }
 
 impl Default for Enemy {
     fn default() -> Self {
         Self {
-            name: "Mystery foe",
+            name: "enemy-mystery-foe",
             health: 10,
             weapons: vec![Weapon {
-                name: "Element of surprise",
+                name: "weapon-element-of-surprise",
                 damage: "1d6".into(),
                 ..default()
             }],
             position_y: BATTLE_ARENA_DEPTH,
             position_x: BATTLE_ARENA_WIDTH,
             handle_image: default(),
+            loot: default(),
+            stunned: false,
         }
     }
 }
@@ -157,210 +281,589 @@ impl Default for Enemy {
 #[derive(Debug, Deref, DerefMut)]
 pub struct OngoingBattle(pub Battle);
 
+/// Open while the player picks a whole turn from one list
+#[derive(Debug)]
+struct BattlePalette(Vec<palette::Shortcut>);
+
 pub struct BattlePlugin;
 
 impl Plugin for BattlePlugin {
     fn build(&self, app: &mut App) {
         app.add_system_set(SystemSet::on_enter(AppState::Battle).with_system(init_battle))
-            .add_system_set(SystemSet::on_update(AppState::Battle).with_system(advance_battle));
+            .add_system_set(
+                SystemSet::on_update(AppState::Battle)
+                    .with_system(advance_battle)
+                    .with_system(visuals::refit),
+            )
+            .add_system_set(SystemSet::on_exit(AppState::Battle).with_system(visuals::clear));
     }
 }
 
+/// Drops the battle going on, if any, along with everything drawn for it
+pub(crate) fn reset(world: &mut World) {
+    world.remove_resource::<OngoingBattle>();
+    world.remove_resource::<BattleResult>();
+    world.remove_resource::<BattlePalette>();
+    visuals::despawn_all(world);
+}
+
 fn init_battle(
     mut commands: Commands,
     assets: Res<AssetHandles>,
     mut ui_helper: ResMut<UIHelper>,
-    player: Res<Player>,
-    mut battle: ResMut<OngoingBattle>,
+    mut player: ResMut<Player>,
+    (mut battle, windows): (ResMut<OngoingBattle>, Res<Windows>),
 ) {
     battle.place_enemies();
-    visuals::init(&mut commands, &assets, &mut battle, &player);
-    prompt_for_action(&mut ui_helper, player.get_battle_actions());
+    battle.stats.lowest_stamina = player.resources.stamina;
+    // The dog sticks close, but not too close
+    player.dog.position = (player.position + 1) % BATTLE_ARENA_WIDTH;
+    visuals::init(&mut commands, &assets, &mut battle, &player, &windows);
+    prompt_for_action(&mut ui_helper, player.get_battle_actions(&battle));
 }
 
 fn advance_battle(
     mut commands: Commands,
-    mut app_state: ResMut<State<AppState>>,
+    mut flow: EventWriter<FlowEvent>,
     mut ui_helper: ResMut<UIHelper>,
     mut player: ResMut<Player>,
     mut battle: ResMut<OngoingBattle>,
+    (actions, palette): (Res<Actions>, Option<Res<BattlePalette>>),
+    payment_result: Option<Res<PaymentResult>>,
 ) {
-    if let Some(decision) = player.drain_decision() {
-        if let Some(selected_action) = player.selected_action {
-            match selected_action {
-                BattleAction::Wait => {
-                    panic!("How did you get here?");
-                }
-                BattleAction::Move => {
-                    player.position = player.get_movable_locations()[decision].1;
-                    process_turn(
-                        &mut commands,
-                        &mut app_state,
-                        &mut battle,
-                        &mut player,
-                        &mut ui_helper,
-                    );
-                }
-                BattleAction::Attack => {
-                    if let Some(selected_weapon) = player.selected_weapon {
-                        // Decision is about selecting a target
-                        let selected_target = battle.get_valid_target_mut(
-                            player.position,
-                            selected_weapon.range,
-                            decision,
-                        );
+    if let Some(result) = payment_result {
+        // Back from counting out the parley money
+        commands.remove_resource::<PaymentResult>();
+        finish_parley(
+            result.paid,
+            &mut commands,
+            &mut flow,
+            &mut ui_helper,
+            &mut player,
+            &mut battle,
+        );
+        return;
+    }
+    if actions.just_pressed(InputAction::Palette) && palette.is_none() {
+        let shortcuts = palette::shortcuts(&player, &battle);
+        player.clear_selections();
+        ui_helper.prompt(
+            t!("battle-palette-prompt"),
+            shortcuts
+                .iter()
+                .map(|shortcut| shortcut.label.clone())
+                .collect(),
+        );
+        commands.insert_resource(BattlePalette(shortcuts));
+        return;
+    }
+    if actions.just_pressed(InputAction::Back) {
+        if palette.is_some() {
+            commands.remove_resource::<BattlePalette>();
+            prompt_for_action(&mut ui_helper, player.get_battle_actions(&battle));
+        } else if ui_helper.back() {
+            // Undo whatever the prompt walked back from had picked
+            if player.selected_weapon.take().is_none() {
+                player.selected_action = None;
+            }
+        }
+        return;
+    }
+
+    let decision = if let Some(decision) = player.drain_decision() {
+        decision
+    } else {
+        return;
+    };
+    let decision = if let Some(palette) = palette {
+        // Set up as if each step had been picked in turn, then take the last one
+        let shortcut = palette.0[decision].clone();
+        commands.remove_resource::<BattlePalette>();
+        player.selected_action = shortcut.action;
+        player.selected_weapon = shortcut.weapon;
+        shortcut.decision
+    } else {
+        decision
+    };
+    decide(
+        decision,
+        &mut commands,
+        &mut flow,
+        &mut ui_helper,
+        &mut player,
+        &mut battle,
+    );
+}
 
-                        if player
-                            .resources
-                            .force_remove(selected_weapon.cost.unwrap_or_default())
-                        {
-                            // Could successfully afford to use that weapon
-                            let damage = selected_weapon.damage.roll();
-                            if damage < selected_target.health {
-                                selected_target.health -= damage;
-                                ui_helper.show_line(format!(
-                                    "Using your {} to deal {} damage to {}, it remains steadfast",
-                                    selected_weapon.name, damage, selected_target.name
-                                ));
+/// Takes the player's pick at whatever step of the turn they're at
+fn decide(
+    decision: usize,
+    commands: &mut Commands,
+    flow: &mut EventWriter<FlowEvent>,
+    ui_helper: &mut ResMut<UIHelper>,
+    player: &mut ResMut<Player>,
+    battle: &mut ResMut<OngoingBattle>,
+) {
+    if let Some(selected_action) = player.selected_action {
+        match selected_action {
+            BattleAction::Wait
+            | BattleAction::Flee
+            | BattleAction::Parley
+            | BattleAction::Surrender => {
+                panic!("How did you get here?");
+            }
+            BattleAction::Command => {
+                let command = player.get_dog_commands()[decision];
+                if DiceValue::from("1d6").roll() < player.dog.obedience_target() {
+                    ui_helper.show_line(t!("battle-dog-ignores", command = command.to_string()));
+                } else {
+                    match command {
+                        DogCommand::Bark => {
+                            if let Some(name) = battle.stun_closest() {
+                                ui_helper.show_line(t!("battle-dog-barks", enemy = tr(name)));
+                            }
+                        }
+                        DogCommand::Fetch => {
+                            if let Some(loot) = player.dog.fetch_loot().roll() {
+                                ui_helper
+                                    .show_line(t!("battle-dog-fetches", loot = loot.to_string()));
+                                battle.spoils.push(loot);
                             } else {
-                                ui_helper.show_line(format!(
-                                    "With your {} you rob {} of it's life",
-                                    selected_weapon.name, selected_target.name
-                                ));
-                                selected_target.health = 0;
+                                ui_helper.show_line(t!("battle-dog-stick"));
                             }
-                            battle.clean_out_dead();
-                            battle.place_enemies();
-                        } else {
-                            // Attempting to shoot, but resources are out
-                            ui_helper.show_line(
-                                "Try as you might, your resources ran dry before the crescendo",
-                            );
                         }
-
-                        process_turn(
-                            &mut commands,
-                            &mut app_state,
-                            &mut battle,
-                            &mut player,
-                            &mut ui_helper,
+                    }
+                }
+                process_turn(commands, flow, battle, player, ui_helper);
+            }
+            BattleAction::UseItem => {
+                let item = player.inventory.take_item(decision);
+                ui_helper.show_line(t!("battle-use-item", item = tr(item.name)));
+                match item.effect {
+                    ItemEffect::Smoke => {
+                        ui_helper.show_line(t!("battle-smoke"));
+                        end_battle(
+                            commands,
+                            flow,
+                            player,
+                            battle,
+                            ui_helper,
+                            BattleOutcome::Fled,
                         );
-                    } else {
-                        let selected_weapon = player.get_weapons()[decision];
-                        let valid_targets =
-                            battle.get_valid_targets(player.position, selected_weapon.range);
+                    }
+                    ItemEffect::Explode(damage) => {
+                        battle.damage_all(damage);
+                        for (name, loot) in battle.clean_out_dead() {
+                            ui_helper.show_line(t!(
+                                "battle-drops",
+                                enemy = tr(name),
+                                loot = loot.to_string()
+                            ));
+                        }
+                        battle.place_enemies();
+                        process_turn(commands, flow, battle, player, ui_helper);
+                    }
+                    ItemEffect::Restore(_) | ItemEffect::Feed(_) => {
+                        item.effect.apply(player);
+                        process_turn(commands, flow, battle, player, ui_helper);
+                    }
+                }
+            }
+            BattleAction::Move => {
+                player.position = player.get_movable_locations()[decision].1;
+                process_turn(commands, flow, battle, player, ui_helper);
+            }
+            BattleAction::Attack => {
+                if let Some(selected_weapon) = player.selected_weapon {
+                    // Decision is about selecting a target
+                    let selected_target = battle.get_valid_target_mut(
+                        player.position,
+                        selected_weapon.range,
+                        decision,
+                    );
 
-                        if valid_targets.len() > 0 {
-                            player.selected_weapon = Some(selected_weapon);
-                            prompt_for_target(&mut ui_helper, valid_targets);
+                    let loss = player
+                        .resources
+                        .force_remove(selected_weapon.cost.unwrap_or_default());
+                    for line in loss.lines() {
+                        ui_helper.show_line(line);
+                    }
+                    if loss.covered() {
+                        // Could successfully afford to use that weapon
+                        let damage = selected_weapon.damage.roll();
+                        let damage_dealt = damage.min(selected_target.health);
+                        if damage < selected_target.health {
+                            selected_target.health -= damage;
+                            ui_helper.show_line(t!(
+                                "battle-hit",
+                                weapon = tr(selected_weapon.name),
+                                damage = damage,
+                                enemy = tr(selected_target.name)
+                            ));
                         } else {
-                            ui_helper.show_line("Nobody in range for that I'm afraid");
-                            prompt_for_action(&mut ui_helper, player.get_battle_actions());
-                            player.clear_selections();
+                            ui_helper.show_line(t!(
+                                "battle-kill",
+                                weapon = tr(selected_weapon.name),
+                                enemy = tr(selected_target.name)
+                            ));
+                            selected_target.health = 0;
+                        }
+                        battle.stats.damage_dealt += damage_dealt;
+                        for (name, loot) in battle.clean_out_dead() {
+                            ui_helper.show_line(t!(
+                                "battle-drops",
+                                enemy = tr(name),
+                                loot = loot.to_string()
+                            ));
                         }
+                        battle.place_enemies();
+                    } else {
+                        // Attempting to shoot, but resources are out
+                        ui_helper.show_line(t!("battle-out-of-resources"));
+                    }
+
+                    process_turn(commands, flow, battle, player, ui_helper);
+                } else {
+                    let selected_weapon = player.get_weapons()[decision];
+                    let valid_targets =
+                        battle.get_valid_targets(player.position, selected_weapon.range);
+
+                    if valid_targets.len() > 0 {
+                        player.selected_weapon = Some(selected_weapon);
+                        prompt_for_target(ui_helper, valid_targets);
+                    } else {
+                        ui_helper.show_line(t!("battle-out-of-range"));
+                        prompt_for_action(ui_helper, player.get_battle_actions(battle));
+                        player.clear_selections();
                     }
                 }
             }
-        } else {
-            let selected_action = player.get_battle_actions()[decision];
-            player.selected_action = Some(selected_action);
-            match selected_action {
-                BattleAction::Move => {
-                    prompt_for_location(&mut ui_helper, player.get_movable_locations())
+        }
+    } else {
+        let selected_action = player.get_battle_actions(battle)[decision];
+        player.selected_action = Some(selected_action);
+        match selected_action {
+            BattleAction::Move => prompt_for_location(ui_helper, player.get_movable_locations()),
+            BattleAction::Attack => prompt_for_weapon(ui_helper, player.get_weapons()),
+            BattleAction::UseItem => prompt_for_item(ui_helper, player.get_items()),
+            BattleAction::Command => prompt_for_command(ui_helper, player.get_dog_commands()),
+            BattleAction::Wait => process_turn(commands, flow, battle, player, ui_helper),
+            BattleAction::Flee => {
+                let target = battle.escape_target(player.position);
+                let roll = DiceValue::from("1d6").roll();
+                if roll >= target {
+                    ui_helper.show_line(t!("battle-flee-success", roll = roll, target = target));
+                    end_battle(
+                        commands,
+                        flow,
+                        player,
+                        battle,
+                        ui_helper,
+                        BattleOutcome::Fled,
+                    );
+                } else {
+                    ui_helper.show_line(t!("battle-flee-failure", roll = roll, target = target));
+                    process_turn(commands, flow, battle, player, ui_helper);
                 }
-                BattleAction::Attack => prompt_for_weapon(&mut ui_helper, player.get_weapons()),
-                BattleAction::Wait => process_turn(
-                    &mut commands,
-                    &mut app_state,
-                    &mut battle,
-                    &mut player,
-                    &mut ui_helper,
-                ),
             }
+            BattleAction::Parley => {
+                let terms = battle.parley.unwrap();
+                let held = if player.resources.could_afford(&terms.cost) {
+                    // The rest is taken first, so paid money always buys the parley
+                    player.resources.remove_all_but_money(terms.cost)
+                } else {
+                    None
+                };
+                if held.is_none() {
+                    ui_helper.show_line(t!("battle-parley-refused"));
+                    process_turn(commands, flow, battle, player, ui_helper);
+                } else if terms.cost.money != DiceValue::default() {
+                    // Let the player see the price and count out their dice
+                    battle.parley_held = held;
+                    let purse = player.resources.money;
+                    start_payment(commands, flow, ui_helper, purse, terms.cost.money);
+                } else {
+                    finish_parley(true, commands, flow, ui_helper, player, battle);
+                }
+            }
+            BattleAction::Surrender => {
+                let terms = battle.surrender.unwrap();
+                let loss = player.resources.force_remove(terms.cost);
+                ui_helper.show_line(tr(terms.line));
+                for line in loss.lines() {
+                    ui_helper.show_line(line);
+                }
+                if player.has_lost() {
+                    flow.send(FlowEvent::PlayerDied);
+                } else {
+                    end_battle(
+                        commands,
+                        flow,
+                        player,
+                        battle,
+                        ui_helper,
+                        BattleOutcome::Surrendered,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Ends the battle if the parley money was paid, hands the rest of the terms back if not
+fn finish_parley(
+    paid: bool,
+    commands: &mut Commands,
+    flow: &mut EventWriter<FlowEvent>,
+    ui_helper: &mut ResMut<UIHelper>,
+    player: &mut ResMut<Player>,
+    battle: &mut ResMut<OngoingBattle>,
+) {
+    let terms = battle.parley.unwrap();
+    let held = battle.parley_held.take();
+    if paid {
+        ui_helper.show_line(tr(terms.line));
+        end_battle(
+            commands,
+            flow,
+            player,
+            battle,
+            ui_helper,
+            BattleOutcome::Parleyed,
+        );
+    } else {
+        if let Some(held) = held {
+            player.resources.add(held);
         }
+        ui_helper.show_line(t!("battle-parley-refused"));
+        process_turn(commands, flow, battle, player, ui_helper);
     }
 }
 
 fn process_turn(
     commands: &mut Commands,
-    app_state: &mut ResMut<State<AppState>>,
+    flow: &mut EventWriter<FlowEvent>,
     battle: &mut ResMut<OngoingBattle>,
     player: &mut ResMut<Player>,
     ui_helper: &mut ResMut<UIHelper>,
 ) {
     let mut rng = thread_rng();
+    let mut damage_taken = 0;
 
-    for lane in battle.lanes.iter_mut() {
+    for (lane_index, lane) in battle.lanes.iter_mut().enumerate() {
         if let Some(ref mut enemy) = lane {
-            if let Some(weapon) = enemy.weapons.choose(&mut rng) {
+            if enemy.stunned {
+                enemy.stunned = false;
+                ui_helper.show_line(t!("battle-stunned", enemy = tr(enemy.name)));
+            } else if let Some(weapon) = enemy.weapons.choose(&mut rng) {
                 if enemy.position_y > weapon.range {
                     // Move closer
                     enemy.position_y -= 1;
-                    ui_helper.show_line(format!("{} creeps closer", enemy.name))
+                    ui_helper.show_line(t!("battle-creeps-closer", enemy = tr(enemy.name)))
+                } else if lane_index as u32 == player.dog.position
+                    && !player.dog.is_dead()
+                    && rng.gen_bool(0.5)
+                {
+                    // Attack the dog
+                    enemy.position_y += 2;
+
+                    let damage = weapon.damage.roll();
+                    if player.dog.take_damage(damage) {
+                        ui_helper.show_highlighted_line(t!(
+                            "battle-dog-struck-down",
+                            enemy = tr(enemy.name),
+                            weapon = tr(weapon.name)
+                        ));
+                    } else {
+                        ui_helper.show_line(t!(
+                            "battle-dog-hurt",
+                            enemy = tr(enemy.name),
+                            weapon = tr(weapon.name),
+                            damage = damage
+                        ));
+                    }
                 } else {
                     // Attack
                     enemy.position_y += 2;
 
                     let damage = weapon.damage.roll() as i32;
+                    damage_taken += damage.min(player.resources.stamina);
                     if damage >= player.resources.stamina {
                         player.resources.stamina = 0;
-                        ui_helper.show_line(format!(
-                            "{} takes your life with the {}",
-                            enemy.name, weapon.name
+                        ui_helper.show_line(t!(
+                            "battle-killed",
+                            enemy = tr(enemy.name),
+                            weapon = tr(weapon.name)
                         ));
                     } else {
                         player.resources.stamina -= damage;
-                        ui_helper.show_line(format!(
-                            "{} uses {} to deal {} damage",
-                            enemy.name, weapon.name, damage
+                        ui_helper.show_line(t!(
+                            "battle-hurt",
+                            enemy = tr(enemy.name),
+                            weapon = tr(weapon.name),
+                            damage = damage
                         ));
                     }
                 }
             } else {
-                ui_helper.show_line(format!(
-                    "{} seems unarmed, but far from dangerous",
-                    enemy.name
-                ))
+                ui_helper.show_line(t!("battle-unarmed", enemy = tr(enemy.name)))
             }
         }
     }
 
-    if player.is_dead() {
-        app_state.set(AppState::GameOver).unwrap();
+    battle.stats.turns += 1;
+    battle.stats.damage_taken += damage_taken as u32;
+    battle.stats.lowest_stamina = battle.stats.lowest_stamina.min(player.resources.stamina);
+
+    if player.has_lost() {
+        flow.send(FlowEvent::PlayerDied);
     } else if battle.is_over() {
-        // Battle is over, return to previous state
-        commands.remove_resource::<OngoingBattle>();
-        app_state.pop().unwrap();
+        end_battle(
+            commands,
+            flow,
+            player,
+            battle,
+            ui_helper,
+            BattleOutcome::Won,
+        );
     } else {
         // Next round
-        prompt_for_action(ui_helper, player.get_battle_actions());
+        prompt_for_action(ui_helper, player.get_battle_actions(battle));
         player.clear_selections();
     }
 }
 
+fn end_battle(
+    commands: &mut Commands,
+    flow: &mut EventWriter<FlowEvent>,
+    player: &mut ResMut<Player>,
+    battle: &mut ResMut<OngoingBattle>,
+    ui_helper: &mut ResMut<UIHelper>,
+    outcome: BattleOutcome,
+) {
+    if outcome == BattleOutcome::Won {
+        if let Some(loot) = battle.loot.roll() {
+            battle.spoils.push(loot);
+        }
+    }
+    if !battle.spoils.is_empty() {
+        let spoils = battle
+            .spoils
+            .iter()
+            .map(|loot| loot.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        ui_helper.show_highlighted_line(t!("battle-spoils", loot = spoils));
+        for loot in battle.spoils.drain(..) {
+            if !player.receive(loot.clone()) {
+                ui_helper.show_line(t!("battle-no-room", loot = loot.to_string()));
+            }
+        }
+    }
+
+    // Return to previous state, the encounter picks up the result from there
+    player.clear_selections();
+    commands.remove_resource::<OngoingBattle>();
+    commands.insert_resource(battle.result(outcome));
+    flow.send(FlowEvent::BattleFinished);
+}
+
 fn prompt_for_action(ui_helper: &mut ResMut<UIHelper>, options: Vec<BattleAction>) {
     ui_helper.prompt(
-        "How do thy wish to occupy thine time",
+        t!("battle-action-prompt"),
         options.iter().map(|option| format!("{}", option)).collect(),
     );
 }
 fn prompt_for_weapon(ui_helper: &mut ResMut<UIHelper>, weapons: Vec<Weapon>) {
-    ui_helper.prompt(
-        "Once more into the frey, choose thy means of destruction",
-        weapons.iter().map(|weapon| weapon.name).collect(),
+    ui_helper.follow_up(
+        t!("battle-weapon-prompt"),
+        weapons.iter().map(|weapon| tr(weapon.name)).collect(),
+    );
+}
+
+fn prompt_for_item(ui_helper: &mut ResMut<UIHelper>, items: Vec<Item>) {
+    ui_helper.follow_up(
+        t!("battle-item-prompt"),
+        items.iter().map(|item| tr(item.name)).collect(),
+    );
+}
+
+fn prompt_for_command(ui_helper: &mut ResMut<UIHelper>, commands: Vec<DogCommand>) {
+    ui_helper.follow_up(
+        t!("battle-command-prompt"),
+        commands
+            .iter()
+            .map(|command| format!("{}", command))
+            .collect(),
     );
 }
 
 fn prompt_for_target(ui_helper: &mut ResMut<UIHelper>, enemies: Vec<Enemy>) {
-    ui_helper.prompt(
-        "Who is the unfortunate soul to bear the brunt of your fury",
-        enemies.iter().map(|enemy| enemy.name).collect(),
+    ui_helper.follow_up(
+        t!("battle-target-prompt"),
+        enemies.iter().map(|enemy| tr(enemy.name)).collect(),
     );
 }
 
 fn prompt_for_location(ui_helper: &mut ResMut<UIHelper>, locations: Vec<(&'static str, u32)>) {
-    ui_helper.prompt(
-        "Whereabouts",
-        locations.iter().map(|location| location.0).collect(),
+    ui_helper.follow_up(
+        t!("battle-location-prompt"),
+        locations.iter().map(|location| tr(location.0)).collect(),
     );
 }
+
+#[cfg(test)]
+mod test {
+    use bevy::prelude::*;
+
+    use super::{reset, visuals::BattleVisual, Battle, Enemy, OngoingBattle, BATTLE_ARENA_WIDTH};
+
+    fn battle_with_distances(distances: Vec<u32>) -> Battle {
+        let mut battle = Battle::with(
+            distances
+                .into_iter()
+                .map(|position_y| Enemy {
+                    position_y,
+                    ..Default::default()
+                })
+                .collect(),
+        );
+        battle.place_enemies();
+        battle
+    }
+
+    #[test]
+    fn escape_is_easier_with_distance() {
+        let far = battle_with_distances(vec![3, 3]);
+        let close = battle_with_distances(vec![1, 0]);
+        assert!(far.escape_target(1) < close.escape_target(1));
+    }
+
+    #[test]
+    fn escape_is_easier_from_the_edge() {
+        let battle = battle_with_distances(vec![2]);
+        assert!(battle.escape_target(0) < battle.escape_target(1));
+        assert!(battle.escape_target(BATTLE_ARENA_WIDTH - 1) < battle.escape_target(1));
+    }
+
+    #[test]
+    fn escape_is_never_certain_or_impossible() {
+        let empty = battle_with_distances(vec![]);
+        assert_eq!(empty.escape_target(0), 3);
+
+        let swarmed = battle_with_distances(vec![0, 0, 1, 1]);
+        assert_eq!(swarmed.escape_target(1), 6);
+    }
+
+    #[test]
+    fn reset_clears_the_arena() {
+        let mut world = World::new();
+        world.insert_resource(OngoingBattle(battle_with_distances(vec![1])));
+        let tile = world.spawn().insert(BattleVisual).id();
+        let bystander = world.spawn().id();
+        reset(&mut world);
+        assert!(world.get_resource::<OngoingBattle>().is_none());
+        assert!(world.get_entity(tile).is_none());
+        assert!(world.get_entity(bystander).is_some());
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{
+    locale::{t, tr},
+    player::{BattleAction, Player},
+};
+
+use super::{Battle, Weapon};
+
+/// A whole turn picked in one go: what would have been selected along the way, and the last pick
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Shortcut {
+    pub label: String,
+    pub action: Option<BattleAction>,
+    pub weapon: Option<Weapon>,
+    pub decision: usize,
+}
+
+/// Every turn the player could take right now, action, weapon and target together
+pub fn shortcuts(player: &Player, battle: &Battle) -> Vec<Shortcut> {
+    let mut shortcuts = vec![];
+    for (index, action) in player.get_battle_actions(battle).into_iter().enumerate() {
+        let step = |label: String, decision: usize| Shortcut {
+            label,
+            action: Some(action),
+            weapon: None,
+            decision,
+        };
+        match action {
+            BattleAction::Move => {
+                for (decision, (name, _)) in player.get_movable_locations().iter().enumerate() {
+                    shortcuts.push(step(t!("palette-move", place = tr(name)), decision));
+                }
+            }
+            BattleAction::Attack => {
+                for weapon in player.get_weapons() {
+                    let targets = battle.get_valid_targets(player.position, weapon.range);
+                    for (decision, target) in targets.iter().enumerate() {
+                        shortcuts.push(Shortcut {
+                            weapon: Some(weapon),
+                            ..step(
+                                t!(
+                                    "palette-attack",
+                                    enemy = tr(target.name),
+                                    weapon = tr(weapon.name)
+                                ),
+                                decision,
+                            )
+                        });
+                    }
+                }
+            }
+            BattleAction::UseItem => {
+                for (decision, item) in player.get_items().iter().enumerate() {
+                    shortcuts.push(step(t!("palette-use", item = tr(item.name)), decision));
+                }
+            }
+            BattleAction::Command => {
+                for (decision, command) in player.get_dog_commands().iter().enumerate() {
+                    shortcuts.push(step(
+                        t!("palette-command", command = command.to_string()),
+                        decision,
+                    ));
+                }
+            }
+            BattleAction::Wait
+            | BattleAction::Flee
+            | BattleAction::Parley
+            | BattleAction::Surrender => shortcuts.push(Shortcut {
+                label: action.to_string(),
+                action: None,
+                weapon: None,
+                decision: index,
+            }),
+        }
+    }
+    shortcuts
+}
+
+#[cfg(test)]
+mod test {
+    use super::shortcuts;
+    use crate::{
+        battle::{Battle, Enemy},
+        player::{BattleAction, Player},
+    };
+
+    #[test]
+    fn every_step_is_filled_in() {
+        let mut battle = Battle::with(vec![Enemy {
+            position_y: 0,
+            ..Default::default()
+        }]);
+        battle.place_enemies();
+        let mut player = Player::new();
+        player.position = 0;
+        let shortcuts = shortcuts(&player, &battle);
+
+        let attacks: Vec<_> = shortcuts
+            .iter()
+            .filter(|shortcut| shortcut.action == Some(BattleAction::Attack))
+            .collect();
+        assert!(!attacks.is_empty());
+        assert!(attacks.iter().all(|attack| attack.weapon.is_some()));
+
+        // From the left edge there's only one way to go
+        let moves: Vec<_> = shortcuts
+            .iter()
+            .filter(|shortcut| shortcut.action == Some(BattleAction::Move))
+            .collect();
+        assert_eq!(moves.len(), 1);
+        assert_eq!(moves[0].label, "move right");
+
+        // One step actions are picked straight from the action prompt
+        let actions = player.get_battle_actions(&battle);
+        let flee = shortcuts
+            .iter()
+            .find(|shortcut| shortcut.label == "flee")
+            .unwrap();
+        assert_eq!(flee.action, None);
+        assert_eq!(actions[flee.decision], BattleAction::Flee);
+    }
+}
@@ -0,0 +1,101 @@
+/// How a battle ended
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum BattleOutcome {
+    Won,
+    Fled,
+    Parleyed,
+    Surrendered,
+}
+
+/// Running tally of what happened during a battle
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct BattleStats {
+    pub turns: u32,
+    pub damage_dealt: u32,
+    pub damage_taken: u32,
+    pub enemies_killed: u32,
+    /// Enemies still standing when the battle ended
+    pub enemies_left: u32,
+    pub lowest_stamina: i32,
+}
+
+/// Handed back to the encounter that started the battle
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct BattleResult {
+    pub outcome: BattleOutcome,
+    pub stats: BattleStats,
+}
+
+/// Something encounters can branch on after a battle
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum BattleCondition {
+    Outcome(BattleOutcome),
+    /// Won in at most this many turns
+    WonWithin(u32),
+    KilledAtLeast(u32),
+    /// Stamina dipped to or below this at some point
+    NearDeath(i32),
+    Always,
+}
+impl BattleCondition {
+    pub fn holds(&self, result: &BattleResult) -> bool {
+        match *self {
+            BattleCondition::Outcome(outcome) => result.outcome == outcome,
+            BattleCondition::WonWithin(turns) => {
+                result.outcome == BattleOutcome::Won && result.stats.turns <= turns
+            }
+            BattleCondition::KilledAtLeast(kills) => result.stats.enemies_killed >= kills,
+            BattleCondition::NearDeath(stamina) => result.stats.lowest_stamina <= stamina,
+            BattleCondition::Always => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{BattleCondition, BattleOutcome, BattleResult, BattleStats};
+
+    fn result(outcome: BattleOutcome, stats: BattleStats) -> BattleResult {
+        BattleResult { outcome, stats }
+    }
+
+    #[test]
+    fn quick_win() {
+        let quick = result(
+            BattleOutcome::Won,
+            BattleStats {
+                turns: 3,
+                ..Default::default()
+            },
+        );
+        let slow = result(
+            BattleOutcome::Won,
+            BattleStats {
+                turns: 4,
+                ..Default::default()
+            },
+        );
+        assert!(BattleCondition::WonWithin(3).holds(&quick));
+        assert!(!BattleCondition::WonWithin(3).holds(&slow));
+    }
+
+    #[test]
+    fn fleeing_quickly_is_not_winning() {
+        let fled = result(BattleOutcome::Fled, BattleStats::default());
+        assert!(!BattleCondition::WonWithin(3).holds(&fled));
+        assert!(BattleCondition::Outcome(BattleOutcome::Fled).holds(&fled));
+    }
+
+    #[test]
+    fn near_death() {
+        let close_call = result(
+            BattleOutcome::Won,
+            BattleStats {
+                lowest_stamina: 5,
+                ..Default::default()
+            },
+        );
+        assert!(BattleCondition::NearDeath(10).holds(&close_call));
+        assert!(!BattleCondition::NearDeath(4).holds(&close_call));
+    }
+}
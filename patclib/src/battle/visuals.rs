@@ -1,81 +1,182 @@
 use std::f32::consts::PI;
 
-use bevy::prelude::*;
+use bevy::{prelude::*, window::WindowResized};
 
-use crate::{assets::AssetHandles, player::Player};
+use crate::{assets::AssetHandles, companion::Dog, player::Player, ui::UiLayout};
 
 use super::{Enemy, OngoingBattle, BATTLE_ARENA_DEPTH, BATTLE_ARENA_WIDTH};
 
+/// Everything drawn for a battle, so it can all be cleared away afterwards
+#[derive(Debug, Component)]
+pub(super) struct BattleVisual;
+
 pub(super) fn init(
     commands: &mut Commands,
     assets: &Res<AssetHandles>,
     battle: &mut ResMut<OngoingBattle>,
-    player: &Res<Player>,
+    player: &Player,
+    windows: &Windows,
 ) {
-    draw_squares(commands, assets);
-    draw_enemies(commands, assets, battle.lanes.clone());
+    let grid = windows.get_primary().map_or_else(Grid::default, |window| {
+        Grid::fit(window.width(), window.height())
+    });
+    draw_squares(commands, assets, &grid);
+    draw_enemies(commands, assets, &grid, battle.lanes.clone());
     draw_player(commands, assets, player);
+    draw_dog(commands, assets, &grid, &player.dog);
+}
+
+/// Draws the arena again to fit a window that changed size
+pub(super) fn refit(
+    mut commands: Commands,
+    assets: Res<AssetHandles>,
+    (battle, player): (Option<ResMut<OngoingBattle>>, Res<Player>),
+    (windows, mut resized): (Res<Windows>, EventReader<WindowResized>),
+    visuals: Query<Entity, With<BattleVisual>>,
+) {
+    let mut battle = match battle {
+        Some(battle) if resized.iter().count() > 0 => battle,
+        _ => return,
+    };
+    for entity in visuals.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    init(&mut commands, &assets, &mut battle, &player, &windows);
+}
+
+pub(super) fn clear(mut commands: Commands, visuals: Query<Entity, With<BattleVisual>>) {
+    for entity in visuals.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub(super) fn despawn_all(world: &mut World) {
+    let visuals: Vec<Entity> = world
+        .query_filtered::<Entity, With<BattleVisual>>()
+        .iter(world)
+        .collect();
+    for entity in visuals {
+        world.entity_mut(entity).despawn_recursive();
+    }
 }
 
-fn draw_squares(commands: &mut Commands, assets: &Res<AssetHandles>) {
+fn draw_squares(commands: &mut Commands, assets: &Res<AssetHandles>, grid: &Grid) {
     for y in 0..BATTLE_ARENA_DEPTH {
         for x in 0..BATTLE_ARENA_WIDTH {
-            draw_tile(commands, assets, x, y);
+            draw_tile(commands, assets, grid, x, y);
         }
     }
 }
 
+/// The sizes below are at a scale of 1, the grid scales them to fit the window
+const BASE_TILE_WIDTH: f32 = 200.0;
 const TILE_X_GAP: f32 = 10.0;
 const TILE_Y_GAP: f32 = 10.0;
-const TILE_WIDTH: f32 = 200.0;
 const TILE_HEIGHT: f32 = 130.0;
 
-const TILE_X_OFFSET: f32 = -((BATTLE_ARENA_WIDTH - 1) as f32 * TILE_WIDTH / 2.0);
-const TILE_Y_OFFSET: f32 = 0.0;
+/// Share of the window's width the arena can take up
+const ARENA_WIDTH_SHARE: f32 = 0.9;
+/// Share of the room between the top and bottom bars the arena can take up
+const ARENA_HEIGHT_SHARE: f32 = 0.9;
 
 const TILE_ANGLE_OFFSET: f32 = 2.0 * PI / (30.0);
-const TILE_SCALE_CHANGE: f32 = 0.02 * TILE_WIDTH;
+const TILE_SCALE_CHANGE: f32 = 0.02 * BASE_TILE_WIDTH;
 
 const ENEMY_SIZE_X: f32 = 100.0;
 const ENEMY_SIZE_Y: f32 = 100.0;
 
-fn tile_position(x: u32, y: u32) -> Vec3 {
-    tile_position_default(x, y, 0.0, 0.0)
+const DOG_SIZE_X: f32 = 80.0;
+const DOG_SIZE_Y: f32 = 80.0;
+
+/// Where the arena goes on screen, fitted between the bars
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Grid {
+    /// How much bigger than the base sizes everything is drawn
+    scale: f32,
+    /// Where the middle of the front row is
+    origin: Vec2,
+}
+impl Default for Grid {
+    /// Without a window to fit, the arena is drawn at its base size
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            origin: Vec2::new(
+                -((BATTLE_ARENA_WIDTH - 1) as f32 * BASE_TILE_WIDTH / 2.0),
+                0.0,
+            ),
+        }
+    }
 }
+impl Grid {
+    /// The biggest arena that fits a window of `width` by `height`, centered in the room left
+    fn fit(width: f32, height: f32) -> Self {
+        let layout = UiLayout::fit(width, height);
+        // The camera looks at the middle of the window
+        let top = height / 2.0 - layout.top_bar_height;
+        let bottom = layout.bottom_bar_height - height / 2.0;
+        let room = (top - bottom) * ARENA_HEIGHT_SHARE;
+        let arena_width = BATTLE_ARENA_WIDTH as f32 * BASE_TILE_WIDTH;
+        let arena_height = BATTLE_ARENA_DEPTH as f32 * TILE_HEIGHT;
+        let scale = (width * ARENA_WIDTH_SHARE / arena_width).min(room / arena_height);
+        Self {
+            scale,
+            origin: Vec2::new(
+                -((BATTLE_ARENA_WIDTH - 1) as f32 * BASE_TILE_WIDTH * scale / 2.0),
+                (top + bottom) / 2.0 - (BATTLE_ARENA_DEPTH - 1) as f32 * TILE_HEIGHT * scale / 2.0,
+            ),
+        }
+    }
+
+    fn tile_position(&self, x: u32, y: u32) -> Vec3 {
+        self.position(x, y, 0.0, 0.0)
+    }
+
+    /// `x_offset` and `y_offset` are at the base size
+    fn position(&self, x: u32, y: u32, x_offset: f32, y_offset: f32) -> Vec3 {
+        Vec3::new(
+            self.origin.x
+                + self.scale
+                    * ((x as f32) * BASE_TILE_WIDTH
+                        + (((BATTLE_ARENA_DEPTH - y) as f32) * (TILE_SCALE_CHANGE + x_offset))),
+            self.origin.y + self.scale * ((y as f32) * TILE_HEIGHT + y_offset),
+            0.0,
+        )
+    }
 
-fn tile_position_default(x: u32, y: u32, x_offset: f32, y_offset: f32) -> Vec3 {
-    Vec3::new(
-        (x as f32) * TILE_WIDTH
-            + TILE_X_OFFSET
-            + (((BATTLE_ARENA_DEPTH - y) as f32) * (TILE_SCALE_CHANGE + x_offset)),
-        (y as f32) * TILE_HEIGHT + TILE_Y_OFFSET + y_offset,
-        0.0,
-    )
+    fn size(&self, width: f32, height: f32) -> Vec2 {
+        Vec2::new(width, height) * self.scale
+    }
 }
 
-fn draw_tile(commands: &mut Commands, assets: &Res<AssetHandles>, x: u32, y: u32) {
+fn draw_tile(commands: &mut Commands, assets: &Res<AssetHandles>, grid: &Grid, x: u32, y: u32) {
     let bundle: SpriteBundle = SpriteBundle {
         transform: Transform {
-            translation: tile_position(x, y),
+            translation: grid.tile_position(x, y),
             rotation: Quat::from_rotation_y(((y as f32) + 1.0) * TILE_ANGLE_OFFSET),
             ..default()
         },
         sprite: Sprite {
-            custom_size: Some(Vec2::new(TILE_WIDTH - TILE_X_GAP, TILE_HEIGHT - TILE_Y_GAP)),
+            custom_size: Some(grid.size(BASE_TILE_WIDTH - TILE_X_GAP, TILE_HEIGHT - TILE_Y_GAP)),
             color: assets.colors.battle_tile,
             ..default()
         },
         ..default()
     };
-    commands.spawn_bundle(bundle);
+    commands.spawn_bundle(bundle).insert(BattleVisual);
 }
 
-fn draw_enemies(commands: &mut Commands, assets: &Res<AssetHandles>, lanes: Vec<Option<Enemy>>) {
+fn draw_enemies(
+    commands: &mut Commands,
+    assets: &Res<AssetHandles>,
+    grid: &Grid,
+    lanes: Vec<Option<Enemy>>,
+) {
     for lane in lanes.iter() {
         if let Some(enemy) = lane {
             let bundle: SpriteBundle = SpriteBundle {
                 transform: Transform {
-                    translation: tile_position_default(
+                    translation: grid.position(
                         enemy.position_x,
                         enemy.position_y,
                         0.0,
@@ -84,16 +185,60 @@ fn draw_enemies(commands: &mut Commands, assets: &Res<AssetHandles>, lanes: Vec<
                     ..default()
                 },
                 sprite: Sprite {
-                    custom_size: Some(Vec2::new(ENEMY_SIZE_X, ENEMY_SIZE_Y)),
+                    custom_size: Some(grid.size(ENEMY_SIZE_X, ENEMY_SIZE_Y)),
                     ..default()
                 },
                 texture: enemy.handle_image.clone(),
                 ..default()
             };
-            commands.spawn_bundle(bundle);
+            commands.spawn_bundle(bundle).insert(BattleVisual);
         }
     }
 }
-fn draw_player(commands: &mut Commands, assets: &Res<AssetHandles>, player: &Res<Player>) {
+fn draw_player(commands: &mut Commands, assets: &Res<AssetHandles>, player: &Player) {
     //todo!()
 }
+
+fn draw_dog(commands: &mut Commands, assets: &Res<AssetHandles>, grid: &Grid, dog: &Dog) {
+    let bundle: SpriteBundle = SpriteBundle {
+        transform: Transform {
+            translation: grid.position(dog.position, 0, 0.0, DOG_SIZE_Y / 2.0),
+            ..default()
+        },
+        sprite: Sprite {
+            custom_size: Some(grid.size(DOG_SIZE_X, DOG_SIZE_Y)),
+            ..default()
+        },
+        texture: assets.images.dog.clone(),
+        ..default()
+    };
+    commands.spawn_bundle(bundle).insert(BattleVisual);
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Grid, BASE_TILE_WIDTH, BATTLE_ARENA_DEPTH, BATTLE_ARENA_WIDTH};
+
+    #[test]
+    fn the_arena_fits_the_window() {
+        for (width, height) in [
+            (1280.0, 720.0),
+            (800.0, 600.0),
+            (2560.0, 1440.0),
+            (3000.0, 500.0),
+        ] {
+            let grid = Grid::fit(width, height);
+            let left = grid.tile_position(0, 0).x - grid.scale * BASE_TILE_WIDTH / 2.0;
+            let right = grid.tile_position(BATTLE_ARENA_WIDTH - 1, 0).x
+                + grid.scale * BASE_TILE_WIDTH / 2.0;
+            assert!(left >= -width / 2.0 && right <= width / 2.0);
+
+            // Between the top bar and the chat at the bottom
+            let back = grid.tile_position(0, BATTLE_ARENA_DEPTH - 1).y;
+            let front = grid.tile_position(0, 0).y;
+            assert!(back < height / 2.0 && front > -height / 2.0 + height * 0.4);
+        }
+        let big = Grid::fit(2560.0, 1440.0);
+        assert!(big.scale > Grid::fit(1280.0, 720.0).scale);
+    }
+}
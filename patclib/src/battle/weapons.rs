@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+use crate::player::PlayerResources;
+
+use super::Weapon;
+
+pub fn sawn_off_shotgun() -> Weapon {
+    Weapon {
+        name: "weapon-sawn-off-shotgun",
+        damage: "3d6".into(),
+        range: 2,
+        cost: Some(PlayerResources {
+            bullets: 2,
+            ..default()
+        }),
+    }
+}
+
+pub fn cattle_prod() -> Weapon {
+    Weapon {
+        name: "weapon-cattle-prod",
+        damage: "2d6".into(),
+        range: 1,
+        cost: Some(PlayerResources {
+            batteries: "6".into(),
+            ..default()
+        }),
+    }
+}
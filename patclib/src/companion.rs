@@ -0,0 +1,134 @@
+use core::fmt;
+
+use bevy::prelude::*;
+
+use crate::{
+    inventory::jerky,
+    locale::tr,
+    loot::{Loot, LootTable},
+    player::PlayerResources,
+};
+
+pub const DOG_MAX_HEALTH: u32 = 20;
+const MAX_TRUST: i32 = 10;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum DogCommand {
+    Bark,
+    Fetch,
+}
+impl fmt::Display for DogCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let id = match self {
+            DogCommand::Bark => "command-bark",
+            DogCommand::Fetch => "command-fetch",
+        };
+        write!(f, "{}", tr(id))
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Dog {
+    pub health: u32,
+    /// How much the dog trusts the player, anywhere from -MAX_TRUST to MAX_TRUST
+    pub trust: i32,
+    /// Lane the dog is in during battle
+    pub position: u32,
+}
+impl Default for Dog {
+    fn default() -> Self {
+        Self {
+            health: DOG_MAX_HEALTH,
+            trust: 0,
+            position: 0,
+        }
+    }
+}
+impl Dog {
+    pub fn is_dead(&self) -> bool {
+        self.health == 0
+    }
+
+    pub fn change_trust(&mut self, amount: i32) {
+        self.trust = (self.trust + amount).clamp(-MAX_TRUST, MAX_TRUST);
+    }
+
+    /// Returns true if that killed the poor thing
+    pub fn take_damage(&mut self, damage: u32) -> bool {
+        self.health = self.health.saturating_sub(damage);
+        self.is_dead()
+    }
+
+    /// What a 1d6 needs to roll at least for the dog to do as it's told
+    pub fn obedience_target(&self) -> u32 {
+        (4 - self.trust / 4).clamp(2, 6) as u32
+    }
+
+    /// Good dogs find better stuff
+    pub fn sniff_loot(&self) -> LootTable {
+        LootTable::with(vec![
+            (
+                2,
+                Loot::Resources(PlayerResources {
+                    money: "2d6".into(),
+                    ..default()
+                }),
+            ),
+            (1, Loot::Item(jerky())),
+        ])
+        .or_nothing((MAX_TRUST - self.trust) as u32 / 4)
+    }
+
+    /// What the dog drags back from a battlefield
+    pub fn fetch_loot(&self) -> LootTable {
+        LootTable::with(vec![
+            (
+                1,
+                Loot::Resources(PlayerResources {
+                    bullets: 2,
+                    ..default()
+                }),
+            ),
+            (
+                1,
+                Loot::Resources(PlayerResources {
+                    batteries: "1d6".into(),
+                    ..default()
+                }),
+            ),
+        ])
+        .or_nothing(1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Dog, DOG_MAX_HEALTH, MAX_TRUST};
+
+    #[test]
+    fn trust_is_capped() {
+        let mut dog = Dog::default();
+        dog.change_trust(100);
+        assert_eq!(dog.trust, MAX_TRUST);
+        dog.change_trust(-100);
+        assert_eq!(dog.trust, -MAX_TRUST);
+    }
+
+    #[test]
+    fn trusting_dogs_listen() {
+        let mut dog = Dog::default();
+        assert_eq!(dog.obedience_target(), 4);
+        dog.change_trust(MAX_TRUST);
+        assert_eq!(dog.obedience_target(), 2);
+        dog.change_trust(-2 * MAX_TRUST);
+        assert_eq!(dog.obedience_target(), 6);
+    }
+
+    #[test]
+    fn damage() {
+        let mut dog = Dog::default();
+        assert!(!dog.take_damage(DOG_MAX_HEALTH - 1));
+        assert!(dog.take_damage(DOG_MAX_HEALTH));
+        assert_eq!(dog.health, 0);
+    }
+}
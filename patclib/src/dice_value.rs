@@ -17,23 +17,24 @@ impl DiceValue {
                 .map(|dice_size| rng.gen_range(1..=dice_size))
                 .sum::<i32>()) as u32
     }
-    pub fn drained_to_match(&self, target: u32) -> Option<DiceValue> {
-        let mut rng = thread_rng();
-        let mut target = target as i32 - self.constant;
-        for dice_used in 0..=self.dice {
-            if target <= 0 {
-                return Some(DiceValue {
-                    dice: self.dice - dice_used,
-                    constant: self.constant,
-                });
-            }
-            target -= rng.gen_range(1..=6);
-        }
-        None
+    /// Every die rolling a one
+    pub fn minimum(&self) -> u32 {
+        (self.dice as i32 + self.constant).max(0) as u32
     }
     pub fn theoretical_limit(&self) -> u32 {
         (6 * self.dice as i32 + self.constant) as u32
     }
+    /// Rounded down
+    pub fn average(&self) -> u32 {
+        ((7 * self.dice as i32) / 2 + self.constant).max(0) as u32
+    }
+    /// Scales both the dice and the constant, rounding to the closest whole number
+    pub fn scaled(&self, percent: u32) -> DiceValue {
+        DiceValue {
+            dice: (self.dice * percent + 50) / 100,
+            constant: (self.constant * percent as i32 + 50).div_euclid(100),
+        }
+    }
     pub fn as_string(&self) -> String {
         let mut bits = vec![];
         if self.dice > 0 {
@@ -165,6 +166,32 @@ mod test {
         assert!(parsed == expected);
     }
 
+    #[test]
+    fn scaled_up() {
+        let scaled = DiceValue::from("2d6+10").scaled(150);
+        let expected = DiceValue {
+            dice: 3,
+            constant: 15,
+        };
+        assert!(scaled == expected);
+    }
+
+    #[test]
+    fn scaled_rounds() {
+        let scaled = DiceValue::from("1d6+5").scaled(90);
+        let expected = DiceValue {
+            dice: 1,
+            constant: 5,
+        };
+        assert!(scaled == expected);
+    }
+
+    #[test]
+    fn average() {
+        assert!(DiceValue::from("2d6+1").average() == 8);
+        assert!(DiceValue::from("1d6").average() == 3);
+    }
+
     #[test]
     fn const_parse_negative() {
         let parsed: DiceValue = "-50".into();
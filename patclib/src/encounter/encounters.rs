@@ -2,232 +2,235 @@ use bevy::prelude::*;
 use rand::seq::SliceRandom;
 
 use crate::{
-    battle::{Battle, Enemy, Weapon},
+    battle::{sawn_off_shotgun, Battle, BattleCondition, BattleOutcome, Enemy, Weapon},
+    inventory::{jerky, WeaponUpgrade},
+    loot::{Loot, LootTable},
+    minigame::Minigame,
     player::PlayerResources,
+    shop::ShopId,
+    travel::NodeKind,
+    ui::{Expression, Speaker},
 };
 
 use super::{Encounter, EncounterDecision, EncounterPhase};
 
-pub fn get_random_encounter() -> Encounter {
+/// Where the player is headed decides what they can run into
+pub fn get_random_encounter(kind: NodeKind) -> Encounter {
     let mut rng = rand::thread_rng();
-    encounters().choose(&mut rng).unwrap().to_owned()
+    encounters(kind).choose(&mut rng).unwrap().to_owned()
 }
 
-fn encounters() -> Vec<Encounter> {
+fn encounters(kind: NodeKind) -> Vec<Encounter> {
+    match kind {
+        NodeKind::Town => vec![town(), gamblers()],
+        NodeKind::Merchant => vec![merchant(), dog_buyer(), prospector()],
+        NodeKind::Wilds => vec![
+            test_encounter(wolves()),
+            electric_sheep(),
+            prospector(),
+            scent(),
+        ],
+        NodeKind::Danger => vec![wolf_fight(wolves()), chase(), electric_sheep()],
+    }
+}
+
+fn wolves() -> Battle {
     let wolf = Enemy {
         name: "",
         health: 10,
         weapons: vec![
             Weapon {
-                name: "The Jaws that Bite",
+                name: "weapon-jaws-that-bite",
                 damage: "2d6".into(),
                 ..default()
             },
             Weapon {
-                name: "The Claws that Catch",
+                name: "weapon-claws-that-catch",
                 damage: "2d6".into(),
                 ..default()
             },
         ],
+        loot: LootTable::with(vec![(1, Loot::Item(jerky()))]).or_nothing(2),
         ..default()
     };
 
-    let wolves = Battle::with(vec![
+    Battle::with(vec![
         Enemy {
-            name: "Gamma wolf",
+            name: "enemy-gamma-wolf",
             ..wolf.clone()
         },
         Enemy {
-            name: "Beta wolf",
+            name: "enemy-beta-wolf",
             ..wolf.clone()
         },
         Enemy {
-            name: "Scientifically accurate alpha wolf",
-            ..wolf.clone()
-        },
-    ]);
-
-    let stamina_drain = EncounterPhase::Lose(
-        "All this effort is exhausting",
-        PlayerResources {
-            stamina: 1,
-            ..default()
+            name: "enemy-alpha-wolf",
+            ..wolf
         },
-    );
-
-    vec![
-        test_encounter(wolves.clone(), stamina_drain.clone()),
-        wolf_fight(wolves, stamina_drain.clone()),
-        electric_sheep(stamina_drain.clone()),
-        merchant(),
-        chase(),
-        town(),
-    ]
+    ])
 }
 
-fn town() -> Encounter {
-    let bullet_trade = EncounterPhase::Trade(
-        "You get some ammo.",
-        "Unfortunately your math rocks failed you today, the marchant gets huffy.",
-        PlayerResources {
-            money: "10".into(),
-            ..default()
-        },
-        PlayerResources {
-            bullets: 6,
-            ..default()
-        },
-    );
-
-    let food_trade = EncounterPhase::Trade(
-        "You manage to gain some energy.",
-        "You don't have enough money.",
-        PlayerResources {
-            money: "8".into(),
-            ..default()
-        },
-        PlayerResources {
-            stamina: 4,
-            ..default()
-        },
-    );
-
-    let battery_trade = EncounterPhase::Loop(vec![
-        EncounterPhase::Trade(
-            "It's a box of batteries. What did you expect?",
-            "Dry on cash are we?",
-            PlayerResources {
-                money: "20".into(),
-                ..default()
-            },
-            PlayerResources {
-                batteries: "10d6".into(),
-                ..default()
-            },
-        ),
-        EncounterPhase::Line(
-            "The box contains absolutely nothing, as you look back up, the merchant grins at you",
-        ),
-        EncounterPhase::Break,
-    ]);
+fn gamblers() -> Encounter {
+    let sit_down = |game, stake| {
+        Box::new(EncounterPhase::Loop(vec![
+            EncounterPhase::Minigame(game, stake),
+            EncounterPhase::Break,
+        ]))
+    };
 
-    let trade_options = vec![
-        ("Bullets, 6 for 10 dice value!", Box::new(bullet_trade)),
-        (
-            "Food, 4 points of stamina for 8 dice value",
-            Box::new(food_trade),
-        ),
-        ("Battery box, 10 for 20 dice value", Box::new(battery_trade)),
-    ];
+    let tables = EncounterPhase::Loop(vec![EncounterPhase::Decision(EncounterDecision {
+        prompt: "gamblers-prompt",
+        options: vec![
+            ("gamblers-craps", sit_down(Minigame::Craps, 5)),
+            ("gamblers-liars-dice", sit_down(Minigame::LiarsDice, 10)),
+            ("gamblers-revolver", sit_down(Minigame::Revolver, 10)),
+            ("gamblers-leave", Box::new(EncounterPhase::Break)),
+        ],
+    })]);
 
-    let trade_loop = EncounterPhase::Loop(vec![
-        EncounterPhase::Decision(EncounterDecision {
-            prompt: "Do you wish to engage in trade?",
-            options: vec![
-                (
-                    "Sure, why not.",
-                    Box::new(EncounterPhase::Line(
-                        "The merchant opens his coat to reveal trinkets and baubles of all sorts",
-                    )),
-                ),
-                ("Maybe some other time.", Box::new(EncounterPhase::Break)),
-            ],
-        }),
-        EncounterPhase::Decision(EncounterDecision {
-            prompt: "Anything in particular?",
-            options: trade_options,
-        }),
-        EncounterPhase::Line("Still interested?"),
-    ]);
+    Encounter::from_phases(vec![
+        EncounterPhase::Line("gamblers-intro"),
+        EncounterPhase::Line("gamblers-wave"),
+        tables,
+        EncounterPhase::Line("gamblers-outro"),
+    ])
+}
 
+fn town() -> Encounter {
     let general_store = EncounterPhase::Loop(vec![
-        EncounterPhase::Line("You see a strangely familiar trader in his shack"),
-        EncounterPhase::Line("Interested in trade, are we?"),
-        trade_loop,
-        EncounterPhase::Line("You leave, somehow feeling emptier than when you came in."),
+        EncounterPhase::Shop(ShopId::GeneralStore),
+        EncounterPhase::Line("town-store-leave"),
+        EncounterPhase::Break,
     ]);
 
     let watering_hole = EncounterPhase::Loop(vec![
-        EncounterPhase::Line("You head to the closest hole in the wall, looking for a drink"),
+        EncounterPhase::Line("town-bar"),
         EncounterPhase::Loop(vec![
             EncounterPhase::Decision(EncounterDecision {
-                prompt: "10 dice points for a bit of relief",
-                options: vec![("Yeah that seems fair", Box::new(EncounterPhase::Trade(
-                "You feel like you were born yesterday",
-                "Unfortunately, capitalism has been deeply rooted here and you need to pay",
-                PlayerResources {
-                    money: "10".into(),
-                    ..default()
-                },
-                PlayerResources {
-                    stamina: 20,
-                    ..default()
-                },
-            ))), ("Eh, that's a bit steep", Box::new(EncounterPhase::Break))],
+                prompt: "town-bar-prompt",
+                options: vec![
+                    (
+                        "town-bar-pay",
+                        Box::new(EncounterPhase::Trade(
+                            "town-bar-drink",
+                            "town-bar-broke",
+                            PlayerResources {
+                                money: "10".into(),
+                                ..default()
+                            },
+                            Loot::Resources(PlayerResources {
+                                stamina: 20,
+                                ..default()
+                            }),
+                        )),
+                    ),
+                    ("town-bar-refuse", Box::new(EncounterPhase::Break)),
+                ],
             }),
-            EncounterPhase::Line("You could stay here forever, maybe just one more day"),
+            EncounterPhase::Line("town-bar-stay"),
         ]),
         EncounterPhase::Break,
     ]);
 
     let town_loop = EncounterPhase::Loop(vec![EncounterPhase::Decision(EncounterDecision {
-        prompt: "Where to?",
+        prompt: "town-prompt",
         options: vec![
-            ("Get a drink", Box::new(watering_hole)),
-            ("Stock up", Box::new(general_store)),
-            ("head out", Box::new(EncounterPhase::Break)),
+            ("town-drink", Box::new(watering_hole)),
+            ("town-stock-up", Box::new(general_store)),
+            ("town-head-out", Box::new(EncounterPhase::Break)),
         ],
     })]);
 
     Encounter::from_phases(vec![
-        EncounterPhase::Line("You arrive in a sleepy town"),
+        EncounterPhase::Line("town-arrive"),
         town_loop,
-        EncounterPhase::Line("This is a place worth visiting."),
+        EncounterPhase::Line("town-outro"),
     ])
 }
 
 fn chase() -> Encounter {
     let yes = EncounterPhase::Loop(vec![
-        EncounterPhase::Line("You pick up the pace and pursue"),
-        EncounterPhase::Battle(Battle::with(vec![Enemy {
-            name: "Getaway car",
-            ..default()
-        }])),
-        EncounterPhase::Gain(
-            "You rummage the remains of the cart and find a fistful of dollars",
-            PlayerResources {
-                money: "10d6".into(),
+        EncounterPhase::Line("chase-pursue"),
+        EncounterPhase::Battle(
+            Battle::with(vec![Enemy {
+                name: "enemy-getaway-car",
                 ..default()
-            },
+            }])
+            .with_loot(LootTable::with(vec![(1, Loot::Weapon(sawn_off_shotgun()))]).or_nothing(1))
+            .with_parley(
+                "chase-parley",
+                PlayerResources {
+                    money: "10".into(),
+                    ..default()
+                },
+            )
+            .with_surrender(
+                "chase-surrender",
+                PlayerResources {
+                    money: "3d6".into(),
+                    ..default()
+                },
+            ),
         ),
+        EncounterPhase::AfterBattle(vec![
+            (
+                BattleCondition::WonWithin(3),
+                Box::new(EncounterPhase::Gain(
+                    "chase-won-quickly",
+                    PlayerResources {
+                        money: "15d6".into(),
+                        ..default()
+                    },
+                )),
+            ),
+            (
+                BattleCondition::Outcome(BattleOutcome::Won),
+                Box::new(EncounterPhase::Gain(
+                    "chase-won",
+                    PlayerResources {
+                        money: "10d6".into(),
+                        ..default()
+                    },
+                )),
+            ),
+            (
+                BattleCondition::Outcome(BattleOutcome::Fled),
+                Box::new(EncounterPhase::Line("chase-fled")),
+            ),
+            (
+                BattleCondition::Outcome(BattleOutcome::Surrendered),
+                Box::new(EncounterPhase::Line("chase-surrendered")),
+            ),
+            (
+                BattleCondition::Always,
+                Box::new(EncounterPhase::Line("chase-survived")),
+            ),
+        ]),
         EncounterPhase::Break,
     ]);
 
     Encounter::from_phases(vec![
-        EncounterPhase::Line("A getaway car kicks up dust as it almost runs you over"),
+        EncounterPhase::Line("chase-intro"),
         EncounterPhase::Decision(EncounterDecision {
-            prompt: "Pursue",
+            prompt: "chase-prompt",
             options: vec![
-                ("Rob the robbers!", Box::new(yes)),
-                (
-                    "Let them get away",
-                    Box::new(EncounterPhase::Line("You watch as the car leaves")),
-                ),
+                ("chase-rob", Box::new(yes)),
+                ("chase-let-go", Box::new(EncounterPhase::Line("chase-gone"))),
             ],
         }),
     ])
 }
-fn test_encounter(wolves: Battle, stamina_drain: EncounterPhase) -> Encounter {
+fn test_encounter(wolves: Battle) -> Encounter {
     Encounter::from_phases(vec![
-        EncounterPhase::Line("This is a test encounter"),
+        EncounterPhase::Line("test-intro"),
         EncounterPhase::Decision(EncounterDecision {
-            prompt: "Would you like to fight?",
+            prompt: "test-prompt",
             options: vec![
-                ("Hell yeah!", Box::new(EncounterPhase::Battle(wolves))),
+                ("test-fight", Box::new(EncounterPhase::Battle(wolves))),
                 (
-                    "Would rather not",
+                    "test-avoid",
                     Box::new(EncounterPhase::Gain(
-                        "You avoid the pointless fight, have a money",
+                        "test-avoided",
                         PlayerResources {
                             money: "1d6".into(),
                             ..default()
@@ -236,50 +239,63 @@ fn test_encounter(wolves: Battle, stamina_drain: EncounterPhase) -> Encounter {
                 ),
             ],
         }),
-        EncounterPhase::Line("This encounter is over"),
-        stamina_drain,
+        EncounterPhase::Line("test-outro"),
     ])
 }
 
-fn wolf_fight(wolves: Battle, stamina_drain: EncounterPhase) -> Encounter {
+fn wolf_fight(wolves: Battle) -> Encounter {
     Encounter::from_phases(vec![
-        EncounterPhase::Line("Wolves attack!"),
+        EncounterPhase::Line("wolves-attack"),
         EncounterPhase::Battle(wolves),
-        stamina_drain,
+        EncounterPhase::AfterBattle(vec![(
+            BattleCondition::NearDeath(10),
+            Box::new(EncounterPhase::Line("wolves-near-death")),
+        )]),
     ])
 }
 
-fn electric_sheep(stamina_drain: EncounterPhase) -> Encounter {
+fn electric_sheep() -> Encounter {
     Encounter::from_phases(vec![
-        EncounterPhase::Line("A herd of electric sheep are barreling at you"),
+        EncounterPhase::Line("sheep-intro"),
         EncounterPhase::Decision(EncounterDecision {
-            prompt: "What to do in time of crisis?",
+            prompt: "sheep-prompt",
             options: vec![
                 (
-                    "Stand your ground",
+                    "sheep-fight",
                     Box::new(EncounterPhase::Battle(Battle::with(vec![
                         Enemy {
-                            name: "Half sheep half machine",
+                            name: "enemy-electric-sheep",
                             health: 10,
-                            weapons: vec![Weapon {
-                                name: "Hoofs of steel",
-                                damage: "1".into(),
-                                ..default()
-                            }, Weapon {
-                                name: "Philosophical quandries",
-                                damage: "1d6".into(),
-                                range: 2,
-                                ..default()
-                            }],
+                            weapons: vec![
+                                Weapon {
+                                    name: "weapon-hoofs-of-steel",
+                                    damage: "1".into(),
+                                    ..default()
+                                },
+                                Weapon {
+                                    name: "weapon-philosophical-quandaries",
+                                    damage: "1d6".into(),
+                                    range: 2,
+                                    ..default()
+                                }
+                            ],
+                            loot: LootTable::with(vec![(
+                                1,
+                                Loot::Resources(PlayerResources {
+                                    batteries: "1d6".into(),
+                                    ..default()
+                                })
+                            )])
+                            .or_nothing(1),
                             ..default()
                         };
                         5
                     ]))),
                 ),
                 (
-                    "Attempt to count them",
+                    "sheep-count",
                     Box::new(EncounterPhase::Lose(
-                        "You start feeling drowsy, briefly dream of androids and the herd tramples you",
+                        "sheep-counted",
                         PlayerResources {
                             stamina: 20,
                             ..default()
@@ -287,171 +303,271 @@ fn electric_sheep(stamina_drain: EncounterPhase) -> Encounter {
                     )),
                 ),
                 (
-                    "Spook them with your gun (6 bullets)",
+                    "sheep-spook",
                     Box::new(EncounterPhase::Trade(
-                        "You rattle a few shots into the air, they seem unimpressed",
-                        "You think of shooting, but don't have enough bullets. They seem to ignore you nonetheless.",
+                        "sheep-spooked",
+                        "sheep-no-bullets",
                         PlayerResources {
                             bullets: 6,
                             ..default()
                         },
-                        PlayerResources {
-                            stamina: -20,   // Negative because this is a ghetto way to drain health
+                        Loot::Resources(PlayerResources {
+                            stamina: -20, // Negative because this is a ghetto way to drain health
                             ..default()
-                        },
+                        }),
                     )),
                 ),
             ],
         }),
-        EncounterPhase::Line("The semimechanical bovine have been dealt with, but at what cost"),
-        stamina_drain,
+        EncounterPhase::AfterBattle(vec![(
+            BattleCondition::KilledAtLeast(5),
+            Box::new(EncounterPhase::Gain(
+                "sheep-salvage",
+                PlayerResources {
+                    batteries: "2d6".into(),
+                    ..default()
+                },
+            )),
+        )]),
+        EncounterPhase::Line("sheep-outro"),
     ])
 }
 
 fn merchant() -> Encounter {
-    let bullet_trade = EncounterPhase::Trade(
-        "You get some ammo.",
-        "Unfortunately your math rocks failed you today, the marchant gets huffy.",
-        PlayerResources {
-            money: "10".into(),
-            ..default()
-        },
-        PlayerResources {
-            bullets: 6,
-            ..default()
-        },
-    );
+    let trade = EncounterPhase::Loop(vec![
+        EncounterPhase::Shop(ShopId::RoadMerchant),
+        EncounterPhase::Break,
+    ]);
 
-    let food_trade = EncounterPhase::Trade(
-        "You manage to gain some energy.",
-        "You don't have enough money.",
-        PlayerResources {
-            money: "8".into(),
-            ..default()
-        },
-        PlayerResources {
-            stamina: 4,
-            ..default()
-        },
-    );
+    Encounter::from_phases(vec![
+        EncounterPhase::Line("merchant-intro"),
+        EncounterPhase::Say(Speaker::Merchant, Expression::Pleased, "merchant-howdy"),
+        EncounterPhase::Decision(EncounterDecision {
+            prompt: "merchant-prompt",
+            options: vec![
+                ("merchant-trade", Box::new(trade)),
+                (
+                    "merchant-decline",
+                    Box::new(EncounterPhase::Line("merchant-shrugs")),
+                ),
+            ],
+        }),
+        EncounterPhase::Line("merchant-outro"),
+    ])
+}
 
-    let mystery_box_trade = EncounterPhase::Loop(vec![
-        EncounterPhase::Trade(
-            "You gain a mystery box!",
-            "You don't have enough money.",
+fn prospector() -> Encounter {
+    let share = EncounterPhase::Loop(vec![
+        EncounterPhase::Lose(
+            "prospector-share",
             PlayerResources {
-                money: "2d6".into(),
+                stamina: 5,
                 ..default()
             },
-            PlayerResources::default(),
         ),
-        EncounterPhase::Line(
-            "The box contains absolutely nothing, as you look back up, the merchant grins at you",
+        EncounterPhase::GainWeapon(
+            "prospector-pickaxe",
+            Weapon {
+                name: "weapon-pickaxe",
+                damage: "1d6+2".into(),
+                ..default()
+            },
         ),
+        EncounterPhase::GainItem("prospector-jerky", jerky()),
         EncounterPhase::Break,
     ]);
 
-    let trade_options = vec![
-        ("Bullets, 6 for 10 dice value!", Box::new(bullet_trade)),
-        (
-            "Food, 4 points of stamina for 8 dice value",
-            Box::new(food_trade),
-        ),
-        ("Mystery box", Box::new(mystery_box_trade)),
-    ];
+    let tinker = EncounterPhase::UpgradeWeapon(
+        "prospector-tinker",
+        "weapon-trusty-sidearm",
+        WeaponUpgrade {
+            damage: "1".into(),
+            range: 1,
+        },
+    );
+
+    let rob = EncounterPhase::Loop(vec![
+        EncounterPhase::Line("prospector-quick"),
+        EncounterPhase::LoseWeapon("prospector-snatch", "weapon-trusty-taser"),
+        EncounterPhase::Break,
+    ]);
 
-    let trade_loop = EncounterPhase::Loop(vec![
+    Encounter::from_phases(vec![
+        EncounterPhase::Line("prospector-intro"),
         EncounterPhase::Decision(EncounterDecision {
-            prompt: "Do you wish to engage in trade?",
+            prompt: "prospector-prompt",
             options: vec![
-                (
-                    "Sure, why not.",
-                    Box::new(EncounterPhase::Line(
-                        "The merchant opens his coat to reveal trinkets and baubles of all sorts",
-                    )),
-                ),
-                ("Maybe some other time.", Box::new(EncounterPhase::Break)),
+                ("prospector-meal", Box::new(share)),
+                ("prospector-ask", Box::new(tinker)),
+                ("prospector-rob", Box::new(rob)),
             ],
         }),
+        EncounterPhase::Line("prospector-outro"),
+    ])
+}
+
+fn dog_buyer() -> Encounter {
+    let thug = Enemy {
+        name: "enemy-thug",
+        health: 10,
+        weapons: vec![
+            Weapon {
+                name: "weapon-brass-knuckles",
+                damage: "1d6".into(),
+                ..default()
+            },
+            Weapon {
+                name: "weapon-rusty-revolver",
+                damage: "1d6".into(),
+                range: 3,
+                ..default()
+            },
+        ],
+        ..default()
+    };
+
+    let refuse = EncounterPhase::Loop(vec![
+        EncounterPhase::Say(Speaker::Cowboy, Expression::Stern, "dog-buyer-refuse"),
+        EncounterPhase::Say(Speaker::Dandy, Expression::Stern, "dog-buyer-insist"),
+        EncounterPhase::Battle(Battle::with(vec![thug.clone(); 2])),
+        EncounterPhase::Trust("dog-buyer-loyal", 2),
+        EncounterPhase::Break,
+    ]);
+
+    let sell = EncounterPhase::Loop(vec![
+        EncounterPhase::Gain(
+            "dog-buyer-paid",
+            PlayerResources {
+                money: "1d6".into(),
+                ..default()
+            },
+        ),
+        EncounterPhase::Trust("dog-buyer-dragged", -5),
+        EncounterPhase::Line("dog-buyer-regret"),
+        EncounterPhase::Battle(Battle::with(vec![
+            thug.clone(),
+            thug.clone(),
+            Enemy {
+                name: "enemy-ringleader",
+                health: 20,
+                ..thug
+            },
+        ])),
+        EncounterPhase::Line("dog-buyer-back"),
+        EncounterPhase::Say(Speaker::Dog, Expression::Worried, "dog-buyer-whimpers"),
+        EncounterPhase::Break,
+    ]);
+
+    Encounter::from_phases(vec![
+        EncounterPhase::Line("dog-buyer-intro"),
         EncounterPhase::Decision(EncounterDecision {
-            prompt: "Anything in particular?",
-            options: trade_options,
+            prompt: "dog-buyer-prompt",
+            options: vec![
+                ("dog-buyer-no", Box::new(refuse)),
+                ("dog-buyer-yes", Box::new(sell)),
+            ],
         }),
-        EncounterPhase::Line("Still interested?"),
-    ]);
+    ])
+}
 
+fn scent() -> Encounter {
     Encounter::from_phases(vec![
-        EncounterPhase::Line("You see a merchant travelling down the road"),
-        EncounterPhase::Line("Interested in trade, are we?"),
-        trade_loop,
-        EncounterPhase::Line("The merchant continues his journey."),
+        EncounterPhase::Line("scent-intro"),
+        EncounterPhase::Say(Speaker::Dog, Expression::Pleased, "scent-woof"),
+        EncounterPhase::Decision(EncounterDecision {
+            prompt: "scent-prompt",
+            options: vec![
+                (
+                    "scent-follow",
+                    Box::new(EncounterPhase::Sniff("scent-followed")),
+                ),
+                (
+                    "scent-pull",
+                    Box::new(EncounterPhase::Trust("scent-pulled", -1)),
+                ),
+            ],
+        }),
     ])
 }
 
 pub fn game_start() -> Encounter {
     Encounter::from_phases(vec![
-        EncounterPhase::Line("Welcome to the frontier. You are a cowboy in charge of a pupper"),
-        EncounterPhase::Line("Use the arrow keys to navigate and space or enter to select"),
-        EncounterPhase::Line("Adventure awaits"),
+        EncounterPhase::Line("intro-welcome"),
+        EncounterPhase::Line("intro-arrows"),
+        EncounterPhase::Line("intro-numbers"),
+        EncounterPhase::Line("intro-inventory"),
+        EncounterPhase::Line("intro-map"),
+        EncounterPhase::Line("intro-chat"),
+        EncounterPhase::Line("intro-hurry"),
+        EncounterPhase::Line("intro-battle"),
+        EncounterPhase::Line("intro-skip-chat"),
+        EncounterPhase::Line("intro-rebind"),
+        EncounterPhase::Line("intro-adventure"),
         EncounterPhase::Decision(EncounterDecision {
-            prompt: "Start your adventure?",
+            prompt: "intro-prompt",
             options: vec![
                 (
-                    "Simple yes",
-                    Box::new(EncounterPhase::Line("That's the sprit!")),
+                    "intro-simple-yes",
+                    Box::new(EncounterPhase::Line("intro-simple-yes-reply")),
                 ),
                 (
-                    "Sarcastic yes",
-                    Box::new(EncounterPhase::Line(
-                        "Aren't you a rascal! Too bad you have no agency",
-                    )),
+                    "intro-sarcastic-yes",
+                    Box::new(EncounterPhase::Line("intro-sarcastic-yes-reply")),
                 ),
                 (
-                    "No but actually yes",
-                    Box::new(EncounterPhase::Line(
-                        "Denying the quest, how heroic. Too bad there is a game to be played.",
-                    )),
+                    "intro-no-yes",
+                    Box::new(EncounterPhase::Line("intro-no-yes-reply")),
                 ),
                 (
-                    "I got confused",
-                    Box::new(EncounterPhase::Line(
-                        "Yeah sometimes it be like that. Hopefully you'll figure it out",
-                    )),
+                    "intro-confused",
+                    Box::new(EncounterPhase::Line("intro-confused-reply")),
                 ),
             ],
         }),
-        EncounterPhase::Line("Onwards!"),
+        EncounterPhase::Line("intro-onwards"),
     ])
 }
 
 pub fn game_over() -> Encounter {
     Encounter::from_phases(vec![
-        EncounterPhase::HighlightLine("Death comes to all. And did any of it matter in the end."),
-        EncounterPhase::Line("Well, sometimes it that's how the dice fall. Can't win them all."),
-        EncounterPhase::Line("Some people can't win them any."),
-        EncounterPhase::Line(
-            "Hopefully you found some enjoyment out of this. But I have to go now.",
-        ),
+        EncounterPhase::HighlightLine("death-comes"),
+        EncounterPhase::Say(Speaker::Stranger, Expression::Neutral, "death-dice-fall"),
+        EncounterPhase::Say(Speaker::Stranger, Expression::Neutral, "death-win-any"),
+        EncounterPhase::Say(Speaker::Stranger, Expression::Neutral, "death-go"),
         EncounterPhase::Decision(EncounterDecision {
-            prompt: "Wait what?",
+            prompt: "death-prompt",
             options: vec![
                 (
-                    "Who are you?",
-                    Box::new(EncounterPhase::Line(
-                        "Does it matter? Why? Do you really crave meaning that badly?",
+                    "death-who",
+                    Box::new(EncounterPhase::Say(
+                        Speaker::Stranger,
+                        Expression::Neutral,
+                        "death-who-reply",
                     )),
                 ),
                 (
-                    "Cheers",
-                    Box::new(EncounterPhase::Line("It was fun while it lasted")),
+                    "death-cheers",
+                    Box::new(EncounterPhase::Say(
+                        Speaker::Stranger,
+                        Expression::Pleased,
+                        "death-cheers-reply",
+                    )),
                 ),
                 (
-                    "*Nod and tip your hat",
-                    Box::new(EncounterPhase::Line("Pardner, *tips back")),
+                    "death-nod",
+                    Box::new(EncounterPhase::Say(
+                        Speaker::Stranger,
+                        Expression::Pleased,
+                        "death-nod-reply",
+                    )),
                 ),
                 (
-                    "I'm still confused, even moreso than before",
-                    Box::new(EncounterPhase::Line("I sincerely hope you figure it out")),
+                    "death-confused",
+                    Box::new(EncounterPhase::Say(
+                        Speaker::Stranger,
+                        Expression::Worried,
+                        "death-confused-reply",
+                    )),
                 ),
             ],
         }),
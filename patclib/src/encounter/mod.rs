@@ -1,10 +1,17 @@
 use bevy::prelude::*;
 
 use crate::{
-    battle::{Battle, OngoingBattle},
-    flow::AppState,
+    battle::{Battle, BattleCondition, BattleResult, OngoingBattle, Weapon},
+    dice_value::DiceValue,
+    flow::{AppState, FlowEvent},
+    inventory::{Item, WeaponUpgrade},
+    locale::{t, tr},
+    loot::Loot,
+    minigame::{Minigame, MinigameOver, OngoingMinigame},
+    payment::{start_payment, PaymentResult},
     player::{Player, PlayerResources},
-    ui::UIHelper,
+    shop::{OngoingShop, ShopClosed, ShopId},
+    ui::{Expression, Speaker, TextPacing, UIHelper},
 };
 
 mod encounters;
@@ -13,29 +20,63 @@ pub use encounters::{game_over, game_start, get_random_encounter};
 #[derive(Debug, Deref, DerefMut)]
 pub struct OngoingEncounter(pub Encounter);
 
+/// Every line and option in here is a message id
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct EncounterDecision {
     prompt: &'static str,
     options: Vec<(&'static str, Box<EncounterPhase>)>,
 }
 
+/// Lines are message ids, looked up as they're shown
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum EncounterPhase {
     Battle(Battle),
     Line(&'static str),
     HighlightLine(&'static str),
+    /// A line said by someone, their name in front of it and their face beside the chat
+    Say(Speaker, Expression, &'static str),
     Decision(EncounterDecision),
     Gain(&'static str, PlayerResources),
     Lose(&'static str, PlayerResources),
-    Trade(&'static str, &'static str, PlayerResources, PlayerResources),
+    Trade(&'static str, &'static str, PlayerResources, Loot),
+    GainWeapon(&'static str, Weapon),
+    LoseWeapon(&'static str, &'static str),
+    UpgradeWeapon(&'static str, &'static str, WeaponUpgrade),
+    GainItem(&'static str, Item),
+    /// Changes how much the dog trusts the player
+    Trust(&'static str, i32),
+    /// Lets the dog go looking for something
+    Sniff(&'static str),
+    /// Lets the player browse a shop until they walk out
+    Shop(ShopId),
+    /// Sits the player down for a game of chance with the given stake
+    Minigame(Minigame, u32),
+    /// Picks the first branch whose condition holds for the latest battle, skipped if none do.
+    /// Wrap anything that waits for the player in a Loop.
+    AfterBattle(Vec<(BattleCondition, Box<EncounterPhase>)>),
     Loop(Vec<EncounterPhase>),
     Break,
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct PendingTrade {
+    line_success: &'static str,
+    line_failure: &'static str,
+    cost: PlayerResources,
+    reward: Loot,
+    /// Everything but the money, taken up front and handed back if the payment falls through
+    taken: PlayerResources,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
 pub struct Encounter {
     stack: Vec<Vec<EncounterPhase>>,
     stack_pointers: Vec<usize>,
+    battle_result: Option<BattleResult>,
+    /// A trade waiting for the player to finish paying
+    pending_trade: Option<PendingTrade>,
+    /// Waiting for the player to read the last line before going on
+    reading: bool,
 }
 impl Encounter {
     fn from_phases(phases: Vec<EncounterPhase>) -> Self {
@@ -65,8 +106,23 @@ impl Encounter {
         }
     }
 
-    fn in_finished_combat(&self) -> bool {
-        matches!(self.get_active_phase(), Some(EncounterPhase::Battle(_)))
+    fn finish_battle(&mut self, result: BattleResult) {
+        self.battle_result = Some(result);
+        // Hop over the combat (or the prompt that brought us to combat)
+        self.move_forward();
+    }
+
+    fn finish_trade(&mut self, paid: bool, ui_helper: &mut UIHelper, player: &mut Player) {
+        let trade = self.pending_trade.take().unwrap();
+        if paid {
+            player.receive(trade.reward);
+            ui_helper.show_line(tr(trade.line_success));
+        } else {
+            player.resources.add(trade.taken);
+            ui_helper.show_line(tr(trade.line_failure));
+        }
+        // Hop over the trade (or the prompt that brought us to it)
+        self.move_forward();
     }
 
     fn break_loop(&mut self) {
@@ -98,29 +154,50 @@ impl Plugin for EncounterPlugin {
     }
 }
 
+/// Drops the encounter going on, if any
+pub(crate) fn reset(world: &mut World) {
+    world.remove_resource::<OngoingEncounter>();
+}
+
 fn init_encounter(
     mut commands: Commands,
     mut encounter: ResMut<OngoingEncounter>,
-    mut app_state: ResMut<State<AppState>>,
+    mut flow: EventWriter<FlowEvent>,
     mut ui_helper: ResMut<UIHelper>,
     mut player: ResMut<Player>,
+    pacing: Res<TextPacing>,
 ) {
     event_loop(
         &mut encounter,
         &mut commands,
-        &mut app_state,
+        &mut flow,
         &mut ui_helper,
         &mut player,
+        &pacing,
     );
 }
 
+/// Whatever a finished battle, shop, payment or minigame left behind
+type Leftovers<'w> = (
+    Option<Res<'w, BattleResult>>,
+    Option<Res<'w, ShopClosed>>,
+    Option<Res<'w, PaymentResult>>,
+    Option<Res<'w, MinigameOver>>,
+);
+
 fn advance_encounter(
     mut commands: Commands,
     mut encounter: ResMut<OngoingEncounter>,
-    mut app_state: ResMut<State<AppState>>,
+    mut flow: EventWriter<FlowEvent>,
     mut ui_helper: ResMut<UIHelper>,
     mut player: ResMut<Player>,
+    (battle_result, shop_closed, payment_result, minigame_over): Leftovers,
+    pacing: Res<TextPacing>,
 ) {
+    if !ui_helper.is_caught_up() {
+        // Nothing moves on until the player has seen what happened so far
+        return;
+    }
     if let Some(decision) = player.drain_decision() {
         // Only act if player has done something
         if let Some(EncounterPhase::Decision(next_phase)) = encounter.get_active_phase() {
@@ -128,7 +205,7 @@ fn advance_encounter(
                 &mut encounter,
                 *next_phase.options.get(decision).unwrap().1.clone(),
                 &mut commands,
-                &mut app_state,
+                &mut flow,
                 &mut ui_helper,
                 &mut player,
             );
@@ -138,19 +215,55 @@ fn advance_encounter(
         event_loop(
             &mut encounter,
             &mut commands,
-            &mut app_state,
+            &mut flow,
+            &mut ui_helper,
+            &mut player,
+            &pacing,
+        );
+    } else if let Some(result) = battle_result {
+        // Battle left us a result, combat has been resolved
+        commands.remove_resource::<BattleResult>();
+        encounter.finish_battle(*result);
+        event_loop(
+            &mut encounter,
+            &mut commands,
+            &mut flow,
+            &mut ui_helper,
+            &mut player,
+            &pacing,
+        );
+    } else if shop_closed.is_some() || minigame_over.is_some() {
+        commands.remove_resource::<ShopClosed>();
+        commands.remove_resource::<MinigameOver>();
+        encounter.move_forward();
+        event_loop(
+            &mut encounter,
+            &mut commands,
+            &mut flow,
+            &mut ui_helper,
+            &mut player,
+            &pacing,
+        );
+    } else if let Some(result) = payment_result {
+        commands.remove_resource::<PaymentResult>();
+        encounter.finish_trade(result.paid, &mut ui_helper, &mut player);
+        event_loop(
+            &mut encounter,
+            &mut commands,
+            &mut flow,
             &mut ui_helper,
             &mut player,
+            &pacing,
         );
-    } else if encounter.in_finished_combat() {
-        // In combat but state is reset back to this, combat has been resolved
-        encounter.move_forward(); // Hop over the combat (or the prompt that brought us to combat)
+    } else if encounter.reading {
+        encounter.reading = false;
         event_loop(
             &mut encounter,
             &mut commands,
-            &mut app_state,
+            &mut flow,
             &mut ui_helper,
             &mut player,
+            &pacing,
         );
     }
 }
@@ -158,38 +271,53 @@ fn advance_encounter(
 fn event_loop(
     encounter: &mut ResMut<OngoingEncounter>,
     commands: &mut Commands,
-    app_state: &mut ResMut<State<AppState>>,
+    flow: &mut EventWriter<FlowEvent>,
     ui_helper: &mut ResMut<UIHelper>,
     player: &mut ResMut<Player>,
+    pacing: &TextPacing,
 ) {
     loop {
         if let Some(phase) = encounter.get_active_phase() {
             // Exists because in death we still need to handle events
-            let old_stamina = player.resources.stamina;
-            process_encounter_phase(
-                encounter,
-                phase.clone(),
-                commands,
-                app_state,
-                ui_helper,
-                player,
-            );
-            if player.resources.stamina == 0 && old_stamina > 0 {
-                // Player died to this thing
+            let had_lost = player.has_lost();
+            process_encounter_phase(encounter, phase.clone(), commands, flow, ui_helper, player);
+            if player.has_lost() && !had_lost {
+                // Player or the dog died to this thing
                 commands.remove_resource::<OngoingEncounter>();
-                app_state.set(AppState::GameOver).unwrap();
+                flow.send(FlowEvent::PlayerDied);
+                break;
+            }
+            if matches!(
+                phase,
+                EncounterPhase::Decision(_)
+                    | EncounterPhase::Battle(_)
+                    | EncounterPhase::Shop(_)
+                    | EncounterPhase::Minigame(..)
+            ) || encounter.pending_trade.is_some()
+            {
                 break;
             }
             if matches!(
                 phase,
-                EncounterPhase::Decision(_) | EncounterPhase::Battle(_)
+                EncounterPhase::Line(_)
+                    | EncounterPhase::HighlightLine(_)
+                    | EncounterPhase::Say(..)
             ) {
+                // One line at a time, the next once this one has been read
+                let more_to_say = !matches!(
+                    encounter.get_active_phase(),
+                    None | Some(EncounterPhase::Decision(_))
+                );
+                if pacing.wait_for_continue && more_to_say {
+                    ui_helper.hold();
+                }
+                encounter.reading = true;
                 break;
             }
         } else {
             // Ran out of phases, encounter is over
             commands.remove_resource::<OngoingEncounter>();
-            app_state.pop().unwrap();
+            flow.send(FlowEvent::EncounterFinished);
             break;
         }
     }
@@ -199,54 +327,152 @@ fn process_encounter_phase(
     encounter: &mut ResMut<OngoingEncounter>,
     phase: EncounterPhase,
     commands: &mut Commands,
-    app_state: &mut ResMut<State<AppState>>,
+    flow: &mut EventWriter<FlowEvent>,
     ui_helper: &mut ResMut<UIHelper>,
     player: &mut ResMut<Player>,
 ) {
     match phase {
         EncounterPhase::Decision(decision) => {
             ui_helper.prompt(
-                decision.prompt,
-                decision
-                    .options
-                    .iter()
-                    .map(|(line, _)| line)
-                    .cloned()
-                    .collect(),
+                tr(decision.prompt),
+                decision.options.iter().map(|(line, _)| tr(line)).collect(),
             );
         }
         EncounterPhase::Battle(battle) => {
-            app_state.push(AppState::Battle).unwrap();
+            flow.send(FlowEvent::BattleStarted);
             commands.insert_resource(OngoingBattle(battle));
-            ui_helper.show_line("Can't escape from crossing fate!");
+            ui_helper.show_line(t!("encounter-trouble"));
         }
         EncounterPhase::Line(line) => {
-            ui_helper.show_line(line);
+            ui_helper.show_line(tr(line));
             encounter.move_forward();
         }
         EncounterPhase::HighlightLine(line) => {
-            ui_helper.show_highlighted_line(line);
+            ui_helper.show_highlighted_line(tr(line));
+            encounter.move_forward();
+        }
+        EncounterPhase::Say(speaker, expression, line) => {
+            ui_helper.say(speaker, expression, tr(line));
             encounter.move_forward();
         }
         EncounterPhase::Gain(line, resources) => {
-            ui_helper.show_line(line);
-            player.resources.add(resources);
+            ui_helper.show_line(tr(line));
+            player.gain(resources);
             encounter.move_forward();
         }
         EncounterPhase::Lose(line, resources) => {
-            ui_helper.show_line(line);
-            player.resources.force_remove(resources);
+            ui_helper.show_line(tr(line));
+            for line in player.resources.force_remove(resources).lines() {
+                ui_helper.show_line(line);
+            }
+            encounter.move_forward();
+        }
+        EncounterPhase::Trade(line_success, line_failure, cost, reward) => {
+            if !player.can_receive(&reward) {
+                ui_helper.show_line(t!("shop-pack-full"));
+                encounter.move_forward();
+            } else if !player.resources.could_afford(&cost) {
+                ui_helper.show_line(tr(line_failure));
+                encounter.move_forward();
+            } else if cost.money != DiceValue::default() {
+                // The rest is taken first, so a payment that went through always buys something
+                if let Some(taken) = player.resources.remove_all_but_money(cost) {
+                    // Let the player see the price and count out their dice
+                    encounter.pending_trade = Some(PendingTrade {
+                        line_success,
+                        line_failure,
+                        cost,
+                        reward,
+                        taken,
+                    });
+                    start_payment(
+                        commands,
+                        flow,
+                        ui_helper,
+                        player.resources.money,
+                        cost.money,
+                    );
+                } else {
+                    ui_helper.show_line(tr(line_failure));
+                    encounter.move_forward();
+                }
+            } else if player.resources.remove(cost) {
+                player.receive(reward);
+                ui_helper.show_line(tr(line_success));
+                encounter.move_forward();
+            } else {
+                ui_helper.show_line(tr(line_failure));
+                encounter.move_forward();
+            }
+        }
+        EncounterPhase::GainWeapon(line, weapon) => {
+            if player.inventory.add_weapon(weapon) {
+                ui_helper.show_line(tr(line));
+            } else {
+                ui_helper.show_line(t!("encounter-pack-full", thing = tr(weapon.name)));
+            }
+            encounter.move_forward();
+        }
+        EncounterPhase::GainItem(line, item) => {
+            if player.inventory.add_item(item) {
+                ui_helper.show_line(tr(line));
+            } else {
+                ui_helper.show_line(t!("encounter-pack-full", thing = tr(item.name)));
+            }
+            encounter.move_forward();
+        }
+        EncounterPhase::LoseWeapon(line, name) => {
+            if player.inventory.remove_weapon(name).is_some() {
+                ui_helper.show_line(tr(line));
+            }
+            encounter.move_forward();
+        }
+        EncounterPhase::UpgradeWeapon(line, name, upgrade) => {
+            if player.inventory.upgrade_weapon(name, upgrade) {
+                ui_helper.show_line(tr(line));
+            }
+            encounter.move_forward();
+        }
+        EncounterPhase::AfterBattle(branches) => {
+            let result = encounter.battle_result;
+            if let Some((_, branch)) = branches
+                .into_iter()
+                .find(|(condition, _)| result.is_some_and(|result| condition.holds(&result)))
+            {
+                process_encounter_phase(encounter, *branch, commands, flow, ui_helper, player);
+            } else {
+                encounter.move_forward();
+            }
+        }
+        EncounterPhase::Trust(line, amount) => {
+            ui_helper.show_line(tr(line));
+            player.dog.change_trust(amount);
             encounter.move_forward();
         }
-        EncounterPhase::Trade(line_success, line_failure, resources_cost, resources_reward) => {
-            if player.resources.remove(resources_cost) {
-                player.resources.add(resources_reward);
-                ui_helper.show_line(line_success);
+        EncounterPhase::Sniff(line) => {
+            ui_helper.show_line(tr(line));
+            let found = if DiceValue::from("1d6").roll() >= player.dog.obedience_target() {
+                player.dog.sniff_loot().roll()
             } else {
-                ui_helper.show_line(line_failure);
+                None
+            };
+            match found {
+                Some(loot) if player.can_receive(&loot) => {
+                    ui_helper.show_line(t!("encounter-dog-finds", loot = loot.to_string()));
+                    player.receive(loot);
+                }
+                _ => ui_helper.show_line(t!("encounter-dog-finds-nothing")),
             }
             encounter.move_forward();
         }
+        EncounterPhase::Shop(id) => {
+            flow.send(FlowEvent::ShopEntered);
+            commands.insert_resource(OngoingShop::new(id));
+        }
+        EncounterPhase::Minigame(game, stake) => {
+            flow.send(FlowEvent::MinigameStarted);
+            commands.insert_resource(OngoingMinigame::new(game, stake));
+        }
         EncounterPhase::Break => {
             encounter.break_loop();
         }
@@ -0,0 +1,37 @@
+use std::{fs, io, path::PathBuf};
+
+/// The folder under the platform's config directory the game keeps its files in
+const GAME_DIR: &str = "patclib";
+
+/// Where one of the player's own files lives, None if the platform has no config directory
+pub fn user_file(name: &str) -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join(GAME_DIR).join(name))
+}
+
+/// What the file holds, None if it isn't there (yet)
+pub fn read_user_file(name: &str) -> Option<String> {
+    fs::read_to_string(user_file(name)?).ok()
+}
+
+/// Writes the file, making the game's folder first if there isn't one
+pub fn write_user_file(name: &str, contents: &str) -> io::Result<()> {
+    let path = user_file(name)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no config directory"))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)
+}
+
+/// Removes the file, fine if it was never written
+pub fn remove_user_file(name: &str) -> io::Result<()> {
+    let path = if let Some(path) = user_file(name) {
+        path
+    } else {
+        return Ok(());
+    };
+    match fs::remove_file(path) {
+        Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+        _ => Ok(()),
+    }
+}
@@ -1,72 +1,534 @@
-use bevy::{app::AppExit, prelude::*};
+use bevy::{ecs::system::Command, prelude::*};
 
-use crate::encounter::{game_over, game_start, OngoingEncounter};
+use crate::{
+    battle,
+    encounter::{self, game_over, OngoingEncounter},
+    inventory, minigame, payment, shop,
+    travel::{self, SavedRun},
+    ui,
+};
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum AppState {
     MainMenu,
+    Settings,
+    Controls,
+    Paused,
+    Journal,
+    Map,
     GameOver,
     Travel,
     Encounter,
     Battle,
+    Inventory,
+    Shop,
+    Payment,
+    Minigame,
+}
+
+/// Everything that moves the game from one state to another. Send these instead of touching
+/// `State<AppState>`, the flow controller decides what they mean for the state stack
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum FlowEvent {
+    EncounterStarted,
+    EncounterFinished,
+    BattleStarted,
+    BattleFinished,
+    ShopEntered,
+    ShopLeft,
+    PaymentStarted,
+    PaymentFinished,
+    MinigameStarted,
+    MinigameFinished,
+    InventoryOpened,
+    InventoryClosed,
+    SettingsOpened,
+    SettingsClosed,
+    ControlsOpened,
+    ControlsClosed,
+    Paused,
+    Unpaused,
+    JournalOpened,
+    JournalClosed,
+    MapOpened,
+    MapClosed,
+    /// Onto the road, whether after the intro or from a save
+    RunStarted,
+    /// The player or the dog didn't make it, wherever that happened
+    PlayerDied,
+    QuitToMenu,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+enum Transition {
+    Push(AppState),
+    Pop,
+    /// Unwinds the whole stack
+    Replace(AppState),
+}
+
+impl FlowEvent {
+    /// What the event does to the stack, None if it makes no sense with `current` on top
+    fn transition(&self, current: &AppState) -> Option<Transition> {
+        let push = |state: AppState| (current != &state).then_some(Transition::Push(state));
+        let pop = |state: AppState| (current == &state).then_some(Transition::Pop);
+        let replace = |state: AppState| (current != &state).then_some(Transition::Replace(state));
+        // The game itself waits until whatever is laid over it is closed again
+        let overlaid = matches!(
+            current,
+            AppState::Paused
+                | AppState::Journal
+                | AppState::Settings
+                | AppState::Controls
+                | AppState::Map
+        );
+        let play = |state: AppState| if overlaid { None } else { push(state) };
+        match self {
+            FlowEvent::EncounterStarted => play(AppState::Encounter),
+            FlowEvent::EncounterFinished => pop(AppState::Encounter),
+            FlowEvent::BattleStarted => play(AppState::Battle),
+            FlowEvent::BattleFinished => pop(AppState::Battle),
+            FlowEvent::ShopEntered => play(AppState::Shop),
+            FlowEvent::ShopLeft => pop(AppState::Shop),
+            FlowEvent::PaymentStarted => play(AppState::Payment),
+            FlowEvent::PaymentFinished => pop(AppState::Payment),
+            FlowEvent::MinigameStarted => play(AppState::Minigame),
+            FlowEvent::MinigameFinished => pop(AppState::Minigame),
+            FlowEvent::InventoryOpened if current == &AppState::Travel => push(AppState::Inventory),
+            FlowEvent::InventoryOpened => None,
+            FlowEvent::InventoryClosed => pop(AppState::Inventory),
+            FlowEvent::MapOpened if current == &AppState::Travel => push(AppState::Map),
+            FlowEvent::MapOpened => None,
+            FlowEvent::MapClosed => pop(AppState::Map),
+            FlowEvent::SettingsOpened => push(AppState::Settings),
+            FlowEvent::SettingsClosed => pop(AppState::Settings),
+            FlowEvent::ControlsOpened => push(AppState::Controls),
+            FlowEvent::ControlsClosed => pop(AppState::Controls),
+            FlowEvent::Paused | FlowEvent::JournalOpened => match current {
+                AppState::MainMenu
+                | AppState::Settings
+                | AppState::Controls
+                | AppState::GameOver
+                | AppState::Paused
+                | AppState::Journal => None,
+                _ if self == &FlowEvent::Paused => push(AppState::Paused),
+                _ => push(AppState::Journal),
+            },
+            FlowEvent::Unpaused => pop(AppState::Paused),
+            FlowEvent::JournalClosed => pop(AppState::Journal),
+            FlowEvent::RunStarted => replace(AppState::Travel),
+            FlowEvent::PlayerDied => replace(AppState::GameOver),
+            FlowEvent::QuitToMenu => replace(AppState::MainMenu),
+        }
+    }
+
+    /// Whether the event waits for its turn if it can't be handled yet, or is simply dropped
+    fn can_wait(&self) -> bool {
+        !matches!(
+            self,
+            FlowEvent::InventoryOpened
+                | FlowEvent::MapOpened
+                | FlowEvent::Paused
+                | FlowEvent::JournalOpened
+        )
+    }
+}
+
+/// Takes the first event in line that can be handled with `current` on top
+fn next_transition(queue: &mut Vec<FlowEvent>, current: &AppState) -> Option<Transition> {
+    let mut index = 0;
+    while index < queue.len() {
+        match queue[index].transition(current) {
+            Some(transition) => {
+                queue.remove(index);
+                if matches!(transition, Transition::Replace(_)) {
+                    // Nothing that was waiting is left to wait for
+                    queue.clear();
+                }
+                return Some(transition);
+            }
+            None if queue[index].can_wait() => index += 1,
+            None => {
+                queue.remove(index);
+            }
+        }
+    }
+    None
 }
 
 pub struct FlowPlugin;
 impl Plugin for FlowPlugin {
     fn build(&self, app: &mut App) {
         app.add_state(AppState::MainMenu)
-            .add_startup_system_to_stage(StartupStage::PostStartup, prompt_to_start)
-            .add_system_set(SystemSet::on_update(AppState::MainMenu).with_system(start_game))
-            .add_system_set(SystemSet::on_enter(AppState::GameOver).with_system(start_end_game))
-            .add_system_set(SystemSet::on_update(AppState::GameOver).with_system(end_game));
+            .add_event::<FlowEvent>()
+            // After everything had its say, so the transition happens before any state runs again
+            .add_system_to_stage(CoreStage::PostUpdate, control_flow)
+            .add_system_set(SystemSet::on_enter(AppState::GameOver).with_system(start_end_game));
     }
 }
 
 /*
-1. Game starts at MainMenu
-2. Initial encounter starts, push encounter as state
+1. Game starts at MainMenu, which can push Settings, which can push Controls
+2. New game starts the initial encounter, push encounter as state
 3. State is popped once that is done, back in MainMenu
-4. Travel is set as state
+4. Travel is set as state, Continue skips straight to this with the saved run
 5. Travel randomizes encounters pushes encounter as state
-6. Encounters push battles as state
-7. Battle pops state
+   Travel asks whether to rest and which road to take on the map first, saving the run
+   Travel can also push inventory or the map as state, they pop themselves once closed
+6. Encounters push battles, shops or minigames as state
+   Encounters and shops can push payments, which pop themselves once paid or abandoned
+7. Battle, shop or minigame pops state
 8. Encounter pops state
-9. Repeat from 4
-10. Player dies, GameOver is set as state
+9. Repeat from 5
+10. Player dies, GameOver replaces the whole stack, however deep it was
 11. Final encounter set active, push encounter state
-12. Once encounter state is popped, wait a bit and close the game
+12. Once encounter state is popped, offer to ride again, go back to MainMenu or quit
+
+Every way into a run goes through StartRun, every way out of one through EndRun
 
-iirc on_enter doesn't register when a state is resumed
+Escape pushes Paused on top of anything but the menus, it can push Settings too
+J pushes Journal the same way, both pop themselves once closed
+Nothing from the game itself is pushed over those, it waits for them to close
+and replaces the whole stack with MainMenu when quitting the run
+
+Only the flow controller touches the state stack, everything else sends FlowEvents.
+One transition happens per frame, anything else sent that frame waits for the next one.
+on_enter doesn't run when a state is returned to, on_resume does
  */
 
-fn prompt_to_start(mut commands: Commands, mut app_state: ResMut<State<AppState>>) {
-    commands.insert_resource(OngoingEncounter(game_start()));
-    app_state.push(AppState::Encounter).unwrap();
+/// Owns every state transition
+fn control_flow(
+    mut events: EventReader<FlowEvent>,
+    mut queue: Local<Vec<FlowEvent>>,
+    mut app_state: ResMut<State<AppState>>,
+) {
+    queue.extend(events.iter().cloned());
+    match next_transition(&mut queue, app_state.current()) {
+        Some(Transition::Push(state)) => app_state.push(state).unwrap(),
+        Some(Transition::Pop) => app_state.pop().unwrap(),
+        Some(Transition::Replace(state)) => app_state.replace(state).unwrap(),
+        None => {}
+    }
 }
 
-fn start_game(mut app_state: ResMut<State<AppState>>) {
-    app_state.set(AppState::Travel).unwrap();
+/// Throws away whatever the last run left behind, the saved run is kept for Continue
+pub struct EndRun;
+impl Command for EndRun {
+    fn write(self, world: &mut World) {
+        battle::reset(world);
+        encounter::reset(world);
+        inventory::reset(world);
+        minigame::reset(world);
+        payment::reset(world);
+        shop::reset(world);
+        travel::reset(world);
+        ui::reset(world);
+    }
 }
 
-fn start_end_game(mut commands: Commands, mut app_state: ResMut<State<AppState>>) {
-    commands.insert_resource(OngoingEncounter(game_over()));
-    app_state.push(AppState::Encounter).unwrap();
+/// Ends the run going on, if any, and picks up the given one
+pub struct StartRun(pub SavedRun);
+impl StartRun {
+    pub fn fresh() -> Self {
+        Self(SavedRun::fresh())
+    }
+}
+impl Command for StartRun {
+    fn write(self, world: &mut World) {
+        EndRun.write(world);
+        self.0.restore(world);
+    }
 }
 
-#[derive(Debug)]
-struct TimeToQuit(f64);
-
-fn end_game(
+fn start_end_game(
     mut commands: Commands,
-    time: Res<Time>,
-    time_to_quit: Option<Res<TimeToQuit>>,
-    mut exit: EventWriter<AppExit>,
+    mut flow: EventWriter<FlowEvent>,
+    saved: Option<Res<SavedRun>>,
 ) {
-    if let Some(ttq) = time_to_quit {
-        if ttq.0 < time.seconds_since_startup() {
-            exit.send(AppExit);
+    // Dead men don't get to continue
+    if saved.is_some() {
+        commands.remove_resource::<SavedRun>();
+        SavedRun::forget();
+    }
+    commands.insert_resource(OngoingEncounter(game_over()));
+    flow.send(FlowEvent::EncounterStarted);
+}
+
+#[cfg(test)]
+mod test {
+    use std::iter::once;
+
+    use bevy::{
+        ecs::{event::Events, system::Command},
+        prelude::*,
+    };
+
+    use super::{next_transition, AppState, EndRun, FlowEvent, FlowPlugin, StartRun, Transition};
+    use crate::{
+        encounter::{game_over, OngoingEncounter},
+        player::Player,
+        travel::{Journey, SavedRun},
+        ui::UIHelper,
+    };
+
+    #[test]
+    fn every_run_starts_fresh() {
+        let mut world = World::new();
+        world.insert_resource(UIHelper::default());
+        for _ in 0..3 {
+            StartRun::fresh().write(&mut world);
+            assert_eq!(*world.resource::<Player>(), Player::new());
+            assert_eq!(world.resource::<Journey>().encounters_started, 0);
+            assert!(world.get_resource::<OngoingEncounter>().is_none());
+
+            // Die horribly partway through
+            world.resource_mut::<Player>().resources.stamina = 0;
+            world.resource_mut::<Journey>().encounters_started = 5;
+            world.insert_resource(OngoingEncounter(game_over()));
         }
-    } else {
-        commands.insert_resource(TimeToQuit(time.seconds_since_startup() + 5.0));
+    }
+
+    #[test]
+    fn ending_a_run_keeps_the_save() {
+        let mut world = World::new();
+        world.insert_resource(SavedRun::fresh());
+        world.insert_resource(OngoingEncounter(game_over()));
+        EndRun.write(&mut world);
+        assert!(world.get_resource::<OngoingEncounter>().is_none());
+        assert!(world.get_resource::<SavedRun>().is_some());
+    }
+
+    #[test]
+    fn starting_and_finishing_things() {
+        let pairs = [
+            (
+                FlowEvent::EncounterStarted,
+                FlowEvent::EncounterFinished,
+                AppState::Encounter,
+            ),
+            (
+                FlowEvent::BattleStarted,
+                FlowEvent::BattleFinished,
+                AppState::Battle,
+            ),
+            (FlowEvent::ShopEntered, FlowEvent::ShopLeft, AppState::Shop),
+            (
+                FlowEvent::PaymentStarted,
+                FlowEvent::PaymentFinished,
+                AppState::Payment,
+            ),
+            (
+                FlowEvent::MinigameStarted,
+                FlowEvent::MinigameFinished,
+                AppState::Minigame,
+            ),
+            (
+                FlowEvent::InventoryOpened,
+                FlowEvent::InventoryClosed,
+                AppState::Inventory,
+            ),
+            (FlowEvent::MapOpened, FlowEvent::MapClosed, AppState::Map),
+            (
+                FlowEvent::SettingsOpened,
+                FlowEvent::SettingsClosed,
+                AppState::Settings,
+            ),
+            (
+                FlowEvent::ControlsOpened,
+                FlowEvent::ControlsClosed,
+                AppState::Controls,
+            ),
+            (FlowEvent::Paused, FlowEvent::Unpaused, AppState::Paused),
+            (
+                FlowEvent::JournalOpened,
+                FlowEvent::JournalClosed,
+                AppState::Journal,
+            ),
+        ];
+        for (start, finish, state) in pairs {
+            assert_eq!(
+                start.transition(&AppState::Travel),
+                Some(Transition::Push(state.clone()))
+            );
+            assert_eq!(start.transition(&state), None);
+            assert_eq!(finish.transition(&state), Some(Transition::Pop));
+            assert_eq!(finish.transition(&AppState::Travel), None);
+        }
+    }
+
+    #[test]
+    fn some_things_only_happen_in_the_right_place() {
+        assert_eq!(
+            FlowEvent::InventoryOpened.transition(&AppState::Battle),
+            None
+        );
+        assert_eq!(FlowEvent::MapOpened.transition(&AppState::Shop), None);
+        for menu in [
+            AppState::MainMenu,
+            AppState::Settings,
+            AppState::Controls,
+            AppState::GameOver,
+        ] {
+            assert_eq!(FlowEvent::Paused.transition(&menu), None);
+            assert_eq!(FlowEvent::JournalOpened.transition(&menu), None);
+        }
+        assert_eq!(FlowEvent::Paused.transition(&AppState::Journal), None);
+        assert_eq!(
+            FlowEvent::Paused.transition(&AppState::Payment),
+            Some(Transition::Push(AppState::Paused))
+        );
+    }
+
+    #[test]
+    fn waiting_in_line() {
+        let mut queue = vec![
+            FlowEvent::EncounterFinished,
+            FlowEvent::InventoryOpened,
+            FlowEvent::Unpaused,
+        ];
+        // Paused over an encounter, the encounter has to wait and the inventory is dropped
+        assert_eq!(
+            next_transition(&mut queue, &AppState::Paused),
+            Some(Transition::Pop)
+        );
+        assert_eq!(queue, vec![FlowEvent::EncounterFinished]);
+        assert_eq!(
+            next_transition(&mut queue, &AppState::Encounter),
+            Some(Transition::Pop)
+        );
+        assert!(queue.is_empty());
+        assert_eq!(next_transition(&mut queue, &AppState::Travel), None);
+    }
+
+    #[test]
+    fn death_clears_the_line() {
+        let mut queue = vec![FlowEvent::BattleFinished, FlowEvent::PlayerDied];
+        assert_eq!(
+            next_transition(&mut queue, &AppState::Payment),
+            Some(Transition::Replace(AppState::GameOver))
+        );
+        assert!(queue.is_empty());
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugin(FlowPlugin);
+        app.update();
+        app
+    }
+
+    /// Sends the events on the same frame, then lets the dust settle
+    fn send(app: &mut App, events: Vec<FlowEvent>) {
+        app.world.resource_mut::<Events<FlowEvent>>().extend(events);
+        for _ in 0..5 {
+            app.update();
+        }
+    }
+
+    fn stack(app: &App) -> Vec<AppState> {
+        let state = app.world.resource::<State<AppState>>();
+        state
+            .inactives()
+            .iter()
+            .chain(once(state.current()))
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn a_whole_run() {
+        let mut app = app();
+        send(&mut app, vec![FlowEvent::EncounterStarted]);
+        assert_eq!(stack(&app), vec![AppState::MainMenu, AppState::Encounter]);
+        send(&mut app, vec![FlowEvent::EncounterFinished]);
+        send(&mut app, vec![FlowEvent::RunStarted]);
+        assert_eq!(stack(&app), vec![AppState::Travel]);
+
+        for event in [
+            FlowEvent::EncounterStarted,
+            FlowEvent::ShopEntered,
+            FlowEvent::PaymentStarted,
+        ] {
+            send(&mut app, vec![event]);
+        }
+        assert_eq!(
+            stack(&app),
+            vec![
+                AppState::Travel,
+                AppState::Encounter,
+                AppState::Shop,
+                AppState::Payment
+            ]
+        );
+        for event in [
+            FlowEvent::PaymentFinished,
+            FlowEvent::ShopLeft,
+            FlowEvent::EncounterFinished,
+        ] {
+            send(&mut app, vec![event]);
+        }
+        assert_eq!(stack(&app), vec![AppState::Travel]);
+    }
+
+    #[test]
+    fn dying_deep_inside_a_battle() {
+        let mut app = app();
+        for event in [
+            FlowEvent::RunStarted,
+            FlowEvent::EncounterStarted,
+            FlowEvent::BattleStarted,
+        ] {
+            send(&mut app, vec![event]);
+        }
+        send(&mut app, vec![FlowEvent::PlayerDied]);
+        // The last words are an encounter of their own, on top of nothing but the game over
+        assert_eq!(stack(&app), vec![AppState::GameOver, AppState::Encounter]);
+        send(&mut app, vec![FlowEvent::EncounterFinished]);
+        assert_eq!(stack(&app), vec![AppState::GameOver]);
+        send(&mut app, vec![FlowEvent::RunStarted]);
+        assert_eq!(stack(&app), vec![AppState::Travel]);
+    }
+
+    #[test]
+    fn pausing_in_the_middle_of_things() {
+        let mut app = app();
+        send(&mut app, vec![FlowEvent::Paused]);
+        assert_eq!(stack(&app), vec![AppState::MainMenu]);
+
+        for event in [
+            FlowEvent::RunStarted,
+            FlowEvent::EncounterStarted,
+            FlowEvent::MinigameStarted,
+        ] {
+            send(&mut app, vec![event]);
+        }
+        // The game finishes on the same frame the player pauses, it waits for the unpause
+        send(
+            &mut app,
+            vec![FlowEvent::Paused, FlowEvent::MinigameFinished],
+        );
+        assert_eq!(stack(&app).last(), Some(&AppState::Paused));
+        send(&mut app, vec![FlowEvent::SettingsOpened]);
+        send(&mut app, vec![FlowEvent::SettingsClosed]);
+        send(&mut app, vec![FlowEvent::Unpaused]);
+        assert_eq!(stack(&app), vec![AppState::Travel, AppState::Encounter]);
+
+        send(&mut app, vec![FlowEvent::Paused]);
+        send(&mut app, vec![FlowEvent::QuitToMenu]);
+        assert_eq!(stack(&app), vec![AppState::MainMenu]);
+    }
+
+    #[test]
+    fn nothing_starts_under_the_pause_menu() {
+        let mut app = app();
+        send(&mut app, vec![FlowEvent::RunStarted]);
+        // The road turns up an encounter on the frame the player pauses
+        send(
+            &mut app,
+            vec![FlowEvent::Paused, FlowEvent::EncounterStarted],
+        );
+        assert_eq!(stack(&app), vec![AppState::Travel, AppState::Paused]);
+        send(&mut app, vec![FlowEvent::Unpaused]);
+        assert_eq!(stack(&app), vec![AppState::Travel, AppState::Encounter]);
     }
 }
@@ -0,0 +1,96 @@
+use core::fmt;
+
+use bevy::prelude::*;
+
+use crate::{
+    dice_value::DiceValue,
+    locale::t,
+    player::{Player, PlayerResources},
+};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Item {
+    /// Message id
+    pub name: &'static str,
+    pub effect: ItemEffect,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ItemEffect {
+    /// Tops up resources, works anywhere
+    Restore(PlayerResources),
+    /// A meal, restores stamina and keeps hunger away for a while
+    Feed(i32),
+    /// Guaranteed getaway from a battle
+    Smoke,
+    /// Damages every enemy on the field
+    Explode(DiceValue),
+}
+impl ItemEffect {
+    pub fn usable_outside_battle(&self) -> bool {
+        matches!(self, ItemEffect::Restore(_) | ItemEffect::Feed(_))
+    }
+
+    /// Applies the parts of the effect that don't need a battle, returns false if there were none
+    pub fn apply(&self, player: &mut Player) -> bool {
+        match self {
+            ItemEffect::Restore(restored) => {
+                player.gain(*restored);
+                true
+            }
+            ItemEffect::Feed(stamina) => {
+                player.condition.eat();
+                player.gain(PlayerResources {
+                    stamina: *stamina,
+                    ..default()
+                });
+                true
+            }
+            ItemEffect::Smoke | ItemEffect::Explode(_) => false,
+        }
+    }
+}
+impl fmt::Display for ItemEffect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let effect = match self {
+            ItemEffect::Restore(resources) => {
+                t!("effect-restore", resources = resources.to_string())
+            }
+            ItemEffect::Feed(stamina) => t!("effect-feed", stamina = *stamina),
+            ItemEffect::Smoke => t!("effect-smoke"),
+            ItemEffect::Explode(damage) => t!("effect-explode", damage = damage.as_string()),
+        };
+        write!(f, "{}", effect)
+    }
+}
+
+pub fn bandages() -> Item {
+    Item {
+        name: "item-bandages",
+        effect: ItemEffect::Restore(PlayerResources {
+            stamina: 20,
+            ..default()
+        }),
+    }
+}
+
+pub fn jerky() -> Item {
+    Item {
+        name: "item-jerky",
+        effect: ItemEffect::Feed(8),
+    }
+}
+
+pub fn smoke_bomb() -> Item {
+    Item {
+        name: "item-smoke-bomb",
+        effect: ItemEffect::Smoke,
+    }
+}
+
+pub fn dynamite() -> Item {
+    Item {
+        name: "item-dynamite",
+        effect: ItemEffect::Explode("3d6".into()),
+    }
+}
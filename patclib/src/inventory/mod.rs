@@ -0,0 +1,303 @@
+use bevy::prelude::*;
+
+use crate::{
+    actions::{Actions, InputAction},
+    battle::Weapon,
+    dice_value::DiceValue,
+    flow::{AppState, FlowEvent},
+    locale::{t, tr},
+    player::Player,
+    ui::UIHelper,
+};
+
+mod items;
+pub use items::{bandages, dynamite, jerky, smoke_bomb, Item, ItemEffect};
+
+/// How many things the player can lug around
+pub const CARRY_LIMIT: usize = 5;
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Inventory {
+    pub weapons: Vec<Weapon>,
+    pub items: Vec<Item>,
+}
+impl Inventory {
+    pub fn item_count(&self) -> usize {
+        self.weapons.len() + self.items.len()
+    }
+
+    pub fn has_room(&self) -> bool {
+        self.item_count() < CARRY_LIMIT
+    }
+
+    /// Returns false if there was no room for it
+    pub fn add_weapon(&mut self, weapon: Weapon) -> bool {
+        let has_room = self.has_room();
+        if has_room {
+            self.weapons.push(weapon);
+        }
+        has_room
+    }
+
+    pub fn remove_weapon(&mut self, name: &str) -> Option<Weapon> {
+        let index = self.weapons.iter().position(|weapon| weapon.name == name)?;
+        Some(self.weapons.remove(index))
+    }
+
+    /// Returns false if there was no room for it
+    pub fn add_item(&mut self, item: Item) -> bool {
+        let has_room = self.has_room();
+        if has_room {
+            self.items.push(item);
+        }
+        has_room
+    }
+
+    pub fn take_item(&mut self, index: usize) -> Item {
+        self.items.remove(index)
+    }
+
+    /// Takes out the first thing in the pack that makes a meal
+    pub fn take_food(&mut self) -> Option<Item> {
+        let index = self
+            .items
+            .iter()
+            .position(|item| matches!(item.effect, ItemEffect::Feed(_)))?;
+        Some(self.items.remove(index))
+    }
+
+    /// Returns false if there was no such weapon
+    pub fn upgrade_weapon(&mut self, name: &str, upgrade: WeaponUpgrade) -> bool {
+        if let Some(weapon) = self.weapons.iter_mut().find(|weapon| weapon.name == name) {
+            weapon.damage += upgrade.damage;
+            weapon.range += upgrade.range;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Added on top of a weapon's existing stats
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct WeaponUpgrade {
+    pub damage: DiceValue,
+    pub range: u32,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Selected {
+    Weapon(usize),
+    Item(usize),
+}
+
+#[derive(Debug, Copy, Clone)]
+enum ItemChoice {
+    Use,
+    Keep,
+    Drop,
+}
+
+/// The thing being looked at, if any
+#[derive(Debug, Default)]
+struct InventoryScreen {
+    selected: Option<Selected>,
+}
+
+pub struct InventoryPlugin;
+
+impl Plugin for InventoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_update(AppState::Travel).with_system(open_inventory))
+            .add_system_set(SystemSet::on_enter(AppState::Inventory).with_system(init_screen))
+            .add_system_set(SystemSet::on_update(AppState::Inventory).with_system(update_screen));
+    }
+}
+
+/// Closes the pack if it was open
+pub(crate) fn reset(world: &mut World) {
+    world.remove_resource::<InventoryScreen>();
+}
+
+fn open_inventory(actions: Res<Actions>, mut flow: EventWriter<FlowEvent>) {
+    if actions.just_pressed(InputAction::Inventory) {
+        // Dropped if an encounter was started on the same frame, that one takes precedence
+        flow.send(FlowEvent::InventoryOpened);
+    }
+}
+
+fn init_screen(mut commands: Commands, mut ui_helper: ResMut<UIHelper>, player: Res<Player>) {
+    commands.insert_resource(InventoryScreen::default());
+    ui_helper.interrupt();
+    prompt_for_thing(&mut ui_helper, &player.inventory);
+}
+
+fn update_screen(
+    mut commands: Commands,
+    mut flow: EventWriter<FlowEvent>,
+    mut ui_helper: ResMut<UIHelper>,
+    mut player: ResMut<Player>,
+    mut screen: ResMut<InventoryScreen>,
+) {
+    if let Some(decision) = player.drain_decision() {
+        let weapon_count = player.inventory.weapons.len();
+        let item_count = player.inventory.items.len();
+
+        match screen.selected.take() {
+            Some(Selected::Weapon(index)) => {
+                if decision == 1 {
+                    let weapon = player.inventory.weapons[index];
+                    if weapon_count > 1 {
+                        player.inventory.remove_weapon(weapon.name);
+                        ui_helper.show_line(t!("inventory-leave", thing = tr(weapon.name)));
+                    } else {
+                        ui_helper.show_line(t!("inventory-empty-handed"));
+                    }
+                }
+                prompt_for_thing(&mut ui_helper, &player.inventory);
+            }
+            Some(Selected::Item(index)) => {
+                let item = player.inventory.items[index];
+                match item_choices(&item)[decision].1 {
+                    ItemChoice::Use => {
+                        player.inventory.take_item(index);
+                        item.effect.apply(&mut player);
+                        ui_helper.show_line(t!("inventory-use", thing = tr(item.name)));
+                    }
+                    ItemChoice::Keep => {}
+                    ItemChoice::Drop => {
+                        player.inventory.take_item(index);
+                        ui_helper.show_line(t!("inventory-leave", thing = tr(item.name)));
+                    }
+                }
+                prompt_for_thing(&mut ui_helper, &player.inventory);
+            }
+            None if decision < weapon_count => {
+                let weapon = player.inventory.weapons[decision];
+                screen.selected = Some(Selected::Weapon(decision));
+                ui_helper.prompt(
+                    t!(
+                        "inventory-weapon",
+                        weapon = tr(weapon.name),
+                        damage = weapon.damage.as_string(),
+                        range = weapon.range
+                    ),
+                    vec![t!("inventory-keep"), t!("inventory-drop")],
+                );
+            }
+            None if decision < weapon_count + item_count => {
+                let index = decision - weapon_count;
+                let item = player.inventory.items[index];
+                screen.selected = Some(Selected::Item(index));
+                ui_helper.prompt(
+                    format!("{}, {}", tr(item.name), item.effect),
+                    item_choices(&item)
+                        .into_iter()
+                        .map(|(line, _)| tr(line))
+                        .collect(),
+                );
+            }
+            None => {
+                // Close
+                commands.remove_resource::<InventoryScreen>();
+                ui_helper.resume();
+                flow.send(FlowEvent::InventoryClosed);
+            }
+        }
+    }
+}
+
+fn item_choices(item: &Item) -> Vec<(&'static str, ItemChoice)> {
+    let mut choices = vec![];
+    if item.effect.usable_outside_battle() {
+        choices.push(("inventory-use-it", ItemChoice::Use));
+    }
+    choices.push(("inventory-keep", ItemChoice::Keep));
+    choices.push(("inventory-drop", ItemChoice::Drop));
+    choices
+}
+
+fn prompt_for_thing(ui_helper: &mut ResMut<UIHelper>, inventory: &Inventory) {
+    let mut options: Vec<String> = inventory
+        .weapons
+        .iter()
+        .map(|weapon| tr(weapon.name))
+        .chain(inventory.items.iter().map(|item| tr(item.name)))
+        .collect();
+    options.push(t!("inventory-close"));
+    ui_helper.prompt(
+        t!(
+            "inventory-prompt",
+            count = inventory.item_count(),
+            limit = CARRY_LIMIT
+        ),
+        options,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::{bandages, jerky, Inventory, WeaponUpgrade, CARRY_LIMIT};
+    use crate::battle::Weapon;
+
+    fn weapon(name: &'static str) -> Weapon {
+        Weapon {
+            name,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn carry_limit() {
+        let mut inventory = Inventory::default();
+        for _ in 0..CARRY_LIMIT {
+            assert!(inventory.add_weapon(weapon("Stick")));
+        }
+        assert!(!inventory.add_weapon(weapon("One stick too many")));
+        assert_eq!(inventory.item_count(), CARRY_LIMIT);
+    }
+
+    #[test]
+    fn items_share_the_carry_limit() {
+        let mut inventory = Inventory::default();
+        for _ in 0..CARRY_LIMIT - 1 {
+            assert!(inventory.add_weapon(weapon("Stick")));
+        }
+        assert!(inventory.add_item(jerky()));
+        assert!(!inventory.add_item(jerky()));
+        assert_eq!(inventory.take_item(0), jerky());
+        assert!(inventory.has_room());
+    }
+
+    #[test]
+    fn food_comes_out_first_found() {
+        let mut inventory = Inventory::default();
+        inventory.add_item(bandages());
+        assert_eq!(inventory.take_food(), None);
+        inventory.add_item(jerky());
+        assert_eq!(inventory.take_food(), Some(jerky()));
+        assert_eq!(inventory.items, vec![bandages()]);
+    }
+
+    #[test]
+    fn upgrade() {
+        let mut inventory = Inventory::default();
+        inventory.add_weapon(Weapon {
+            damage: "1d6".into(),
+            ..weapon("Stick")
+        });
+        assert!(inventory.upgrade_weapon(
+            "Stick",
+            WeaponUpgrade {
+                damage: "1d6+1".into(),
+                range: 1,
+            }
+        ));
+        assert!(!inventory.upgrade_weapon("Branch", WeaponUpgrade::default()));
+
+        let stick = inventory.remove_weapon("Stick").unwrap();
+        assert_eq!(stick.damage, "2d6+1".into());
+        assert_eq!(stick.range, 2);
+        assert_eq!(inventory.item_count(), 0);
+    }
+}
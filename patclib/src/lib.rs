@@ -1,11 +1,21 @@
 mod dice_value;
 use bevy::prelude::*;
 
+mod actions;
 mod assets;
 mod battle;
+mod companion;
 mod encounter;
+mod files;
 mod flow;
+mod inventory;
+mod locale;
+mod loot;
+mod menu;
+mod minigame;
+mod payment;
 mod player;
+mod shop;
 mod travel;
 mod ui;
 
@@ -13,10 +23,16 @@ pub struct PatcPlugins;
 impl PluginGroup for PatcPlugins {
     fn build(&mut self, group: &mut bevy::app::PluginGroupBuilder) {
         group
+            .add(actions::ActionPlugin)
             .add(assets::AssetPlugin)
             .add(flow::FlowPlugin)
             .add(battle::BattlePlugin)
             .add(encounter::EncounterPlugin)
+            .add(inventory::InventoryPlugin)
+            .add(menu::MenuPlugin)
+            .add(minigame::MinigamePlugin)
+            .add(payment::PaymentPlugin)
+            .add(shop::ShopPlugin)
             .add(travel::TravelPlugin)
             .add(ui::UIPlugin)
             .add(player::PlayerPlugin);
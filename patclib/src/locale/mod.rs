@@ -0,0 +1,204 @@
+use core::fmt;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    OnceLock,
+};
+
+use fluent::{concurrent::FluentBundle, FluentArgs, FluentResource};
+use unic_langid::LanguageIdentifier;
+
+/// Every language the game can be played in, each with its own file of messages.
+/// As a resource it's the one the game is being played in, change it to relabel the UI
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Language {
+    #[default]
+    English,
+    Finnish,
+}
+impl fmt::Display for Language {
+    /// Each language goes by the name its speakers know it by
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Language::English => write!(f, "English"),
+            Language::Finnish => write!(f, "Suomi"),
+        }
+    }
+}
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::Finnish];
+
+    pub fn next(self) -> Self {
+        match self {
+            Language::English => Language::Finnish,
+            Language::Finnish => Language::English,
+        }
+    }
+
+    fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Finnish => "fi",
+        }
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Language::English => include_str!("../../locales/en.ftl"),
+            Language::Finnish => include_str!("../../locales/fi.ftl"),
+        }
+    }
+
+    fn index(self) -> usize {
+        Language::ALL
+            .iter()
+            .position(|language| *language == self)
+            .unwrap()
+    }
+
+    fn bundle(self) -> &'static FluentBundle<FluentResource> {
+        static BUNDLES: OnceLock<Vec<FluentBundle<FluentResource>>> = OnceLock::new();
+        &BUNDLES.get_or_init(|| {
+            Language::ALL
+                .iter()
+                .map(|language| language.load())
+                .collect()
+        })[self.index()]
+    }
+
+    fn load(self) -> FluentBundle<FluentResource> {
+        let resource = FluentResource::try_new(self.source().to_string())
+            .unwrap_or_else(|(_, errors)| panic!("Broken {} locale: {:?}", self.code(), errors));
+        let id: LanguageIdentifier = self.code().parse().unwrap();
+        let mut bundle = FluentBundle::new_concurrent(vec![id]);
+        // The font has no glyphs for the marks that keep right-to-left text apart
+        bundle.set_use_isolating(false);
+        bundle
+            .add_resource(resource)
+            .unwrap_or_else(|errors| panic!("Clashing {} messages: {:?}", self.code(), errors));
+        bundle
+    }
+
+    /// The message in this language, the id itself if it's missing
+    pub fn format(self, id: &str, args: Option<&FluentArgs>) -> String {
+        let bundle = self.bundle();
+        let pattern = match bundle.get_message(id).and_then(|message| message.value()) {
+            Some(pattern) => pattern,
+            None => return id.to_string(),
+        };
+        let mut errors = vec![];
+        // A missing argument still leaves the rest of the message readable
+        bundle
+            .format_pattern(pattern, args, &mut errors)
+            .into_owned()
+    }
+}
+
+/// The `Language` resource as `tr` sees it, messages are looked up from Display impls
+/// and helpers that can't reach the world
+static LOOKUP: AtomicUsize = AtomicUsize::new(0);
+
+fn language() -> Language {
+    Language::ALL[LOOKUP.load(Ordering::Relaxed)]
+}
+
+/// Looks messages up in the newly switched `Language` resource from here on.
+/// Only the system switching the resource calls this, what's on screen redraws off its change
+pub fn look_up_in(language: Language) {
+    LOOKUP.store(language.index(), Ordering::Relaxed);
+}
+
+/// Looks up a message in the current language, for ids kept around in data
+pub fn tr(id: &str) -> String {
+    language().format(id, None)
+}
+
+pub fn tr_with(id: &str, args: &FluentArgs) -> String {
+    language().format(id, Some(args))
+}
+
+/// `t!("battle-creeps-closer", enemy = name)` looks up a message and fills in its arguments
+macro_rules! t {
+    ($id:expr) => {
+        $crate::locale::tr($id)
+    };
+    ($id:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = fluent::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)+
+        $crate::locale::tr_with($id, &args)
+    }};
+}
+pub(crate) use t;
+
+#[cfg(test)]
+mod test {
+    use std::{collections::BTreeSet, fs, path::Path};
+
+    use fluent::FluentArgs;
+    use regex::Regex;
+
+    use super::Language;
+
+    /// Message ids are the only kebab-case string literals in the code
+    fn ids_in(dir: &Path, ids: &mut BTreeSet<String>) {
+        let id = Regex::new(r#""([a-z][a-z0-9]*(?:-[a-z0-9]+)+)""#).unwrap();
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                ids_in(&path, ids);
+            } else if path.extension().is_some_and(|extension| extension == "rs") {
+                let source = fs::read_to_string(&path).unwrap();
+                ids.extend(id.captures_iter(&source).map(|found| found[1].to_string()));
+            }
+        }
+    }
+
+    fn messages(language: Language) -> BTreeSet<String> {
+        let message = Regex::new(r"(?m)^([a-z][a-z0-9-]*) =").unwrap();
+        message
+            .captures_iter(language.source())
+            .map(|found| found[1].to_string())
+            .collect()
+    }
+
+    #[test]
+    fn every_id_is_in_every_locale() {
+        let mut used = BTreeSet::new();
+        ids_in(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+            &mut used,
+        );
+        assert!(used.len() > 100);
+        for language in Language::ALL {
+            let messages = messages(language);
+            let missing: Vec<_> = used.difference(&messages).collect();
+            assert!(missing.is_empty(), "{} is missing {:?}", language, missing);
+            let unused: Vec<_> = messages.difference(&used).collect();
+            assert!(unused.is_empty(), "{} has unused {:?}", language, unused);
+        }
+    }
+
+    #[test]
+    fn arguments_are_filled_in() {
+        let mut args = FluentArgs::new();
+        args.set("enemy", "Gamma wolf");
+        assert_eq!(
+            Language::English.format("battle-creeps-closer", Some(&args)),
+            "Gamma wolf creeps closer"
+        );
+        assert_eq!(
+            Language::Finnish.format("battle-creeps-closer", Some(&args)),
+            "Gamma wolf hiipii lähemmäs"
+        );
+        assert_eq!(Language::English.format("nonsense", None), "nonsense");
+    }
+
+    #[test]
+    fn languages_go_round() {
+        let mut language = Language::English;
+        for _ in Language::ALL {
+            language = language.next();
+        }
+        assert_eq!(language, Language::English);
+        assert_eq!(Language::Finnish.to_string(), "Suomi");
+    }
+}
@@ -0,0 +1,95 @@
+use core::fmt;
+
+use rand::{seq::SliceRandom, thread_rng, Rng};
+
+use crate::{battle::Weapon, inventory::Item, locale::tr, player::PlayerResources};
+
+/// Something that can be handed to the player
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Loot {
+    Resources(PlayerResources),
+    Weapon(Weapon),
+    Item(Item),
+}
+impl fmt::Display for Loot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Loot::Resources(resources) => write!(f, "{}", resources),
+            Loot::Weapon(weapon) => write!(f, "{}", tr(weapon.name)),
+            Loot::Item(item) => write!(f, "{}", tr(item.name)),
+        }
+    }
+}
+
+/// Weighted entries, at most one of which drops per roll
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct LootTable {
+    entries: Vec<(u32, Option<Loot>)>,
+}
+impl LootTable {
+    pub fn with(entries: Vec<(u32, Loot)>) -> Self {
+        Self {
+            entries: entries
+                .into_iter()
+                .map(|(weight, loot)| (weight, Some(loot)))
+                .collect(),
+        }
+    }
+
+    /// Adds a chance of nothing dropping at all
+    pub fn or_nothing(mut self, weight: u32) -> Self {
+        self.entries.push((weight, None));
+        self
+    }
+
+    pub fn roll(&self) -> Option<Loot> {
+        self.roll_with(&mut thread_rng())
+    }
+
+    fn roll_with(&self, rng: &mut impl Rng) -> Option<Loot> {
+        self.entries
+            .choose_weighted(rng, |(weight, _)| *weight)
+            .ok()
+            .and_then(|(_, loot)| loot.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{Loot, LootTable};
+    use crate::player::PlayerResources;
+
+    fn bullets(amount: i32) -> Loot {
+        Loot::Resources(PlayerResources {
+            bullets: amount,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn empty_table_drops_nothing() {
+        let mut rng = StdRng::seed_from_u64(0);
+        assert_eq!(LootTable::default().roll_with(&mut rng), None);
+    }
+
+    #[test]
+    fn nothing_drops_nothing() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let table = LootTable::with(vec![(0, bullets(1))]).or_nothing(1);
+        for _ in 0..100 {
+            assert_eq!(table.roll_with(&mut rng), None);
+        }
+    }
+
+    #[test]
+    fn weights_are_respected() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let table = LootTable::with(vec![(3, bullets(1)), (1, bullets(2))]);
+        let common = (0..1000)
+            .filter(|_| table.roll_with(&mut rng) == Some(bullets(1)))
+            .count();
+        assert!((650..850).contains(&common));
+    }
+}
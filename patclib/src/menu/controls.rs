@@ -0,0 +1,157 @@
+use bevy::prelude::*;
+
+use crate::{
+    actions::{save_bindings, Binding, Bindings, Conflict, InputAction},
+    flow::FlowEvent,
+    locale::{t, tr},
+    player::Player,
+    ui::UIHelper,
+};
+
+/// The controls, a few at a time so every prompt fits on screen, each group with its message id
+const GROUPS: [(&str, &[InputAction]); 3] = [
+    (
+        "controls-choosing",
+        &[
+            InputAction::Prev,
+            InputAction::Next,
+            InputAction::Confirm,
+            InputAction::Back,
+            InputAction::Palette,
+        ],
+    ),
+    (
+        "controls-screens",
+        &[
+            InputAction::Pause,
+            InputAction::Journal,
+            InputAction::Inventory,
+            InputAction::Map,
+        ],
+    ),
+    (
+        "controls-road",
+        &[
+            InputAction::Hurry,
+            InputAction::Skip,
+            InputAction::ScrollUp,
+            InputAction::ScrollDown,
+        ],
+    ),
+];
+
+/// Where the player is on the controls screen
+#[derive(Debug, Copy, Clone, Default)]
+pub(super) enum ControlsScreen {
+    #[default]
+    Groups,
+    Group(usize),
+    /// Waiting for the key or button to bind
+    Listening {
+        group: usize,
+        action: InputAction,
+    },
+    /// Heard it, the group is asked again next frame so the same press doesn't answer it
+    Heard(usize),
+}
+
+pub(super) fn open_controls(mut commands: Commands, mut ui_helper: ResMut<UIHelper>) {
+    commands.insert_resource(ControlsScreen::default());
+    prompt_groups(&mut ui_helper);
+}
+
+pub(super) fn update_controls(
+    mut commands: Commands,
+    mut flow: EventWriter<FlowEvent>,
+    mut ui_helper: ResMut<UIHelper>,
+    mut player: ResMut<Player>,
+    mut screen: ResMut<ControlsScreen>,
+    mut bindings: ResMut<Bindings>,
+    (kb_inputs, gamepad_inputs): (Res<Input<KeyCode>>, Res<Input<GamepadButton>>),
+) {
+    match *screen {
+        ControlsScreen::Groups => match player.drain_decision() {
+            Some(group) if group < GROUPS.len() => {
+                *screen = ControlsScreen::Group(group);
+                prompt_group(&mut ui_helper, &bindings, group);
+            }
+            Some(group) if group == GROUPS.len() => {
+                *bindings = Bindings::default();
+                save_bindings(&bindings);
+                ui_helper.show_line(t!("controls-reset-done"));
+                prompt_groups(&mut ui_helper);
+            }
+            Some(_) => {
+                commands.remove_resource::<ControlsScreen>();
+                flow.send(FlowEvent::ControlsClosed);
+            }
+            None => {}
+        },
+        ControlsScreen::Group(group) => match player.drain_decision() {
+            Some(index) if index < GROUPS[group].1.len() => {
+                let action = GROUPS[group].1[index];
+                ui_helper.show_line(t!("controls-listening", action = action.to_string()));
+                *screen = ControlsScreen::Listening { group, action };
+            }
+            Some(_) => {
+                *screen = ControlsScreen::Groups;
+                prompt_groups(&mut ui_helper);
+            }
+            None => {}
+        },
+        ControlsScreen::Listening { group, action } => {
+            let heard = kb_inputs
+                .get_just_pressed()
+                .map(|key| Binding::Key(*key))
+                .chain(
+                    gamepad_inputs
+                        .get_just_pressed()
+                        .map(|GamepadButton(_, button)| Binding::Button(*button)),
+                )
+                .next();
+            let binding = if let Some(binding) = heard {
+                binding
+            } else {
+                return;
+            };
+            match bindings.rebind(action, binding) {
+                Ok(()) => {
+                    save_bindings(&bindings);
+                    ui_helper.show_line(bindings.describe(action));
+                }
+                Err(Conflict::Taken(other)) => {
+                    ui_helper.show_line(t!(
+                        "controls-taken",
+                        binding = binding.to_string(),
+                        action = other.to_string()
+                    ));
+                }
+                Err(Conflict::Reserved) => {
+                    ui_helper.show_line(t!("controls-reserved", binding = binding.to_string()));
+                }
+            }
+            *screen = ControlsScreen::Heard(group);
+        }
+        ControlsScreen::Heard(group) => {
+            *screen = ControlsScreen::Group(group);
+            prompt_group(&mut ui_helper, &bindings, group);
+        }
+    }
+}
+
+fn prompt_groups(ui_helper: &mut ResMut<UIHelper>) {
+    let mut options: Vec<String> = GROUPS.iter().map(|(name, _)| tr(name)).collect();
+    options.push(t!("controls-reset"));
+    options.push(t!("controls-back"));
+    ui_helper.prompt(t!("controls-prompt"), options);
+}
+
+fn prompt_group(ui_helper: &mut ResMut<UIHelper>, bindings: &Bindings, group: usize) {
+    let (name, actions) = GROUPS[group];
+    for action in actions {
+        ui_helper.show_line(bindings.describe(*action));
+    }
+    let mut options: Vec<String> = actions.iter().map(|action| action.to_string()).collect();
+    options.push(t!("controls-back"));
+    ui_helper.prompt(t!("controls-group-prompt", group = tr(name)), options);
+}
@@ -0,0 +1,296 @@
+use bevy::{app::AppExit, prelude::*};
+
+use crate::{
+    actions::{Actions, InputAction},
+    encounter::{game_start, OngoingEncounter},
+    flow::{AppState, EndRun, FlowEvent, StartRun},
+    locale::{self, t, tr, Language},
+    player::Player,
+    travel::{SavedRun, TravelConfig},
+    ui::{TextPacing, UIHelper},
+};
+
+mod controls;
+mod settings;
+use controls::{open_controls, update_controls};
+use settings::Settings;
+
+#[derive(Debug, Copy, Clone)]
+enum MainMenuChoice {
+    NewGame,
+    Continue,
+    Settings,
+    Quit,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum PauseChoice {
+    Resume,
+    Settings,
+    MainMenu,
+    Quit,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum GameOverChoice {
+    RideAgain,
+    MainMenu,
+    Quit,
+}
+
+const PAUSE_CHOICES: [(&str, PauseChoice); 4] = [
+    ("menu-resume", PauseChoice::Resume),
+    ("menu-settings", PauseChoice::Settings),
+    ("menu-quit-to-menu", PauseChoice::MainMenu),
+    ("menu-quit-game", PauseChoice::Quit),
+];
+
+const GAME_OVER_CHOICES: [(&str, GameOverChoice); 3] = [
+    ("menu-ride-again", GameOverChoice::RideAgain),
+    ("menu-main-menu", GameOverChoice::MainMenu),
+    ("menu-quit-game", GameOverChoice::Quit),
+];
+
+/// Left behind by New game, the road starts once the intro is over
+#[derive(Debug)]
+struct StartingRun;
+
+pub struct MenuPlugin;
+
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Settings>()
+            .add_system(toggle_pause)
+            .add_system_set(SystemSet::on_enter(AppState::MainMenu).with_system(open_main_menu))
+            .add_system_set(SystemSet::on_resume(AppState::MainMenu).with_system(resume_main_menu))
+            .add_system_set(SystemSet::on_update(AppState::MainMenu).with_system(update_main_menu))
+            .add_system_set(SystemSet::on_enter(AppState::Settings).with_system(open_settings))
+            .add_system_set(SystemSet::on_resume(AppState::Settings).with_system(open_settings))
+            .add_system_set(SystemSet::on_update(AppState::Settings).with_system(update_settings))
+            .add_system_set(SystemSet::on_enter(AppState::Controls).with_system(open_controls))
+            .add_system_set(SystemSet::on_update(AppState::Controls).with_system(update_controls))
+            .add_system_set(SystemSet::on_enter(AppState::Paused).with_system(open_pause_menu))
+            .add_system_set(SystemSet::on_resume(AppState::Paused).with_system(prompt_pause_menu))
+            .add_system_set(SystemSet::on_update(AppState::Paused).with_system(update_pause_menu))
+            .add_system_set(SystemSet::on_resume(AppState::GameOver).with_system(prompt_game_over))
+            .add_system_set(SystemSet::on_update(AppState::GameOver).with_system(update_game_over));
+    }
+}
+
+fn open_main_menu(
+    mut commands: Commands,
+    mut ui_helper: ResMut<UIHelper>,
+    saved_run: Option<Res<SavedRun>>,
+) {
+    commands.remove_resource::<StartingRun>();
+    ui_helper.show_highlighted_line(t!("game-title"));
+    prompt_main_menu(&mut ui_helper, saved_run.is_some());
+}
+
+/// Back from settings, or from the intro of a new run
+fn resume_main_menu(
+    mut commands: Commands,
+    mut flow: EventWriter<FlowEvent>,
+    mut ui_helper: ResMut<UIHelper>,
+    starting_run: Option<Res<StartingRun>>,
+    saved_run: Option<Res<SavedRun>>,
+) {
+    if starting_run.is_some() {
+        commands.remove_resource::<StartingRun>();
+        flow.send(FlowEvent::RunStarted);
+    } else {
+        prompt_main_menu(&mut ui_helper, saved_run.is_some());
+    }
+}
+
+fn update_main_menu(
+    mut commands: Commands,
+    mut flow: EventWriter<FlowEvent>,
+    mut ui_helper: ResMut<UIHelper>,
+    mut player: ResMut<Player>,
+    saved_run: Option<Res<SavedRun>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let decision = if let Some(decision) = player.drain_decision() {
+        decision
+    } else {
+        return;
+    };
+    match main_menu_choices(saved_run.is_some())[decision].1 {
+        MainMenuChoice::NewGame => {
+            commands.add(StartRun::fresh());
+            commands.insert_resource(StartingRun);
+            commands.insert_resource(OngoingEncounter(game_start()));
+            flow.send(FlowEvent::EncounterStarted);
+        }
+        MainMenuChoice::Continue => {
+            commands.add(StartRun(SavedRun::clone(&saved_run.unwrap())));
+            ui_helper.show_line(t!("menu-continued"));
+            flow.send(FlowEvent::RunStarted);
+        }
+        MainMenuChoice::Settings => flow.send(FlowEvent::SettingsOpened),
+        MainMenuChoice::Quit => exit.send(AppExit),
+    }
+}
+
+fn main_menu_choices(has_save: bool) -> Vec<(&'static str, MainMenuChoice)> {
+    let mut choices = vec![("menu-new-game", MainMenuChoice::NewGame)];
+    if has_save {
+        choices.push(("menu-continue", MainMenuChoice::Continue));
+    }
+    choices.push(("menu-settings", MainMenuChoice::Settings));
+    choices.push(("menu-quit", MainMenuChoice::Quit));
+    choices
+}
+
+fn prompt_main_menu(ui_helper: &mut ResMut<UIHelper>, has_save: bool) {
+    ui_helper.prompt(
+        t!("menu-prompt"),
+        main_menu_choices(has_save)
+            .into_iter()
+            .map(|(line, _)| tr(line))
+            .collect(),
+    );
+}
+
+fn open_settings(mut ui_helper: ResMut<UIHelper>, settings: Res<Settings>) {
+    prompt_settings(&mut ui_helper, &settings);
+}
+
+fn update_settings(
+    mut flow: EventWriter<FlowEvent>,
+    mut ui_helper: ResMut<UIHelper>,
+    mut player: ResMut<Player>,
+    mut settings: ResMut<Settings>,
+    (mut config, mut pacing): (ResMut<TravelConfig>, ResMut<TextPacing>),
+    mut language: ResMut<Language>,
+) {
+    match player.drain_decision() {
+        Some(0) => {
+            settings.cycle_pace(&mut config);
+            prompt_settings(&mut ui_helper, &settings);
+        }
+        Some(1) => {
+            settings.cycle_road_events(&mut config);
+            prompt_settings(&mut ui_helper, &settings);
+        }
+        Some(2) => {
+            settings.cycle_text_speed(&mut pacing);
+            prompt_settings(&mut ui_helper, &settings);
+        }
+        Some(3) => {
+            settings.toggle_continue(&mut pacing);
+            prompt_settings(&mut ui_helper, &settings);
+        }
+        Some(4) => {
+            settings.cycle_language(&mut language);
+            // The settings are prompted for again right away, in the new language
+            locale::look_up_in(*language);
+            prompt_settings(&mut ui_helper, &settings);
+        }
+        Some(5) => flow.send(FlowEvent::ControlsOpened),
+        Some(_) => flow.send(FlowEvent::SettingsClosed),
+        None => {}
+    }
+}
+
+fn prompt_settings(ui_helper: &mut ResMut<UIHelper>, settings: &Settings) {
+    ui_helper.prompt(t!("menu-settings"), settings.options());
+}
+
+/// Escape or Start pauses whatever is going on, and unpauses it again
+fn toggle_pause(
+    actions: Res<Actions>,
+    app_state: Res<State<AppState>>,
+    mut flow: EventWriter<FlowEvent>,
+    mut ui_helper: ResMut<UIHelper>,
+) {
+    if !actions.just_pressed(InputAction::Pause) {
+        return;
+    }
+    match app_state.current() {
+        AppState::Paused => {
+            ui_helper.resume();
+            flow.send(FlowEvent::Unpaused);
+        }
+        // The key closes those panels, it shouldn't pause the game right after
+        AppState::Journal | AppState::Map => {}
+        _ => {
+            // Dropped in places where pausing makes no sense, like the menus
+            flow.send(FlowEvent::Paused);
+        }
+    }
+}
+
+fn open_pause_menu(mut ui_helper: ResMut<UIHelper>) {
+    ui_helper.interrupt();
+    prompt_pause_menu(ui_helper);
+}
+
+fn prompt_pause_menu(mut ui_helper: ResMut<UIHelper>) {
+    ui_helper.prompt(
+        t!("menu-paused"),
+        PAUSE_CHOICES.iter().map(|(line, _)| tr(line)).collect(),
+    );
+}
+
+fn update_pause_menu(
+    mut commands: Commands,
+    mut flow: EventWriter<FlowEvent>,
+    mut ui_helper: ResMut<UIHelper>,
+    mut player: ResMut<Player>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let decision = if let Some(decision) = player.drain_decision() {
+        decision
+    } else {
+        return;
+    };
+    match PAUSE_CHOICES[decision].1 {
+        PauseChoice::Resume => {
+            ui_helper.resume();
+            flow.send(FlowEvent::Unpaused);
+        }
+        PauseChoice::Settings => flow.send(FlowEvent::SettingsOpened),
+        PauseChoice::MainMenu => {
+            commands.add(EndRun);
+            ui_helper.show_line(t!("menu-left-the-road"));
+            flow.send(FlowEvent::QuitToMenu);
+        }
+        PauseChoice::Quit => exit.send(AppExit),
+    }
+}
+
+/// The final words have been said, now what
+fn prompt_game_over(mut ui_helper: ResMut<UIHelper>) {
+    ui_helper.prompt(
+        t!("menu-game-over"),
+        GAME_OVER_CHOICES.iter().map(|(line, _)| tr(line)).collect(),
+    );
+}
+
+fn update_game_over(
+    mut commands: Commands,
+    mut flow: EventWriter<FlowEvent>,
+    mut ui_helper: ResMut<UIHelper>,
+    mut player: ResMut<Player>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let decision = if let Some(decision) = player.drain_decision() {
+        decision
+    } else {
+        return;
+    };
+    match GAME_OVER_CHOICES[decision].1 {
+        GameOverChoice::RideAgain => {
+            commands.add(StartRun::fresh());
+            ui_helper.show_line(t!("menu-ride-again-line"));
+            flow.send(FlowEvent::RunStarted);
+        }
+        GameOverChoice::MainMenu => {
+            commands.add(EndRun);
+            flow.send(FlowEvent::QuitToMenu);
+        }
+        GameOverChoice::Quit => exit.send(AppExit),
+    }
+}
@@ -0,0 +1,263 @@
+use core::fmt;
+
+use crate::{
+    locale::{t, tr, Language},
+    travel::TravelConfig,
+    ui::TextPacing,
+};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Pace {
+    Brisk,
+    Steady,
+    Leisurely,
+}
+impl fmt::Display for Pace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let id = match self {
+            Pace::Brisk => "pace-brisk",
+            Pace::Steady => "pace-steady",
+            Pace::Leisurely => "pace-leisurely",
+        };
+        write!(f, "{}", tr(id))
+    }
+}
+impl Pace {
+    fn next(self) -> Self {
+        match self {
+            Pace::Brisk => Pace::Steady,
+            Pace::Steady => Pace::Leisurely,
+            Pace::Leisurely => Pace::Brisk,
+        }
+    }
+
+    /// How long a leg takes compared to the configured one
+    fn leg_scale(self) -> f64 {
+        match self {
+            Pace::Brisk => 0.6,
+            Pace::Steady => 1.0,
+            Pace::Leisurely => 1.6,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum RoadEvents {
+    Rare,
+    Occasional,
+    Frequent,
+}
+impl fmt::Display for RoadEvents {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let id = match self {
+            RoadEvents::Rare => "road-events-rare",
+            RoadEvents::Occasional => "road-events-occasional",
+            RoadEvents::Frequent => "road-events-frequent",
+        };
+        write!(f, "{}", tr(id))
+    }
+}
+impl RoadEvents {
+    fn next(self) -> Self {
+        match self {
+            RoadEvents::Rare => RoadEvents::Occasional,
+            RoadEvents::Occasional => RoadEvents::Frequent,
+            RoadEvents::Frequent => RoadEvents::Rare,
+        }
+    }
+
+    /// How long between chances of something happening compared to the configured interval
+    fn interval_scale(self) -> f64 {
+        match self {
+            RoadEvents::Rare => 2.0,
+            RoadEvents::Occasional => 1.0,
+            RoadEvents::Frequent => 0.5,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TextSpeed {
+    Slow,
+    Normal,
+    Fast,
+    Instant,
+}
+impl fmt::Display for TextSpeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let id = match self {
+            TextSpeed::Slow => "text-speed-slow",
+            TextSpeed::Normal => "text-speed-normal",
+            TextSpeed::Fast => "text-speed-fast",
+            TextSpeed::Instant => "text-speed-instant",
+        };
+        write!(f, "{}", tr(id))
+    }
+}
+impl TextSpeed {
+    fn next(self) -> Self {
+        match self {
+            TextSpeed::Slow => TextSpeed::Normal,
+            TextSpeed::Normal => TextSpeed::Fast,
+            TextSpeed::Fast => TextSpeed::Instant,
+            TextSpeed::Instant => TextSpeed::Slow,
+        }
+    }
+
+    fn chars_per_second(self) -> f32 {
+        match self {
+            TextSpeed::Slow => 25.0,
+            TextSpeed::Normal => 60.0,
+            TextSpeed::Fast => 150.0,
+            TextSpeed::Instant => f32::INFINITY,
+        }
+    }
+}
+
+/// What the player can tweak, applied on top of the travel config the game started with
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Settings {
+    pub pace: Pace,
+    pub road_events: RoadEvents,
+    pub text_speed: TextSpeed,
+    pub wait_for_continue: bool,
+    pub language: Language,
+}
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            pace: Pace::Steady,
+            road_events: RoadEvents::Occasional,
+            text_speed: TextSpeed::Normal,
+            wait_for_continue: false,
+            language: Language::English,
+        }
+    }
+}
+impl Settings {
+    pub fn cycle_pace(&mut self, config: &mut TravelConfig) {
+        let pace = self.pace.next();
+        config.leg_duration *= pace.leg_scale() / self.pace.leg_scale();
+        self.pace = pace;
+    }
+
+    pub fn cycle_road_events(&mut self, config: &mut TravelConfig) {
+        let road_events = self.road_events.next();
+        config.event_interval *= road_events.interval_scale() / self.road_events.interval_scale();
+        self.road_events = road_events;
+    }
+
+    pub fn cycle_text_speed(&mut self, pacing: &mut TextPacing) {
+        self.text_speed = self.text_speed.next();
+        pacing.chars_per_second = self.text_speed.chars_per_second();
+    }
+
+    pub fn toggle_continue(&mut self, pacing: &mut TextPacing) {
+        self.wait_for_continue = !self.wait_for_continue;
+        pacing.wait_for_continue = self.wait_for_continue;
+    }
+
+    pub fn cycle_language(&mut self, language: &mut Language) {
+        self.language = self.language.next();
+        *language = self.language;
+    }
+
+    /// The settings screen, one option per setting, the controls and a way out
+    pub fn options(&self) -> Vec<String> {
+        let wait_for_continue = if self.wait_for_continue {
+            t!("settings-on")
+        } else {
+            t!("settings-off")
+        };
+        vec![
+            t!("settings-pace", pace = self.pace.to_string()),
+            t!(
+                "settings-road-events",
+                frequency = self.road_events.to_string()
+            ),
+            t!("settings-text-speed", speed = self.text_speed.to_string()),
+            t!("settings-continue", setting = wait_for_continue),
+            t!("settings-language", language = self.language.to_string()),
+            t!("settings-controls"),
+            t!("settings-back"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Pace, RoadEvents, Settings, TextSpeed};
+    use crate::{locale::Language, travel::TravelConfig, ui::TextPacing};
+
+    fn close_enough(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn pace_changes_leg_length() {
+        let mut settings = Settings::default();
+        let mut config = TravelConfig::default();
+        settings.cycle_pace(&mut config);
+        assert_eq!(settings.pace, Pace::Leisurely);
+        assert!(config.leg_duration > TravelConfig::default().leg_duration);
+        settings.cycle_pace(&mut config);
+        assert_eq!(settings.pace, Pace::Brisk);
+        assert!(config.leg_duration < TravelConfig::default().leg_duration);
+    }
+
+    #[test]
+    fn cycling_all_the_way_around_changes_nothing() {
+        let mut settings = Settings::default();
+        let mut config = TravelConfig {
+            leg_duration: 7.0,
+            event_interval: 3.0,
+            ..TravelConfig::default()
+        };
+        for _ in 0..3 {
+            settings.cycle_pace(&mut config);
+            settings.cycle_road_events(&mut config);
+        }
+        assert_eq!(settings, Settings::default());
+        assert!(close_enough(config.leg_duration, 7.0));
+        assert!(close_enough(config.event_interval, 3.0));
+    }
+
+    #[test]
+    fn frequent_events_come_sooner() {
+        let mut settings = Settings::default();
+        let mut config = TravelConfig::default();
+        settings.cycle_road_events(&mut config);
+        assert_eq!(settings.road_events, RoadEvents::Frequent);
+        assert!(close_enough(
+            config.event_interval,
+            TravelConfig::default().event_interval / 2.0
+        ));
+    }
+
+    #[test]
+    fn text_settings_pace_the_chat() {
+        let mut settings = Settings::default();
+        let mut pacing = TextPacing::default();
+        settings.cycle_text_speed(&mut pacing);
+        assert_eq!(settings.text_speed, TextSpeed::Fast);
+        assert!(pacing.chars_per_second > TextPacing::default().chars_per_second);
+        settings.cycle_text_speed(&mut pacing);
+        assert!(pacing.chars_per_second.is_infinite());
+        settings.cycle_text_speed(&mut pacing);
+        assert!(pacing.chars_per_second < TextPacing::default().chars_per_second);
+
+        settings.toggle_continue(&mut pacing);
+        assert!(pacing.wait_for_continue);
+        assert_eq!(settings.options()[3], "Continue between lines: on");
+    }
+
+    #[test]
+    fn switching_language_only_touches_the_resource() {
+        let mut settings = Settings::default();
+        let mut language = Language::default();
+        settings.cycle_language(&mut language);
+        assert_eq!(language, Language::Finnish);
+        // Other tests read English off the same lookups, and still can
+        assert_eq!(settings.options()[3], "Continue between lines: off");
+    }
+}
@@ -0,0 +1,63 @@
+/// What a single throw in craps amounts to
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum CrapsThrow {
+    Win,
+    Lose,
+    /// The come-out roll set a point, hit it again before a 7
+    Point(u32),
+    ThrowAgain,
+}
+
+/// Street craps, pass line only
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Craps {
+    pub point: Option<u32>,
+}
+impl Craps {
+    pub fn throw(&mut self, first: u32, second: u32) -> CrapsThrow {
+        let total = first + second;
+        match self.point {
+            None => match total {
+                7 | 11 => CrapsThrow::Win,
+                2 | 3 | 12 => CrapsThrow::Lose,
+                point => {
+                    self.point = Some(point);
+                    CrapsThrow::Point(point)
+                }
+            },
+            Some(point) if total == point => CrapsThrow::Win,
+            Some(_) if total == 7 => CrapsThrow::Lose,
+            Some(_) => CrapsThrow::ThrowAgain,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Craps, CrapsThrow};
+
+    #[test]
+    fn natural_wins() {
+        assert_eq!(Craps::default().throw(3, 4), CrapsThrow::Win);
+        assert_eq!(Craps::default().throw(5, 6), CrapsThrow::Win);
+    }
+
+    #[test]
+    fn craps_loses() {
+        assert_eq!(Craps::default().throw(1, 1), CrapsThrow::Lose);
+        assert_eq!(Craps::default().throw(1, 2), CrapsThrow::Lose);
+        assert_eq!(Craps::default().throw(6, 6), CrapsThrow::Lose);
+    }
+
+    #[test]
+    fn point() {
+        let mut craps = Craps::default();
+        assert_eq!(craps.throw(4, 4), CrapsThrow::Point(8));
+        assert_eq!(craps.throw(5, 6), CrapsThrow::ThrowAgain);
+        assert_eq!(craps.throw(2, 6), CrapsThrow::Win);
+
+        let mut craps = Craps::default();
+        craps.throw(2, 2);
+        assert_eq!(craps.throw(3, 4), CrapsThrow::Lose);
+    }
+}
@@ -0,0 +1,170 @@
+use core::fmt;
+
+use rand::{thread_rng, Rng};
+
+use crate::locale::t;
+
+pub const DICE_PER_HAND: usize = 5;
+
+/// A claim about how many dice on the whole table show a face
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Bid {
+    pub quantity: u32,
+    pub face: u32,
+}
+impl fmt::Display for Bid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            t!("liars-bid", quantity = self.quantity, face = self.face)
+        )
+    }
+}
+impl Bid {
+    pub fn holds(&self, table: &[u32]) -> bool {
+        count(table, self.face) >= self.quantity
+    }
+
+    /// The smallest raise for every face, a raise is more dice or as many of a higher face
+    pub fn raises(&self) -> Vec<Bid> {
+        (1..=6)
+            .map(|face| Bid {
+                quantity: if face > self.face {
+                    self.quantity
+                } else {
+                    self.quantity + 1
+                },
+                face,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum LiarsMove {
+    Bid(Bid),
+    CallLiar,
+}
+
+pub fn roll_hand() -> Vec<u32> {
+    let mut rng = thread_rng();
+    (0..DICE_PER_HAND).map(|_| rng.gen_range(1..=6)).collect()
+}
+
+pub fn count(dice: &[u32], face: u32) -> u32 {
+    dice.iter().filter(|die| **die == face).count() as u32
+}
+
+/// How the house plays: trusts its own dice, assumes a sixth of the hidden ones match,
+/// and calls anything more than one over that
+pub fn opponent_move(bid: Option<&Bid>, hand: &[u32], hidden: usize) -> LiarsMove {
+    let expected = |face| count(hand, face) as f32 + hidden as f32 / 6.0;
+    let plausible = |bid: &Bid| bid.quantity as f32 <= expected(bid.face) + 1.0;
+
+    let favourite = (1..=6)
+        .max_by_key(|face| (count(hand, *face), *face))
+        .unwrap();
+    match bid {
+        None => LiarsMove::Bid(Bid {
+            quantity: count(hand, favourite).max(1),
+            face: favourite,
+        }),
+        Some(bid) if !plausible(bid) => LiarsMove::CallLiar,
+        Some(bid) => {
+            let raise = bid.raises()[favourite as usize - 1];
+            if plausible(&raise) {
+                LiarsMove::Bid(raise)
+            } else {
+                LiarsMove::CallLiar
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{opponent_move, Bid, LiarsMove, DICE_PER_HAND};
+
+    #[test]
+    fn raising() {
+        let bid = Bid {
+            quantity: 2,
+            face: 4,
+        };
+        let raises = bid.raises();
+        assert_eq!(raises.len(), 6);
+        assert!(raises.iter().all(|raise| raise.quantity > bid.quantity
+            || (raise.quantity == bid.quantity && raise.face > bid.face)));
+        assert_eq!(
+            raises[4],
+            Bid {
+                quantity: 2,
+                face: 5
+            }
+        );
+        assert_eq!(
+            raises[0],
+            Bid {
+                quantity: 3,
+                face: 1
+            }
+        );
+    }
+
+    #[test]
+    fn holding() {
+        let table = [3, 3, 1, 6, 3, 2];
+        assert!(Bid {
+            quantity: 3,
+            face: 3
+        }
+        .holds(&table));
+        assert!(!Bid {
+            quantity: 2,
+            face: 6
+        }
+        .holds(&table));
+    }
+
+    #[test]
+    fn house_opens_with_its_best() {
+        let hand = [2, 5, 5, 1, 5];
+        assert_eq!(
+            opponent_move(None, &hand, DICE_PER_HAND),
+            LiarsMove::Bid(Bid {
+                quantity: 3,
+                face: 5
+            })
+        );
+    }
+
+    #[test]
+    fn house_calls_nonsense() {
+        let hand = [1, 2, 3, 4, 5];
+        let bid = Bid {
+            quantity: 6,
+            face: 6,
+        };
+        assert_eq!(
+            opponent_move(Some(&bid), &hand, DICE_PER_HAND),
+            LiarsMove::CallLiar
+        );
+    }
+
+    #[test]
+    fn house_raises_plausible_bids() {
+        let hand = [6, 6, 6, 2, 1];
+        let bid = Bid {
+            quantity: 1,
+            face: 4,
+        };
+        assert_eq!(
+            opponent_move(Some(&bid), &hand, DICE_PER_HAND),
+            LiarsMove::Bid(Bid {
+                quantity: 1,
+                face: 6
+            })
+        );
+    }
+}
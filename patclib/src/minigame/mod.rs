@@ -0,0 +1,315 @@
+use core::fmt;
+
+use bevy::prelude::*;
+
+use crate::{
+    dice_value::DiceValue,
+    flow::{AppState, FlowEvent},
+    locale::{t, tr},
+    payment::{start_payment, PaymentResult},
+    player::Player,
+    ui::UIHelper,
+};
+
+mod craps;
+mod liars_dice;
+mod revolver;
+use craps::{Craps, CrapsThrow};
+use liars_dice::{opponent_move, roll_hand, Bid, LiarsMove};
+use revolver::Revolver;
+
+/// How many times the stake a win pays out, stake included
+const CRAPS_PAYOUT: u32 = 2;
+const LIARS_DICE_PAYOUT: u32 = 2;
+const REVOLVER_PAYOUT: u32 = 3;
+/// The revolver is loaded with rock salt, it won't kill a healthy cowboy
+const REVOLVER_DAMAGE: i32 = 30;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Minigame {
+    Craps,
+    LiarsDice,
+    Revolver,
+}
+impl fmt::Display for Minigame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let id = match self {
+            Minigame::Craps => "minigame-craps",
+            Minigame::LiarsDice => "minigame-liars-dice",
+            Minigame::Revolver => "minigame-revolver",
+        };
+        write!(f, "{}", tr(id))
+    }
+}
+impl Minigame {
+    /// Message id
+    fn rules(&self) -> &'static str {
+        match self {
+            Minigame::Craps => "craps-rules",
+            Minigame::LiarsDice => "liars-rules",
+            Minigame::Revolver => "revolver-rules",
+        }
+    }
+
+    fn payout(&self) -> u32 {
+        match self {
+            Minigame::Craps => CRAPS_PAYOUT,
+            Minigame::LiarsDice => LIARS_DICE_PAYOUT,
+            Minigame::Revolver => REVOLVER_PAYOUT,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Table {
+    /// Waiting for the player to put up the stake
+    Stake,
+    Craps(Craps),
+    LiarsDice {
+        hand: Vec<u32>,
+        opponent_hand: Vec<u32>,
+        bid: Bid,
+    },
+    Revolver(Revolver),
+}
+
+#[derive(Debug)]
+pub struct OngoingMinigame {
+    game: Minigame,
+    stake: u32,
+    table: Table,
+}
+impl OngoingMinigame {
+    pub fn new(game: Minigame, stake: u32) -> Self {
+        Self {
+            game,
+            stake,
+            table: Table::Stake,
+        }
+    }
+}
+
+/// Left behind for the encounter once the player gets up from the table
+#[derive(Debug)]
+pub struct MinigameOver;
+
+pub struct MinigamePlugin;
+
+impl Plugin for MinigamePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_enter(AppState::Minigame).with_system(init_minigame))
+            .add_system_set(SystemSet::on_update(AppState::Minigame).with_system(play_minigame));
+    }
+}
+
+/// Clears the table
+pub(crate) fn reset(world: &mut World) {
+    world.remove_resource::<OngoingMinigame>();
+    world.remove_resource::<MinigameOver>();
+}
+
+fn init_minigame(mut ui_helper: ResMut<UIHelper>, minigame: Res<OngoingMinigame>) {
+    ui_helper.show_line(tr(minigame.game.rules()));
+    ui_helper.prompt(
+        t!("minigame-stake", stake = minigame.stake),
+        vec![t!("minigame-put-up"), t!("minigame-walk-away")],
+    );
+}
+
+fn play_minigame(
+    mut commands: Commands,
+    mut flow: EventWriter<FlowEvent>,
+    mut ui_helper: ResMut<UIHelper>,
+    mut player: ResMut<Player>,
+    mut minigame: ResMut<OngoingMinigame>,
+    payment_result: Option<Res<PaymentResult>>,
+) {
+    if let Some(result) = payment_result {
+        // Back from putting up the stake
+        commands.remove_resource::<PaymentResult>();
+        if result.paid {
+            minigame.table = deal(minigame.game, &mut ui_helper);
+        } else {
+            finish_minigame(&mut commands, &mut flow);
+        }
+        return;
+    }
+    let decision = if let Some(decision) = player.drain_decision() {
+        decision
+    } else {
+        return;
+    };
+    let game = minigame.game;
+    let stake = minigame.stake;
+
+    let won = match &mut minigame.table {
+        Table::Stake => {
+            if decision != 0 {
+                ui_helper.show_line(t!("minigame-walked-away"));
+                finish_minigame(&mut commands, &mut flow);
+                return;
+            }
+            let stake = DiceValue {
+                dice: 0,
+                constant: stake as i32,
+            };
+            if player.resources.money.theoretical_limit() < stake.theoretical_limit() {
+                ui_helper.show_line(t!("minigame-cannot-cover"));
+                finish_minigame(&mut commands, &mut flow);
+            } else {
+                // Let the player count out the stake, the cards are dealt once it's in
+                let purse = player.resources.money;
+                start_payment(&mut commands, &mut flow, &mut ui_helper, purse, stake);
+            }
+            return;
+        }
+        Table::Craps(craps) => {
+            let (first, second) = (roll_die(), roll_die());
+            ui_helper.show_line(t!("craps-throw", first = first, second = second));
+            match craps.throw(first, second) {
+                CrapsThrow::Win => Some(true),
+                CrapsThrow::Lose => Some(false),
+                CrapsThrow::Point(point) => {
+                    ui_helper.show_line(t!("craps-point", point = point));
+                    None
+                }
+                CrapsThrow::ThrowAgain => None,
+            }
+        }
+        Table::LiarsDice {
+            hand,
+            opponent_hand,
+            bid,
+        } => {
+            let table: Vec<u32> = hand.iter().chain(opponent_hand.iter()).cloned().collect();
+            if decision == 0 {
+                ui_helper.show_line(t!("liars-called", dice = show_dice(opponent_hand)));
+                Some(!bid.holds(&table))
+            } else {
+                let raise = bid.raises()[decision - 1];
+                ui_helper.show_line(t!("liars-you-bid", bid = raise.to_string()));
+                match opponent_move(Some(&raise), opponent_hand, hand.len()) {
+                    LiarsMove::CallLiar => {
+                        ui_helper
+                            .show_line(t!("liars-house-calls", dice = show_dice(opponent_hand)));
+                        Some(raise.holds(&table))
+                    }
+                    LiarsMove::Bid(counter) => {
+                        ui_helper.show_line(t!("liars-house-bids", bid = counter.to_string()));
+                        *bid = counter;
+                        None
+                    }
+                }
+            }
+        }
+        Table::Revolver(revolver) => {
+            if decision != 0 {
+                ui_helper.show_line(t!("revolver-folded"));
+                Some(false)
+            } else if revolver.pull() {
+                ui_helper.show_highlighted_line(t!("revolver-shot"));
+                player.resources.stamina = (player.resources.stamina - REVOLVER_DAMAGE).max(0);
+                Some(false)
+            } else {
+                ui_helper.show_line(t!("revolver-click"));
+                if revolver.opponent_folds() {
+                    ui_helper.show_line(t!("revolver-house-folds"));
+                    Some(true)
+                } else if revolver.pull() {
+                    ui_helper.show_line(t!("revolver-house-shot"));
+                    Some(true)
+                } else {
+                    ui_helper.show_line(t!("revolver-house-click"));
+                    None
+                }
+            }
+        }
+    };
+
+    match won {
+        Some(true) => {
+            let winnings = stake * game.payout();
+            player.resources.money += DiceValue {
+                dice: 0,
+                constant: winnings as i32,
+            };
+            ui_helper.show_line(t!(
+                "minigame-won",
+                winnings = winnings,
+                game = game.to_string()
+            ));
+            finish_minigame(&mut commands, &mut flow);
+        }
+        Some(false) => {
+            ui_helper.show_line(t!("minigame-lost", stake = stake));
+            if player.has_lost() {
+                flow.send(FlowEvent::PlayerDied);
+            } else {
+                finish_minigame(&mut commands, &mut flow);
+            }
+        }
+        None => prompt_for_move(&mut ui_helper, &minigame.table),
+    }
+}
+
+/// Sets up the table once the stake is in
+fn deal(game: Minigame, ui_helper: &mut ResMut<UIHelper>) -> Table {
+    let table = match game {
+        Minigame::Craps => Table::Craps(Craps::default()),
+        Minigame::LiarsDice => {
+            let hand = roll_hand();
+            let opponent_hand = roll_hand();
+            ui_helper.show_line(t!("liars-peek", dice = show_dice(&hand)));
+            let bid = match opponent_move(None, &opponent_hand, hand.len()) {
+                LiarsMove::Bid(bid) => bid,
+                LiarsMove::CallLiar => unreachable!("Nothing to call yet"),
+            };
+            ui_helper.show_line(t!("liars-house-opens", bid = bid.to_string()));
+            Table::LiarsDice {
+                hand,
+                opponent_hand,
+                bid,
+            }
+        }
+        Minigame::Revolver => Table::Revolver(Revolver::spun()),
+    };
+    prompt_for_move(ui_helper, &table);
+    table
+}
+
+fn finish_minigame(commands: &mut Commands, flow: &mut EventWriter<FlowEvent>) {
+    commands.remove_resource::<OngoingMinigame>();
+    commands.insert_resource(MinigameOver);
+    flow.send(FlowEvent::MinigameFinished);
+}
+
+fn roll_die() -> u32 {
+    DiceValue::from("1d6").roll()
+}
+
+fn show_dice(dice: &[u32]) -> String {
+    dice.iter()
+        .map(|die| die.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn prompt_for_move(ui_helper: &mut ResMut<UIHelper>, table: &Table) {
+    match table {
+        Table::Stake => {}
+        Table::Craps(_) => ui_helper.prompt(t!("craps-prompt"), vec![t!("craps-throw-option")]),
+        Table::LiarsDice { hand, bid, .. } => {
+            let mut options = vec![t!("liars-call")];
+            options.extend(
+                bid.raises()
+                    .iter()
+                    .map(|raise| t!("liars-raise", bid = raise.to_string())),
+            );
+            ui_helper.prompt(t!("liars-prompt", dice = show_dice(hand)), options);
+        }
+        Table::Revolver(revolver) => ui_helper.prompt(
+            t!("revolver-prompt", chambers = revolver.chambers_left()),
+            vec![t!("revolver-pull"), t!("revolver-fold")],
+        ),
+    }
+}
@@ -0,0 +1,61 @@
+use rand::{thread_rng, Rng};
+
+pub const CHAMBERS: u32 = 6;
+
+/// One bullet, six chambers, no spinning between pulls
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Revolver {
+    bullet: u32,
+    fired: u32,
+}
+impl Revolver {
+    pub fn spun() -> Self {
+        Self::loaded_at(thread_rng().gen_range(0..CHAMBERS))
+    }
+
+    fn loaded_at(bullet: u32) -> Self {
+        Self { bullet, fired: 0 }
+    }
+
+    /// Returns true if that was the loaded chamber
+    pub fn pull(&mut self) -> bool {
+        let bang = self.fired == self.bullet;
+        self.fired += 1;
+        bang
+    }
+
+    pub fn chambers_left(&self) -> u32 {
+        CHAMBERS - self.fired
+    }
+
+    /// The house loses its nerve once it's a coin flip or worse
+    pub fn opponent_folds(&self) -> bool {
+        self.chambers_left() <= 2
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Revolver, CHAMBERS};
+
+    #[test]
+    fn fires_once() {
+        for bullet in 0..CHAMBERS {
+            let mut revolver = Revolver::loaded_at(bullet);
+            let bangs = (0..CHAMBERS).filter(|_| revolver.pull()).count();
+            assert_eq!(bangs, 1);
+            assert_eq!(revolver.chambers_left(), 0);
+        }
+    }
+
+    #[test]
+    fn nerve() {
+        let mut revolver = Revolver::loaded_at(5);
+        for _ in 0..3 {
+            revolver.pull();
+        }
+        assert!(!revolver.opponent_folds());
+        revolver.pull();
+        assert!(revolver.opponent_folds());
+    }
+}
@@ -0,0 +1,290 @@
+use rand::{thread_rng, Rng};
+
+use crate::{dice_value::DiceValue, locale::t};
+
+/// One thing handed over towards a bill
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Spend {
+    Cash(u32),
+    Die(u32),
+}
+
+/// Paying a rolled price out of a purse of dice.
+///
+/// The rules:
+/// - Cash (the purse's constant) is spent point for point, so it never overpays
+/// - A die is rolled when it's spent and goes away in full, whatever it shows
+/// - If a die overpays a voluntary bill, the difference comes back as cash change
+/// - Forced losses (robberies, surrenders) give no change
+/// - Nothing leaves the purse until the bill is settled or forfeited
+/// - Walking away from a bill hands the cash back, but dice already rolled are gone
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Bill {
+    pub price: u32,
+    purse: DiceValue,
+    owed: u32,
+    change: u32,
+    spent: Vec<Spend>,
+}
+impl Bill {
+    pub fn new(purse: DiceValue, price: u32) -> Self {
+        Self {
+            price,
+            purse,
+            owed: price,
+            change: 0,
+            spent: vec![],
+        }
+    }
+
+    /// Pays whatever is owed out of the purse without asking, never giving change
+    pub fn forced(purse: DiceValue, price: u32) -> Self {
+        Self::forced_with(purse, price, &mut thread_rng())
+    }
+
+    fn forced_with(purse: DiceValue, price: u32, rng: &mut impl Rng) -> Self {
+        let mut bill = Self::new(purse, price);
+        bill.pay_in_full_with(rng);
+        bill.change = 0;
+        bill
+    }
+
+    /// Cash first, then dice until it's covered or the purse is empty
+    pub fn pay_in_full(&mut self) {
+        self.pay_in_full_with(&mut thread_rng());
+    }
+
+    fn pay_in_full_with(&mut self, rng: &mut impl Rng) {
+        self.pay_cash();
+        while !self.is_paid() && self.dice_left() > 0 {
+            self.pay_die(rng.gen_range(1..=6));
+        }
+    }
+
+    pub fn cash_left(&self) -> u32 {
+        self.purse.constant.max(0) as u32
+    }
+
+    pub fn dice_left(&self) -> u32 {
+        self.purse.dice
+    }
+
+    pub fn owed(&self) -> u32 {
+        self.owed
+    }
+
+    pub fn is_paid(&self) -> bool {
+        self.owed == 0
+    }
+
+    pub fn can_pay_more(&self) -> bool {
+        !self.is_paid() && (self.cash_left() > 0 || self.dice_left() > 0)
+    }
+
+    /// Hands over as much cash as is owed, returns how much that was
+    pub fn pay_cash(&mut self) -> u32 {
+        let amount = self.cash_left().min(self.owed);
+        if amount > 0 {
+            self.purse.constant -= amount as i32;
+            self.owed -= amount;
+            self.spent.push(Spend::Cash(amount));
+        }
+        amount
+    }
+
+    /// Rolls one die from the purse, returns what it showed
+    pub fn roll_die(&mut self) -> Option<u32> {
+        if self.dice_left() == 0 || self.is_paid() {
+            return None;
+        }
+        let roll = thread_rng().gen_range(1..=6);
+        self.pay_die(roll);
+        Some(roll)
+    }
+
+    fn pay_die(&mut self, roll: u32) {
+        self.purse.dice -= 1;
+        self.spent.push(Spend::Die(roll));
+        if roll >= self.owed {
+            self.change = roll - self.owed;
+            self.owed = 0;
+        } else {
+            self.owed -= roll;
+        }
+    }
+
+    /// What's left in the purse once the bill is done with, change included
+    pub fn settle(&self) -> DiceValue {
+        DiceValue {
+            dice: self.purse.dice,
+            constant: self.purse.constant + self.change as i32,
+        }
+    }
+
+    /// What's left in the purse if the bill is walked away from unpaid
+    pub fn forfeit(&self) -> DiceValue {
+        DiceValue {
+            dice: self.purse.dice,
+            constant: self.purse.constant + self.cash_spent() as i32,
+        }
+    }
+
+    fn cash_spent(&self) -> u32 {
+        self.spent
+            .iter()
+            .map(|spend| match spend {
+                Spend::Cash(amount) => *amount,
+                Spend::Die(_) => 0,
+            })
+            .sum()
+    }
+
+    /// How many dice have been rolled towards the bill so far
+    pub fn dice_rolled(&self) -> u32 {
+        self.spent
+            .iter()
+            .filter(|spend| matches!(spend, Spend::Die(_)))
+            .count() as u32
+    }
+
+    /// A human readable account of what was spent
+    pub fn receipt(&self) -> String {
+        let mut bits: Vec<String> = self
+            .spent
+            .iter()
+            .map(|spend| match spend {
+                Spend::Cash(amount) => t!("receipt-cash", amount = *amount),
+                Spend::Die(roll) => t!("receipt-die", roll = *roll),
+            })
+            .collect();
+        if bits.is_empty() {
+            bits.push(t!("receipt-nothing"));
+        }
+        let paid = self.price - self.owed;
+        let spent = bits.join(", ");
+        if self.change > 0 {
+            t!(
+                "receipt-with-change",
+                paid = paid,
+                price = self.price,
+                spent = spent,
+                change = self.change
+            )
+        } else {
+            t!(
+                "receipt-paid",
+                paid = paid,
+                price = self.price,
+                spent = spent
+            )
+        }
+    }
+}
+
+/// What a forced loss actually took
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Loss {
+    pub money: Bill,
+    pub batteries: Bill,
+}
+impl Loss {
+    /// Whether the dice covered everything that was asked
+    pub fn covered(&self) -> bool {
+        self.money.is_paid() && self.batteries.is_paid()
+    }
+
+    /// One line per kind of dice that was taken
+    pub fn lines(&self) -> Vec<String> {
+        [
+            ("loss-money", "loss-all-money", &self.money),
+            ("loss-batteries", "loss-all-batteries", &self.batteries),
+        ]
+        .into_iter()
+        .filter(|(_, _, bill)| bill.price > 0)
+        .map(|(paid, all, bill)| {
+            let id = if bill.is_paid() { paid } else { all };
+            t!(id, receipt = bill.receipt())
+        })
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{Bill, Spend};
+    use crate::dice_value::DiceValue;
+
+    #[test]
+    fn cash_never_overpays() {
+        let mut bill = Bill::new("3d6+10".into(), 4);
+        assert_eq!(bill.pay_cash(), 4);
+        assert!(bill.is_paid());
+        assert_eq!(bill.settle(), "3d6+6".into());
+    }
+
+    #[test]
+    fn dice_overpay_with_change() {
+        let mut bill = Bill::new("2d6+1".into(), 4);
+        bill.pay_cash();
+        bill.pay_die(5);
+        assert!(bill.is_paid());
+        assert_eq!(bill.change, 2);
+        assert_eq!(bill.settle(), "1d6+2".into());
+        assert_eq!(bill.spent, vec![Spend::Cash(1), Spend::Die(5)]);
+    }
+
+    #[test]
+    fn backing_out_keeps_cash_but_not_rolled_dice() {
+        let mut bill = Bill::new("2d6+1".into(), 20);
+        assert_eq!(bill.forfeit(), "2d6+1".into());
+        bill.pay_cash();
+        bill.pay_die(6);
+        assert!(!bill.is_paid());
+        assert_eq!(bill.owed(), 13);
+        assert_eq!(bill.dice_rolled(), 1);
+        assert_eq!(bill.forfeit(), "1d6+1".into());
+    }
+
+    #[test]
+    fn running_out() {
+        let mut bill = Bill::new("1d6".into(), 10);
+        bill.pay_die(3);
+        assert!(!bill.can_pay_more());
+        assert_eq!(bill.roll_die(), None);
+        // Coming up short doesn't give the die back either
+        assert_eq!(bill.forfeit(), DiceValue::default());
+    }
+
+    #[test]
+    fn forced_gives_no_change() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let bill = Bill::forced_with("5d6+2".into(), 3, &mut rng);
+            assert!(bill.is_paid());
+            assert_eq!(bill.change, 0);
+            assert!(bill.settle().dice < 5);
+            assert_eq!(bill.settle().constant, 0);
+        }
+    }
+
+    #[test]
+    fn forced_can_empty_the_purse() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let bill = Bill::forced_with("1d6+2".into(), 100, &mut rng);
+        assert!(!bill.is_paid());
+        assert_eq!(bill.settle(), DiceValue::default());
+    }
+
+    #[test]
+    fn receipt() {
+        let mut bill = Bill::new("2d6+1".into(), 4);
+        bill.pay_cash();
+        bill.pay_die(5);
+        assert_eq!(
+            bill.receipt(),
+            "4 of 4 paid with 1 in cash, a rolled 5, 2 back in change"
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use core::fmt;
+
+use bevy::prelude::*;
+
+use crate::{
+    dice_value::DiceValue,
+    flow::{AppState, FlowEvent},
+    locale::{t, tr},
+    player::Player,
+    ui::UIHelper,
+};
+
+mod bill;
+pub use bill::{Bill, Loss};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+enum PayAction {
+    Cash,
+    Die,
+    BackOut,
+}
+impl fmt::Display for PayAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let id = match self {
+            PayAction::Cash => "pay-cash",
+            PayAction::Die => "pay-die",
+            PayAction::BackOut => "pay-back-out",
+        };
+        write!(f, "{}", tr(id))
+    }
+}
+
+#[derive(Debug)]
+struct OngoingPayment(Bill);
+
+/// Left behind for whoever asked for the money once the player is done paying
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PaymentResult {
+    pub paid: bool,
+}
+
+pub struct PaymentPlugin;
+
+impl Plugin for PaymentPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_enter(AppState::Payment).with_system(init_payment))
+            .add_system_set(SystemSet::on_update(AppState::Payment).with_system(update_payment));
+    }
+}
+
+/// Tears up any bill being paid
+pub(crate) fn reset(world: &mut World) {
+    world.remove_resource::<OngoingPayment>();
+    world.remove_resource::<PaymentResult>();
+}
+
+/// Rolls the price and lets the player pay it die by die, or walk away
+pub fn start_payment(
+    commands: &mut Commands,
+    flow: &mut EventWriter<FlowEvent>,
+    ui_helper: &mut ResMut<UIHelper>,
+    purse: DiceValue,
+    price: DiceValue,
+) {
+    let rolled = price.roll();
+    if price.dice > 0 {
+        ui_helper.show_line(t!(
+            "pay-price-rolled",
+            price = rolled,
+            dice = price.as_string()
+        ));
+    } else {
+        ui_helper.show_line(t!("pay-price", price = rolled));
+    }
+    commands.insert_resource(OngoingPayment(Bill::new(purse, rolled)));
+    flow.send(FlowEvent::PaymentStarted);
+}
+
+fn init_payment(mut ui_helper: ResMut<UIHelper>, payment: Res<OngoingPayment>) {
+    prompt_for_payment(&mut ui_helper, &payment);
+}
+
+fn update_payment(
+    mut commands: Commands,
+    mut flow: EventWriter<FlowEvent>,
+    mut ui_helper: ResMut<UIHelper>,
+    mut player: ResMut<Player>,
+    mut payment: ResMut<OngoingPayment>,
+) {
+    if let Some(decision) = player.drain_decision() {
+        match get_pay_actions(&payment)[decision] {
+            PayAction::Cash => {
+                let amount = payment.0.pay_cash();
+                ui_helper.show_line(t!("pay-handed-over", amount = amount));
+            }
+            PayAction::Die => {
+                let roll = payment.0.roll_die().unwrap();
+                ui_helper.show_line(t!("pay-rolled", roll = roll));
+            }
+            PayAction::BackOut => {
+                ui_helper.show_line(t!("pay-backed-out"));
+                walk_away(&mut ui_helper, &mut player, &payment.0);
+                finish_payment(&mut commands, &mut flow, false);
+                return;
+            }
+        }
+
+        if payment.0.is_paid() {
+            // Only now does anything actually leave the purse
+            player.resources.money = payment.0.settle();
+            ui_helper.show_line(payment.0.receipt());
+            finish_payment(&mut commands, &mut flow, true);
+        } else if !payment.0.can_pay_more() {
+            ui_helper.show_line(t!("pay-short", owed = payment.0.owed()));
+            walk_away(&mut ui_helper, &mut player, &payment.0);
+            finish_payment(&mut commands, &mut flow, false);
+        } else {
+            prompt_for_payment(&mut ui_helper, &payment);
+        }
+    }
+}
+
+/// The cash goes back in the purse, whatever dice were rolled stay on the table
+fn walk_away(ui_helper: &mut UIHelper, player: &mut Player, bill: &Bill) {
+    player.resources.money = bill.forfeit();
+    if bill.dice_rolled() > 0 {
+        ui_helper.show_line(t!("pay-forfeit", dice = bill.dice_rolled()));
+    }
+}
+
+fn finish_payment(commands: &mut Commands, flow: &mut EventWriter<FlowEvent>, paid: bool) {
+    commands.remove_resource::<OngoingPayment>();
+    commands.insert_resource(PaymentResult { paid });
+    flow.send(FlowEvent::PaymentFinished);
+}
+
+fn get_pay_actions(payment: &OngoingPayment) -> Vec<PayAction> {
+    let mut actions = vec![];
+    if payment.0.cash_left() > 0 {
+        actions.push(PayAction::Cash);
+    }
+    if payment.0.dice_left() > 0 {
+        actions.push(PayAction::Die);
+    }
+    actions.push(PayAction::BackOut);
+    actions
+}
+
+fn prompt_for_payment(ui_helper: &mut ResMut<UIHelper>, payment: &OngoingPayment) {
+    ui_helper.prompt(
+        t!(
+            "pay-prompt",
+            owed = payment.0.owed(),
+            cash = payment.0.cash_left(),
+            dice = payment.0.dice_left()
+        ),
+        get_pay_actions(payment)
+            .iter()
+            .map(|action| action.to_string())
+            .collect(),
+    );
+}
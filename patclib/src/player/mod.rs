@@ -3,8 +3,14 @@ use core::fmt;
 use bevy::prelude::*;
 
 use crate::{
-    battle::{Weapon, BATTLE_ARENA_WIDTH},
+    battle::{Battle, Weapon, BATTLE_ARENA_WIDTH},
+    companion::{Dog, DogCommand},
     dice_value::DiceValue,
+    inventory::{bandages, Inventory, Item},
+    locale::{t, tr},
+    loot::Loot,
+    payment::{Bill, Loss},
+    travel::{Condition, MAX_STAMINA},
 };
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
@@ -12,14 +18,25 @@ pub enum BattleAction {
     Move,
     Attack,
     Wait,
+    Flee,
+    UseItem,
+    Command,
+    Parley,
+    Surrender,
 }
 impl fmt::Display for BattleAction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            BattleAction::Move => write!(f, "move"),
-            BattleAction::Attack => write!(f, "attack"),
-            BattleAction::Wait => write!(f, "wait"),
-        }
+        let id = match self {
+            BattleAction::Move => "action-move",
+            BattleAction::Attack => "action-attack",
+            BattleAction::Wait => "action-wait",
+            BattleAction::UseItem => "action-use-item",
+            BattleAction::Command => "action-command",
+            BattleAction::Flee => "action-flee",
+            BattleAction::Parley => "action-parley",
+            BattleAction::Surrender => "action-surrender",
+        };
+        write!(f, "{}", tr(id))
     }
 }
 
@@ -28,47 +45,52 @@ impl fmt::Display for BattleAction {
 pub struct Player {
     pub resources: PlayerResources,
     pub decision: Option<usize>,
-    pub weapons: Vec<Weapon>,
+    pub inventory: Inventory,
     pub selected_action: Option<BattleAction>,
     pub selected_weapon: Option<Weapon>,
     pub position: u32,
+    pub dog: Dog,
+    pub condition: Condition,
 }
 
 impl Player {
-    fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             resources: PlayerResources {
-                stamina: 100,
+                stamina: MAX_STAMINA,
                 money: "5d6".into(),
                 batteries: "5d6".into(),
                 bullets: 15,
             },
-            weapons: vec![
-                Weapon {
-                    name: "Trusty sidearm",
-                    damage: "1d6".into(),
-                    range: 5,
-                    cost: Some(PlayerResources {
-                        bullets: 1,
-                        ..default()
-                    }),
-                },
-                Weapon {
-                    name: "Still somewhat trusty taser",
-                    damage: "1d6".into(),
-                    range: 2,
-                    cost: Some(PlayerResources {
-                        batteries: "4".into(),
-                        ..default()
-                    }),
-                },
-                Weapon {
-                    name: "Knuckle sandwich",
-                    damage: "1".into(),
-                    range: 1,
-                    cost: None,
-                },
-            ],
+            inventory: Inventory {
+                weapons: vec![
+                    Weapon {
+                        name: "weapon-trusty-sidearm",
+                        damage: "1d6".into(),
+                        range: 5,
+                        cost: Some(PlayerResources {
+                            bullets: 1,
+                            ..default()
+                        }),
+                    },
+                    Weapon {
+                        name: "weapon-trusty-taser",
+                        damage: "1d6".into(),
+                        range: 2,
+                        cost: Some(PlayerResources {
+                            batteries: "4".into(),
+                            ..default()
+                        }),
+                    },
+                    Weapon {
+                        name: "weapon-knuckle-sandwich",
+                        damage: "1".into(),
+                        range: 1,
+                        cost: None,
+                    },
+                ],
+                items: vec![bandages()],
+            },
             ..default()
         }
     }
@@ -76,17 +98,41 @@ impl Player {
         self.resources.stamina == 0
     }
 
+    /// The run is over if either one of you doesn't make it
+    pub fn has_lost(&self) -> bool {
+        self.is_dead() || self.dog.is_dead()
+    }
+
     pub fn choose(&mut self, index: usize) {
         self.decision = Some(index);
     }
 
-    pub fn get_battle_actions(&self) -> Vec<BattleAction> {
+    pub fn get_battle_actions(&self, battle: &Battle) -> Vec<BattleAction> {
         // A function so if we want to later on add statuses that prevent moving or something, it's easier.
-        vec![BattleAction::Attack, BattleAction::Move, BattleAction::Wait]
+        let mut actions = vec![
+            BattleAction::Attack,
+            BattleAction::Move,
+            BattleAction::Wait,
+            BattleAction::Flee,
+        ];
+        if !self.inventory.items.is_empty() {
+            actions.insert(2, BattleAction::UseItem);
+        }
+        if !self.dog.is_dead() {
+            actions.insert(actions.len() - 1, BattleAction::Command);
+        }
+        if battle.can_parley() {
+            actions.push(BattleAction::Parley);
+        }
+        if battle.can_surrender() {
+            actions.push(BattleAction::Surrender);
+        }
+        actions
     }
 
     pub fn get_weapons(&self) -> Vec<Weapon> {
-        self.weapons
+        self.inventory
+            .weapons
             .iter()
             .filter(|weapon| {
                 weapon.cost.is_none() || self.resources.could_afford(&weapon.cost.unwrap())
@@ -95,19 +141,28 @@ impl Player {
             .collect()
     }
 
+    /// Message ids of where the player can go, along with the lane
     pub fn get_movable_locations(&self) -> Vec<(&'static str, u32)> {
         let mut collector = vec![];
 
         if self.position > 0 {
-            collector.push(("left", self.position - 1));
+            collector.push(("place-left", self.position - 1));
         }
 
         if self.position < BATTLE_ARENA_WIDTH - 1 {
-            collector.push(("right", self.position + 1));
+            collector.push(("place-right", self.position + 1));
         }
         collector
     }
 
+    pub fn get_dog_commands(&self) -> Vec<DogCommand> {
+        vec![DogCommand::Bark, DogCommand::Fetch]
+    }
+
+    pub fn get_items(&self) -> Vec<Item> {
+        self.inventory.items.clone()
+    }
+
     pub fn clear_selections(&mut self) {
         self.selected_action = None;
         self.selected_weapon = None;
@@ -116,6 +171,31 @@ impl Player {
     pub fn drain_decision(&mut self) -> Option<usize> {
         self.decision.take()
     }
+
+    /// Adds resources, stamina can't go past what the player's condition allows
+    pub fn gain(&mut self, resources: PlayerResources) {
+        self.resources.add(resources);
+        self.resources.stamina = self.resources.stamina.min(self.condition.stamina_cap());
+    }
+
+    pub fn can_receive(&self, loot: &Loot) -> bool {
+        match loot {
+            Loot::Resources(_) => true,
+            Loot::Weapon(_) | Loot::Item(_) => self.inventory.has_room(),
+        }
+    }
+
+    /// Returns false if there was no room for it
+    pub fn receive(&mut self, loot: Loot) -> bool {
+        match loot {
+            Loot::Resources(resources) => {
+                self.gain(resources);
+                true
+            }
+            Loot::Weapon(weapon) => self.inventory.add_weapon(weapon),
+            Loot::Item(item) => self.inventory.add_item(item),
+        }
+    }
 }
 
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
@@ -125,6 +205,27 @@ pub struct PlayerResources {
     pub bullets: i32,
     pub batteries: DiceValue,
 }
+impl fmt::Display for PlayerResources {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut bits = vec![];
+        if self.stamina != 0 {
+            bits.push(t!("resources-stamina", amount = self.stamina));
+        }
+        if self.money != DiceValue::default() {
+            bits.push(t!("resources-money", amount = self.money.as_string()));
+        }
+        if self.bullets != 0 {
+            bits.push(t!("resources-bullets", amount = self.bullets));
+        }
+        if self.batteries != DiceValue::default() {
+            bits.push(t!(
+                "resources-batteries",
+                amount = self.batteries.as_string()
+            ));
+        }
+        write!(f, "{}", bits.join(", "))
+    }
+}
 impl PlayerResources {
     pub fn add(&mut self, other: PlayerResources) {
         self.stamina += other.stamina;
@@ -139,43 +240,59 @@ impl PlayerResources {
             && self.money.theoretical_limit() >= other.money.theoretical_limit()
             && self.batteries.theoretical_limit() >= other.batteries.theoretical_limit()
     }
+    /// Pays for something in full or not at all, see Bill for the rules
     pub fn remove(&mut self, other: PlayerResources) -> bool {
-        let can_afford = self.could_afford(&other);
-        if can_afford {
-            if let (Some(new_money), Some(new_batteries)) = (
-                self.money.drained_to_match(other.money.roll()),
-                self.batteries.drained_to_match(other.batteries.roll()),
-            ) {
-                self.money = new_money;
-                self.batteries = new_batteries;
-                self.stamina -= other.stamina;
-                self.bullets -= other.bullets;
-            }
+        if !self.could_afford(&other) {
+            return false;
         }
+        let mut money = Bill::new(self.money, other.money.roll());
+        money.pay_in_full();
+        let mut batteries = Bill::new(self.batteries, other.batteries.roll());
+        batteries.pay_in_full();
 
-        can_afford
+        let paid = money.is_paid() && batteries.is_paid();
+        if paid {
+            self.money = money.settle();
+            self.batteries = batteries.settle();
+            self.stamina -= other.stamina;
+            self.bullets -= other.bullets;
+        }
+        paid
     }
 
-    pub fn force_remove(&mut self, other: PlayerResources) -> bool {
-        let can_afford = self.could_afford(&other);
-
-        let new_money = self.money.drained_to_match(other.money.roll());
-        let new_batteries = self.batteries.drained_to_match(other.batteries.roll());
-
-        self.money = new_money.unwrap_or_default();
-        self.batteries = new_batteries.unwrap_or_default();
-        self.stamina = if self.stamina >= other.stamina {
-            self.stamina - other.stamina
-        } else {
-            0
-        };
-        self.bullets = if self.bullets >= other.bullets {
-            self.bullets - other.bullets
-        } else {
-            0
+    /// Takes everything but the money, which is counted out in a payment of its own.
+    /// Returns what was taken, to be handed back with `add` if that payment falls through
+    pub fn remove_all_but_money(&mut self, other: PlayerResources) -> Option<PlayerResources> {
+        let before = *self;
+        let rest = PlayerResources {
+            money: default(),
+            ..other
         };
+        if !self.remove(rest) {
+            return None;
+        }
+        Some(PlayerResources {
+            stamina: before.stamina - self.stamina,
+            money: default(),
+            bullets: before.bullets - self.bullets,
+            batteries: DiceValue {
+                dice: before.batteries.dice - self.batteries.dice,
+                constant: before.batteries.constant - self.batteries.constant,
+            },
+        })
+    }
 
-        can_afford && new_money.is_some() && new_batteries.is_some()
+    /// Takes as much as there is, reporting what was taken
+    pub fn force_remove(&mut self, other: PlayerResources) -> Loss {
+        let loss = Loss {
+            money: Bill::forced(self.money, other.money.roll()),
+            batteries: Bill::forced(self.batteries, other.batteries.roll()),
+        };
+        self.money = loss.money.settle();
+        self.batteries = loss.batteries.settle();
+        self.stamina = (self.stamina - other.stamina).max(0);
+        self.bullets = (self.bullets - other.bullets).max(0);
+        loss
     }
 }
 
@@ -190,3 +307,31 @@ impl Plugin for PlayerPlugin {
 fn init(mut commands: Commands) {
     commands.insert_resource(Player::new());
 }
+
+#[cfg(test)]
+mod test {
+    use super::PlayerResources;
+
+    #[test]
+    fn held_terms_go_back_as_they_were() {
+        let before = PlayerResources {
+            stamina: 20,
+            money: "2d6".into(),
+            bullets: 5,
+            batteries: "2d6+10".into(),
+        };
+        let mut resources = before;
+        let held = resources
+            .remove_all_but_money(PlayerResources {
+                stamina: 4,
+                money: "1d6".into(),
+                bullets: 2,
+                batteries: "1d6".into(),
+            })
+            .unwrap();
+        assert_eq!(resources.money, before.money);
+        assert_eq!(resources.bullets, 3);
+        resources.add(held);
+        assert_eq!(resources, before);
+    }
+}
@@ -0,0 +1,392 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::{
+    dice_value::DiceValue,
+    flow::{AppState, FlowEvent},
+    locale::{t, tr},
+    loot::Loot,
+    payment::{start_payment, PaymentResult},
+    player::{Player, PlayerResources},
+    travel::{Chapter, Journey},
+    ui::UIHelper,
+};
+
+mod shops;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ShopId {
+    RoadMerchant,
+    GeneralStore,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ShopEntry {
+    /// Message id
+    pub name: &'static str,
+    pub loot: Loot,
+    /// Before markups and discounts
+    pub price: DiceValue,
+    /// None for an endless supply
+    pub stock: Option<u32>,
+}
+impl ShopEntry {
+    fn in_stock(&self) -> bool {
+        self.stock != Some(0)
+    }
+}
+
+const CHAPTER_MARKUP: i32 = 10;
+const REPUTATION_DISCOUNT: i32 = 2;
+const MIN_PRICE_PERCENT: i32 = 50;
+const MAX_REPUTATION: i32 = 10;
+/// What shopkeepers pay for things they don't stock themselves
+const DEFAULT_OFFER: u32 = 3;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Shop {
+    /// Message id
+    pub greeting: &'static str,
+    /// Percentage of the base price this shopkeeper charges
+    pub markup: u32,
+    /// How much the shopkeeper likes the player, good customers get discounts
+    pub reputation: i32,
+    pub entries: Vec<ShopEntry>,
+}
+impl Shop {
+    /// Prices go up the further out you are, and down the more the shopkeeper likes you
+    fn price_percent(&self, chapter: Chapter) -> u32 {
+        let chapter_markup = match chapter {
+            Chapter::Departure => 0,
+            Chapter::Travel => CHAPTER_MARKUP,
+            Chapter::Arrival => 2 * CHAPTER_MARKUP,
+        };
+        (self.markup as i32 + chapter_markup - self.reputation * REPUTATION_DISCOUNT)
+            .max(MIN_PRICE_PERCENT) as u32
+    }
+
+    pub fn price_of(&self, entry: &ShopEntry, chapter: Chapter) -> DiceValue {
+        entry.price.scaled(self.price_percent(chapter))
+    }
+
+    /// What the shopkeeper is willing to pay for something, half of what it's worth to them
+    pub fn offer_for(&self, loot: &Loot) -> u32 {
+        self.entries
+            .iter()
+            .find(|entry| &entry.loot == loot)
+            .map_or(DEFAULT_OFFER, |entry| (entry.price.average() / 2).max(1))
+    }
+
+    fn sell(&mut self, index: usize) -> Loot {
+        let entry = &mut self.entries[index];
+        if let Some(stock) = entry.stock.as_mut() {
+            *stock -= 1;
+        }
+        self.reputation = (self.reputation + 1).min(MAX_REPUTATION);
+        entry.loot.clone()
+    }
+
+    /// Sold things go on the shelf, so the player can buy them back
+    fn buy(&mut self, name: &'static str, loot: Loot, paid: u32) {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.loot == loot) {
+            if let Some(stock) = entry.stock.as_mut() {
+                *stock += 1;
+            }
+        } else {
+            self.entries.push(ShopEntry {
+                name,
+                loot,
+                price: DiceValue {
+                    dice: 0,
+                    constant: 2 * paid as i32,
+                },
+                stock: Some(1),
+            });
+        }
+    }
+}
+
+/// Every shop in the world, so stock and reputation carry over between visits
+#[derive(Debug, Deref, DerefMut)]
+pub struct Shops(HashMap<ShopId, Shop>);
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum ShopMenu {
+    Main,
+    Buy,
+    Sell,
+}
+
+#[derive(Debug)]
+pub struct OngoingShop {
+    pub id: ShopId,
+    menu: ShopMenu,
+    /// What the player is paying for right now
+    purchase: Option<usize>,
+}
+impl OngoingShop {
+    pub fn new(id: ShopId) -> Self {
+        Self {
+            id,
+            menu: ShopMenu::Main,
+            purchase: None,
+        }
+    }
+}
+
+/// Left behind for the encounter once the player walks out
+#[derive(Debug)]
+pub struct ShopClosed;
+
+pub struct ShopPlugin;
+
+impl Plugin for ShopPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Shops(shops::shops()))
+            .add_system_set(SystemSet::on_enter(AppState::Shop).with_system(open_shop))
+            .add_system_set(SystemSet::on_update(AppState::Shop).with_system(update_shop));
+    }
+}
+
+/// Closes the shop going on, if any, and makes the shopkeepers forget the player
+pub(crate) fn reset(world: &mut World) {
+    world.remove_resource::<OngoingShop>();
+    world.remove_resource::<ShopClosed>();
+    world.insert_resource(Shops(shops::shops()));
+}
+
+fn open_shop(mut ui_helper: ResMut<UIHelper>, shops: Res<Shops>, ongoing: Res<OngoingShop>) {
+    ui_helper.show_line(tr(shops[&ongoing.id].greeting));
+    prompt_for_menu(&mut ui_helper);
+}
+
+fn update_shop(
+    mut commands: Commands,
+    mut flow: EventWriter<FlowEvent>,
+    mut ui_helper: ResMut<UIHelper>,
+    mut player: ResMut<Player>,
+    mut shops: ResMut<Shops>,
+    mut ongoing: ResMut<OngoingShop>,
+    (journey, payment_result): (Res<Journey>, Option<Res<PaymentResult>>),
+) {
+    let shop = shops.get_mut(&ongoing.id).unwrap();
+    let chapter = journey.chapter();
+
+    if let Some(result) = payment_result {
+        commands.remove_resource::<PaymentResult>();
+        let index = ongoing.purchase.take().unwrap();
+        if result.paid {
+            let name = shop.entries[index].name;
+            player.receive(shop.sell(index));
+            ui_helper.show_line(t!("shop-bought", thing = tr(name)));
+        } else {
+            ui_helper.show_line(t!("shop-unpaid"));
+        }
+        prompt_for_purchase(&mut ui_helper, shop, &player, chapter);
+        return;
+    }
+
+    let decision = if let Some(decision) = player.drain_decision() {
+        decision
+    } else {
+        return;
+    };
+
+    match ongoing.menu {
+        ShopMenu::Main => match decision {
+            0 => {
+                ongoing.menu = ShopMenu::Buy;
+                prompt_for_purchase(&mut ui_helper, shop, &player, chapter);
+            }
+            1 => {
+                ongoing.menu = ShopMenu::Sell;
+                prompt_for_sale(&mut ui_helper, shop, &player);
+            }
+            _ => {
+                commands.remove_resource::<OngoingShop>();
+                commands.insert_resource(ShopClosed);
+                flow.send(FlowEvent::ShopLeft);
+            }
+        },
+        ShopMenu::Buy => {
+            if let Some(entry) = shop.entries.get(decision) {
+                let price = shop.price_of(entry, chapter);
+                let cost = PlayerResources {
+                    money: price,
+                    ..default()
+                };
+                if !entry.in_stock() {
+                    ui_helper.show_line(t!("shop-sold-out"));
+                } else if !player.can_receive(&entry.loot) {
+                    ui_helper.show_line(t!("shop-pack-full"));
+                } else if !player.resources.could_afford(&cost) {
+                    ui_helper.show_line(t!("shop-too-dear"));
+                } else {
+                    ongoing.purchase = Some(decision);
+                    let purse = player.resources.money;
+                    start_payment(&mut commands, &mut flow, &mut ui_helper, purse, price);
+                    return;
+                }
+                prompt_for_purchase(&mut ui_helper, shop, &player, chapter);
+            } else {
+                ongoing.menu = ShopMenu::Main;
+                prompt_for_menu(&mut ui_helper);
+            }
+        }
+        ShopMenu::Sell => {
+            if let Some((name, loot)) = sellables(&player).get(decision).cloned() {
+                let offer = shop.offer_for(&loot);
+                match &loot {
+                    Loot::Weapon(weapon) => {
+                        player.inventory.remove_weapon(weapon.name);
+                    }
+                    Loot::Item(item) => {
+                        let index = player
+                            .inventory
+                            .items
+                            .iter()
+                            .position(|held| held == item)
+                            .unwrap();
+                        player.inventory.take_item(index);
+                    }
+                    Loot::Resources(_) => unreachable!("Resources can't be sold"),
+                }
+                player.resources.add(PlayerResources {
+                    money: DiceValue {
+                        dice: 0,
+                        constant: offer as i32,
+                    },
+                    ..default()
+                });
+                shop.buy(name, loot, offer);
+                ui_helper.show_line(t!("shop-sold", thing = tr(name), offer = offer));
+                prompt_for_sale(&mut ui_helper, shop, &player);
+            } else {
+                ongoing.menu = ShopMenu::Main;
+                prompt_for_menu(&mut ui_helper);
+            }
+        }
+    }
+}
+
+/// Everything the player could sell, keeping at least one weapon
+fn sellables(player: &Player) -> Vec<(&'static str, Loot)> {
+    let weapons = if player.inventory.weapons.len() > 1 {
+        player.inventory.weapons.as_slice()
+    } else {
+        &[]
+    };
+    weapons
+        .iter()
+        .map(|weapon| (weapon.name, Loot::Weapon(*weapon)))
+        .chain(
+            player
+                .inventory
+                .items
+                .iter()
+                .map(|item| (item.name, Loot::Item(*item))),
+        )
+        .collect()
+}
+
+fn prompt_for_menu(ui_helper: &mut ResMut<UIHelper>) {
+    ui_helper.prompt(
+        t!("shop-prompt"),
+        vec![t!("shop-buy"), t!("shop-sell"), t!("shop-leave")],
+    );
+}
+
+fn prompt_for_purchase(
+    ui_helper: &mut ResMut<UIHelper>,
+    shop: &Shop,
+    player: &Player,
+    chapter: Chapter,
+) {
+    let mut options: Vec<String> = shop
+        .entries
+        .iter()
+        .map(|entry| {
+            let price = shop.price_of(entry, chapter);
+            let id = if !entry.in_stock() {
+                "shop-entry-sold-out"
+            } else if !player.resources.could_afford(&PlayerResources {
+                money: price,
+                ..default()
+            }) {
+                "shop-entry-too-dear"
+            } else {
+                "shop-entry"
+            };
+            t!(id, thing = tr(entry.name), price = price.as_string())
+        })
+        .collect();
+    options.push(t!("shop-back"));
+    ui_helper.prompt(t!("shop-buy-prompt"), options);
+}
+
+fn prompt_for_sale(ui_helper: &mut ResMut<UIHelper>, shop: &Shop, player: &Player) {
+    let mut options: Vec<String> = sellables(player)
+        .iter()
+        .map(|(name, loot)| t!("shop-entry", thing = tr(name), price = shop.offer_for(loot)))
+        .collect();
+    options.push(t!("shop-back"));
+    ui_helper.prompt(t!("shop-sell-prompt"), options);
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Shop, ShopEntry};
+    use crate::{inventory::jerky, loot::Loot, travel::Chapter};
+
+    fn shop(markup: u32, reputation: i32) -> Shop {
+        Shop {
+            greeting: "",
+            markup,
+            reputation,
+            entries: vec![ShopEntry {
+                name: "Jerky",
+                loot: Loot::Item(jerky()),
+                price: "10".into(),
+                stock: Some(1),
+            }],
+        }
+    }
+
+    #[test]
+    fn markup_and_chapter() {
+        let greedy = shop(150, 0);
+        let entry = &greedy.entries[0];
+        assert_eq!(greedy.price_of(entry, Chapter::Departure), "15".into());
+        assert_eq!(greedy.price_of(entry, Chapter::Arrival), "17".into());
+    }
+
+    #[test]
+    fn reputation_discount_has_a_floor() {
+        let friendly = shop(100, 5);
+        assert_eq!(
+            friendly.price_of(&friendly.entries[0], Chapter::Departure),
+            "9".into()
+        );
+
+        let besties = shop(50, 10);
+        assert_eq!(
+            besties.price_of(&besties.entries[0], Chapter::Departure),
+            "5".into()
+        );
+    }
+
+    #[test]
+    fn buy_back() {
+        let mut shop = shop(100, 0);
+        assert_eq!(shop.offer_for(&Loot::Item(jerky())), 5);
+
+        shop.sell(0);
+        assert!(!shop.entries[0].in_stock());
+        assert_eq!(shop.reputation, 1);
+
+        shop.buy("Jerky", Loot::Item(jerky()), 5);
+        assert!(shop.entries[0].in_stock());
+        assert_eq!(shop.entries.len(), 1);
+    }
+}
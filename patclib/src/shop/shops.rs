@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::{
+    battle::{cattle_prod, sawn_off_shotgun},
+    inventory::{bandages, dynamite, jerky, smoke_bomb},
+    loot::Loot,
+    player::PlayerResources,
+};
+
+use super::{Shop, ShopEntry, ShopId};
+
+fn bullets() -> ShopEntry {
+    ShopEntry {
+        name: "shop-bullets",
+        loot: Loot::Resources(PlayerResources {
+            bullets: 6,
+            ..default()
+        }),
+        price: "10".into(),
+        stock: None,
+    }
+}
+
+fn food() -> ShopEntry {
+    ShopEntry {
+        name: "shop-food",
+        loot: Loot::Resources(PlayerResources {
+            stamina: 4,
+            ..default()
+        }),
+        price: "8".into(),
+        stock: None,
+    }
+}
+
+pub fn shops() -> HashMap<ShopId, Shop> {
+    HashMap::from([
+        (
+            ShopId::RoadMerchant,
+            Shop {
+                greeting: "shop-road-merchant",
+                markup: 100,
+                reputation: 0,
+                entries: vec![
+                    bullets(),
+                    food(),
+                    ShopEntry {
+                        name: "item-bandages",
+                        loot: Loot::Item(bandages()),
+                        price: "6".into(),
+                        stock: Some(3),
+                    },
+                    ShopEntry {
+                        name: "item-dynamite",
+                        loot: Loot::Item(dynamite()),
+                        price: "20".into(),
+                        stock: Some(1),
+                    },
+                    ShopEntry {
+                        name: "shop-mystery-box",
+                        loot: Loot::Item(smoke_bomb()),
+                        price: "2d6".into(),
+                        stock: Some(2),
+                    },
+                    ShopEntry {
+                        name: "weapon-sawn-off-shotgun",
+                        loot: Loot::Weapon(sawn_off_shotgun()),
+                        price: "30".into(),
+                        stock: Some(1),
+                    },
+                ],
+            },
+        ),
+        (
+            ShopId::GeneralStore,
+            Shop {
+                greeting: "shop-general-store",
+                markup: 120,
+                reputation: 0,
+                entries: vec![
+                    bullets(),
+                    food(),
+                    ShopEntry {
+                        name: "shop-battery-box",
+                        loot: Loot::Resources(PlayerResources {
+                            batteries: "10d6".into(),
+                            ..default()
+                        }),
+                        price: "20".into(),
+                        stock: Some(2),
+                    },
+                    ShopEntry {
+                        name: "item-jerky",
+                        loot: Loot::Item(jerky()),
+                        price: "5".into(),
+                        stock: None,
+                    },
+                    ShopEntry {
+                        name: "weapon-cattle-prod",
+                        loot: Loot::Weapon(cattle_prod()),
+                        price: "15".into(),
+                        stock: Some(1),
+                    },
+                ],
+            },
+        ),
+    ])
+}
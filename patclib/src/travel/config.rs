@@ -0,0 +1,138 @@
+/// Pacing of the walk between encounters, read from the travel file when there is one.
+/// Insert one before the plugins to skip the file
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TravelConfig {
+    /// Seconds a leg of the journey takes
+    pub leg_duration: f64,
+    /// Seconds each hour of rest adds to the leg
+    pub rest_hour_duration: f64,
+    /// Seconds between chances of something happening on the road
+    pub event_interval: f64,
+    /// Chance out of 100 that something happens at each interval
+    pub event_chance: u32,
+    /// How many times faster time passes while hurrying along
+    pub fast_forward: f64,
+}
+impl Default for TravelConfig {
+    fn default() -> Self {
+        Self {
+            leg_duration: 5.0,
+            rest_hour_duration: 0.5,
+            event_interval: 1.5,
+            event_chance: 40,
+            fast_forward: 4.0,
+        }
+    }
+}
+impl TravelConfig {
+    /// The travel file, one setting per line
+    pub fn render(&self) -> String {
+        let mut text = String::from("# setting = value, durations in seconds\n");
+        text.push_str(&format!("leg_duration = {}\n", self.leg_duration));
+        text.push_str(&format!(
+            "rest_hour_duration = {}\n",
+            self.rest_hour_duration
+        ));
+        text.push_str(&format!("event_interval = {}\n", self.event_interval));
+        text.push_str(&format!("event_chance = {}\n", self.event_chance));
+        text.push_str(&format!("fast_forward = {}\n", self.fast_forward));
+        text
+    }
+
+    /// Reads a travel file. Settings it doesn't mention keep their defaults,
+    /// anything it gets wrong is skipped and complained about
+    pub fn parse(text: &str) -> (Self, Vec<String>) {
+        let mut config = Self::default();
+        let mut complaints = vec![];
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (name, value) = if let Some((name, value)) = line.split_once('=') {
+                (name.trim(), value.trim())
+            } else {
+                complaints.push(format!("line {}: expected `setting = value`", number + 1));
+                continue;
+            };
+            let set = match name {
+                "leg_duration" => seconds(value).map(|seconds| config.leg_duration = seconds),
+                "rest_hour_duration" => {
+                    seconds(value).map(|seconds| config.rest_hour_duration = seconds)
+                }
+                "event_interval" => seconds(value).map(|seconds| config.event_interval = seconds),
+                "event_chance" => match value.parse() {
+                    Ok(chance) if chance <= 100 => {
+                        config.event_chance = chance;
+                        Ok(())
+                    }
+                    _ => Err("a chance out of 100"),
+                },
+                "fast_forward" => match value.parse::<f64>() {
+                    Ok(times) if times.is_finite() && times >= 1.0 => {
+                        config.fast_forward = times;
+                        Ok(())
+                    }
+                    _ => Err("1 or more"),
+                },
+                _ => {
+                    complaints.push(format!("line {}: no setting called {}", number + 1, name));
+                    continue;
+                }
+            };
+            if let Err(expected) = set {
+                complaints.push(format!(
+                    "line {}: {} should be {}, not {}",
+                    number + 1,
+                    name,
+                    expected,
+                    value
+                ));
+            }
+        }
+        (config, complaints)
+    }
+}
+
+/// A duration, anything that wouldn't let time pass is refused
+fn seconds(value: &str) -> Result<f64, &'static str> {
+    match value.parse::<f64>() {
+        Ok(seconds) if seconds.is_finite() && seconds > 0.0 => Ok(seconds),
+        _ => Err("a positive number of seconds"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TravelConfig;
+
+    #[test]
+    fn settings_survive_the_file() {
+        let config = TravelConfig {
+            leg_duration: 8.5,
+            event_chance: 100,
+            fast_forward: 1.0,
+            ..TravelConfig::default()
+        };
+        assert_eq!(TravelConfig::parse(&config.render()), (config, vec![]));
+    }
+
+    #[test]
+    fn mistakes_keep_the_defaults() {
+        let (config, complaints) = TravelConfig::parse(
+            "leg_duration = 0\nevent_chance = 101\nrest_hour_duration = 2\nwalk faster\nspeed = 3",
+        );
+        assert_eq!(
+            config,
+            TravelConfig {
+                rest_hour_duration: 2.0,
+                ..TravelConfig::default()
+            }
+        );
+        assert_eq!(complaints.len(), 4);
+        assert_eq!(
+            complaints[0],
+            "line 1: leg_duration should be a positive number of seconds, not 0"
+        );
+    }
+}
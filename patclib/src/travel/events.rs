@@ -0,0 +1,235 @@
+use core::fmt;
+
+use bevy::prelude::*;
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    locale::{t, tr},
+    player::PlayerResources,
+};
+
+/// Hours of walking between two stops on the map
+pub const HOURS_PER_LEG: u32 = 6;
+const STARTING_HOUR: u32 = 8;
+/// Chance out of 100 that the weather turns on any given leg
+const WEATHER_CHANGE_CHANCE: u32 = 30;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Weather {
+    Clear,
+    Rain,
+    Dust,
+    Heat,
+}
+impl fmt::Display for Weather {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let id = match self {
+            Weather::Clear => "weather-clear",
+            Weather::Rain => "weather-rain",
+            Weather::Dust => "weather-dust",
+            Weather::Heat => "weather-heat",
+        };
+        write!(f, "{}", tr(id))
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TimeOfDay {
+    Morning,
+    Afternoon,
+    Evening,
+    Night,
+}
+impl fmt::Display for TimeOfDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let id = match self {
+            TimeOfDay::Morning => "time-morning",
+            TimeOfDay::Afternoon => "time-afternoon",
+            TimeOfDay::Evening => "time-evening",
+            TimeOfDay::Night => "time-night",
+        };
+        write!(f, "{}", tr(id))
+    }
+}
+
+/// Time and weather out on the road
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Clock {
+    pub day: u32,
+    pub hour: u32,
+    pub weather: Weather,
+}
+impl Default for Clock {
+    fn default() -> Self {
+        Self {
+            day: 1,
+            hour: STARTING_HOUR,
+            weather: Weather::Clear,
+        }
+    }
+}
+impl fmt::Display for Clock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let clock = t!(
+            "travel-clock",
+            day = self.day,
+            time = self.time_of_day().to_string(),
+            weather = self.weather.to_string()
+        );
+        write!(f, "{}", clock)
+    }
+}
+impl Clock {
+    pub fn time_of_day(&self) -> TimeOfDay {
+        match self.hour {
+            5..=11 => TimeOfDay::Morning,
+            12..=16 => TimeOfDay::Afternoon,
+            17..=20 => TimeOfDay::Evening,
+            _ => TimeOfDay::Night,
+        }
+    }
+
+    pub fn advance(&mut self, hours: u32) {
+        let hours = self.hour + hours;
+        self.day += hours / 24;
+        self.hour = hours % 24;
+    }
+
+    /// Returns the new weather if it changed
+    pub fn change_weather_with(&mut self, rng: &mut impl Rng) -> Option<Weather> {
+        if rng.gen_range(0..100) >= WEATHER_CHANGE_CHANCE {
+            return None;
+        }
+        let weather = *[Weather::Clear, Weather::Rain, Weather::Dust, Weather::Heat]
+            .choose(rng)
+            .unwrap();
+        (weather != self.weather).then(|| {
+            self.weather = weather;
+            weather
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum EventEffect {
+    Nothing,
+    Gain(PlayerResources),
+    Lose(PlayerResources),
+}
+
+/// Something small that happens while walking
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct AmbientEvent {
+    /// Message id
+    pub line: &'static str,
+    pub effect: EventEffect,
+}
+
+/// Picks something fitting for the weather and time of day
+pub fn ambient_event_with(clock: &Clock, rng: &mut impl Rng) -> AmbientEvent {
+    let mut events = vec![
+        AmbientEvent {
+            line: "event-tumbleweed",
+            effect: EventEffect::Nothing,
+        },
+        AmbientEvent {
+            line: "event-dropped-die",
+            effect: EventEffect::Gain(PlayerResources {
+                money: "1d6".into(),
+                ..default()
+            }),
+        },
+    ];
+    events.push(match clock.weather {
+        Weather::Clear => AmbientEvent {
+            line: "event-clear",
+            effect: EventEffect::Nothing,
+        },
+        Weather::Rain => AmbientEvent {
+            line: "event-rain",
+            effect: EventEffect::Lose(PlayerResources {
+                batteries: "1".into(),
+                ..default()
+            }),
+        },
+        Weather::Dust => AmbientEvent {
+            line: "event-dust",
+            effect: EventEffect::Gain(PlayerResources {
+                bullets: 1,
+                ..default()
+            }),
+        },
+        Weather::Heat => AmbientEvent {
+            line: "event-heat",
+            effect: EventEffect::Lose(PlayerResources {
+                stamina: 2,
+                ..default()
+            }),
+        },
+    });
+    events.push(match clock.time_of_day() {
+        TimeOfDay::Morning => AmbientEvent {
+            line: "event-morning",
+            effect: EventEffect::Gain(PlayerResources {
+                stamina: 2,
+                ..default()
+            }),
+        },
+        TimeOfDay::Afternoon | TimeOfDay::Evening => AmbientEvent {
+            line: "event-afternoon",
+            effect: EventEffect::Nothing,
+        },
+        TimeOfDay::Night => AmbientEvent {
+            line: "event-night",
+            effect: EventEffect::Lose(PlayerResources {
+                stamina: 1,
+                ..default()
+            }),
+        },
+    });
+    *events.choose(rng).unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{ambient_event_with, Clock, EventEffect, TimeOfDay, Weather};
+
+    #[test]
+    fn days_roll_over() {
+        let mut clock = Clock::default();
+        assert_eq!(clock.time_of_day(), TimeOfDay::Morning);
+        clock.advance(14);
+        assert_eq!(clock.time_of_day(), TimeOfDay::Night);
+        clock.advance(6);
+        assert_eq!(clock.day, 2);
+        assert_eq!(clock.hour, 4);
+    }
+
+    #[test]
+    fn weather_changes_sometimes() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut clock = Clock::default();
+        let changes = (0..1000)
+            .filter(|_| clock.change_weather_with(&mut rng).is_some())
+            .count();
+        assert!((100..400).contains(&changes));
+    }
+
+    #[test]
+    fn events_fit_the_weather() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let clock = Clock {
+            weather: Weather::Rain,
+            ..Clock::default()
+        };
+        for _ in 0..100 {
+            let event = ambient_event_with(&clock, &mut rng);
+            assert_ne!(event.line, "event-heat");
+            if let EventEffect::Lose(loss) = event.effect {
+                assert_eq!(loss.stamina, 0);
+            }
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use core::fmt;
+
+use rand::{seq::SliceRandom, thread_rng, Rng};
+
+use crate::locale::tr;
+
+/// How many stops a stretch of road has, start and destination included
+const ROUTE_LENGTH: u32 = 8;
+const MIN_WIDTH: usize = 2;
+const MAX_WIDTH: usize = 3;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum NodeKind {
+    Town,
+    Wilds,
+    Merchant,
+    Danger,
+}
+impl fmt::Display for NodeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let id = match self {
+            NodeKind::Town => "place-town",
+            NodeKind::Wilds => "place-wilds",
+            NodeKind::Merchant => "place-trading-post",
+            NodeKind::Danger => "place-badlands",
+        };
+        write!(f, "{}", tr(id))
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Node {
+    pub kind: NodeKind,
+    pub depth: u32,
+    /// Indices of the nodes this one leads to
+    pub next: Vec<usize>,
+}
+
+/// A branching stretch of road, every path leads from the first node to the last
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct RouteMap {
+    pub nodes: Vec<Node>,
+    pub current: usize,
+}
+impl RouteMap {
+    pub fn generate() -> Self {
+        Self::generate_with(&mut thread_rng())
+    }
+
+    fn generate_with(rng: &mut impl Rng) -> Self {
+        let mut nodes = vec![Node {
+            kind: NodeKind::Town,
+            depth: 0,
+            next: vec![],
+        }];
+        let mut previous_layer = vec![0];
+
+        for depth in 1..ROUTE_LENGTH {
+            let is_destination = depth == ROUTE_LENGTH - 1;
+            let width = if is_destination {
+                1
+            } else {
+                rng.gen_range(MIN_WIDTH..=MAX_WIDTH)
+            };
+            let layer: Vec<usize> = (nodes.len()..nodes.len() + width).collect();
+            for _ in 0..width {
+                let kind = if is_destination {
+                    NodeKind::Town
+                } else {
+                    *[
+                        (NodeKind::Wilds, 3),
+                        (NodeKind::Danger, 2),
+                        (NodeKind::Merchant, 1),
+                        (NodeKind::Town, 1),
+                    ]
+                    .choose_weighted(rng, |(_, weight)| *weight)
+                    .map(|(kind, _)| kind)
+                    .unwrap()
+                };
+                nodes.push(Node {
+                    kind,
+                    depth,
+                    next: vec![],
+                });
+            }
+
+            // Every node gets somewhere, and everywhere can be gotten to
+            for &from in &previous_layer {
+                let roads = rng.gen_range(1..=2.min(width));
+                for &to in layer.choose_multiple(rng, roads) {
+                    nodes[from].next.push(to);
+                }
+            }
+            for &to in &layer {
+                if !nodes.iter().any(|node| node.next.contains(&to)) {
+                    let from = *previous_layer.choose(rng).unwrap();
+                    nodes[from].next.push(to);
+                }
+            }
+            for &from in &previous_layer {
+                nodes[from].next.sort_unstable();
+            }
+            previous_layer = layer;
+        }
+
+        Self { nodes, current: 0 }
+    }
+
+    pub fn current_node(&self) -> &Node {
+        &self.nodes[self.current]
+    }
+
+    /// The nodes reachable from here, in the order they're offered to the player
+    pub fn choices(&self) -> Vec<(usize, NodeKind)> {
+        self.current_node()
+            .next
+            .iter()
+            .map(|&index| (index, self.nodes[index].kind))
+            .collect()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.current_node().next.is_empty()
+    }
+
+    /// Stops made so far and stops in total, not counting the start
+    pub fn progress(&self) -> (u32, u32) {
+        (self.current_node().depth, ROUTE_LENGTH - 1)
+    }
+
+    /// Returns false if that node isn't reachable from here
+    pub fn travel_to(&mut self, index: usize) -> bool {
+        let reachable = self.current_node().next.contains(&index);
+        if reachable {
+            self.current = index;
+        }
+        reachable
+    }
+
+    /// One line per stop along the road, every node numbered with the numbers it leads to,
+    /// the current node in brackets
+    pub fn render(&self) -> Vec<String> {
+        (0..ROUTE_LENGTH)
+            .map(|depth| {
+                self.nodes
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, node)| node.depth == depth)
+                    .map(|(index, node)| {
+                        let stop = if index == self.current {
+                            format!("[{}. {}]", index + 1, node.kind)
+                        } else {
+                            format!("{}. {}", index + 1, node.kind)
+                        };
+                        if node.next.is_empty() {
+                            stop
+                        } else {
+                            let next: Vec<String> = node
+                                .next
+                                .iter()
+                                .map(|next| (next + 1).to_string())
+                                .collect();
+                            format!("{} -> {}", stop, next.join(", "))
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("   ")
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{Node, NodeKind, RouteMap, ROUTE_LENGTH};
+
+    fn maps() -> impl Iterator<Item = RouteMap> {
+        let mut rng = StdRng::seed_from_u64(0);
+        (0..50).map(move |_| RouteMap::generate_with(&mut rng))
+    }
+
+    #[test]
+    fn starts_and_ends_in_town() {
+        for map in maps() {
+            assert_eq!(map.nodes[0].kind, NodeKind::Town);
+            let last = map.nodes.last().unwrap();
+            assert_eq!(last.kind, NodeKind::Town);
+            assert_eq!(last.depth, ROUTE_LENGTH - 1);
+            assert!(last.next.is_empty());
+        }
+    }
+
+    #[test]
+    fn everything_is_connected() {
+        for map in maps() {
+            for (index, node) in map.nodes.iter().enumerate().skip(1) {
+                assert!(map.nodes.iter().any(|other| other.next.contains(&index)));
+                assert!(node
+                    .next
+                    .iter()
+                    .all(|&next| map.nodes[next].depth == node.depth + 1));
+                if node.depth < ROUTE_LENGTH - 1 {
+                    assert!(!node.next.is_empty());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn walking_the_route() {
+        for mut map in maps() {
+            assert!(!map.travel_to(map.nodes.len() - 1));
+            let mut stops = 0;
+            while !map.is_finished() {
+                let (next, _) = map.choices()[0];
+                assert!(map.travel_to(next));
+                stops += 1;
+            }
+            assert_eq!(stops, ROUTE_LENGTH - 1);
+            assert_eq!(map.render().len(), ROUTE_LENGTH as usize);
+        }
+    }
+
+    #[test]
+    fn forks_show_on_the_map() {
+        let node = |kind, depth, next| Node { kind, depth, next };
+        let map = RouteMap {
+            nodes: vec![
+                node(NodeKind::Town, 0, vec![1, 2]),
+                node(NodeKind::Wilds, 1, vec![3]),
+                node(NodeKind::Danger, 1, vec![3]),
+                node(NodeKind::Town, 2, vec![]),
+            ],
+            current: 1,
+        };
+        let lines = map.render();
+        assert_eq!(lines[0], "1. Town -> 2, 3");
+        assert_eq!(lines[1], "[2. Wilds] -> 4   3. Badlands -> 4");
+        assert_eq!(lines[2], "4. Town");
+    }
+}
@@ -1,45 +1,322 @@
 use bevy::prelude::*;
 
+use rand::{thread_rng, Rng};
+
 use crate::{
+    actions::{Actions, InputAction},
     encounter::{get_random_encounter, Encounter, OngoingEncounter},
-    flow::AppState,
+    files::{read_user_file, write_user_file},
+    flow::{AppState, FlowEvent},
+    locale::{t, tr},
+    player::{Player, PlayerResources},
     ui::UIHelper,
 };
 
+mod config;
+mod events;
+mod map;
+mod save;
+mod survival;
+pub use config::TravelConfig;
+pub use events::Clock;
+use events::{ambient_event_with, EventEffect, HOURS_PER_LEG};
+pub use map::{NodeKind, RouteMap};
+pub use save::SavedRun;
+pub use survival::{Condition, Rest, MAX_STAMINA};
+
+/// Pacing the player wants instead of the defaults, next to the controls file
+const TRAVEL_FILE: &str = "travel.cfg";
+
 #[derive(Debug)]
 pub struct NextEncounter {
     encounter: Encounter,
-    start_at: f64,
+    /// Seconds of walking left
+    remaining: f64,
+    duration: f64,
+    until_event: f64,
+}
+impl NextEncounter {
+    fn progress(&self) -> f64 {
+        (1.0 - self.remaining / self.duration).clamp(0.0, 1.0)
+    }
+}
+
+const PROGRESS_BAR_WIDTH: usize = 10;
+
+/// Where the player is in time and along the road, for the top bar
+pub fn travel_status(clock: &Clock, route: &RouteMap, next: Option<&NextEncounter>) -> String {
+    let (stop, stops) = route.progress();
+    let mut status = t!(
+        "travel-status",
+        clock = clock.to_string(),
+        stop = stop,
+        stops = stops
+    );
+    if let Some(next) = next {
+        let filled = (next.progress() * PROGRESS_BAR_WIDTH as f64) as usize;
+        status.push_str(&format!(
+            " [{}{}]",
+            "#".repeat(filled),
+            "-".repeat(PROGRESS_BAR_WIDTH - filled)
+        ));
+    }
+    status
+}
+
+/// Between encounters, first deciding whether to rest, then where to head next
+#[derive(Debug, Default)]
+struct AtCrossroads {
+    rest: Option<Rest>,
+}
+
+/// The chapters of the journey, as laid out in the README
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Chapter {
+    Departure,
+    Travel,
+    Arrival,
+}
+
+const ENCOUNTERS_IN_DEPARTURE: u32 = 3;
+const ENCOUNTERS_BEFORE_ARRIVAL: u32 = 10;
+
+/// How far along the road the player is
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Journey {
+    pub encounters_started: u32,
 }
+impl Journey {
+    pub fn chapter(&self) -> Chapter {
+        if self.encounters_started <= ENCOUNTERS_IN_DEPARTURE {
+            Chapter::Departure
+        } else if self.encounters_started <= ENCOUNTERS_BEFORE_ARRIVAL {
+            Chapter::Travel
+        } else {
+            Chapter::Arrival
+        }
+    }
+}
+
 pub struct TravelPlugin;
 
 impl Plugin for TravelPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system_set(SystemSet::on_update(AppState::Travel).with_system(start_encounter));
+        // Whatever run the last launch left behind, for Continue to pick up
+        if app.world.get_resource::<SavedRun>().is_none() {
+            if let Some(saved) = SavedRun::load() {
+                app.insert_resource(saved);
+            }
+        }
+        if app.world.get_resource::<TravelConfig>().is_none() {
+            app.insert_resource(load_travel_config());
+        }
+        app.insert_resource(Journey::default())
+            .insert_resource(RouteMap::generate())
+            .insert_resource(Clock::default())
+            .add_system_set(
+                SystemSet::on_update(AppState::Travel)
+                    .with_system(walk)
+                    .with_system(choose_road),
+            );
     }
 }
 
-const WAIT_BEFORE_ENCOUNTER_STARTS: f64 = 5.0;
+/// The player's pacing, or the defaults if they never wrote any down
+fn load_travel_config() -> TravelConfig {
+    let text = if let Some(text) = read_user_file(TRAVEL_FILE) {
+        text
+    } else {
+        // Written out so there's a file to tweak next time
+        let config = TravelConfig::default();
+        if let Err(error) = write_user_file(TRAVEL_FILE, &config.render()) {
+            warn!("Couldn't save {}: {}", TRAVEL_FILE, error);
+        }
+        return config;
+    };
+    let (config, complaints) = TravelConfig::parse(&text);
+    for complaint in complaints {
+        warn!("{}: {}", TRAVEL_FILE, complaint);
+    }
+    config
+}
+
+/// Leaves the road wherever the player was on it
+pub(crate) fn reset(world: &mut World) {
+    world.remove_resource::<NextEncounter>();
+    world.remove_resource::<AtCrossroads>();
+}
+
+/// What decides how the walk goes
+type Pacing<'w> = (
+    Res<'w, Time>,
+    Res<'w, Actions>,
+    Res<'w, TravelConfig>,
+    Res<'w, Clock>,
+);
 
-fn start_encounter(
+/// Walks the current leg, with things happening along the way
+fn walk(
     mut commands: Commands,
-    time: Res<Time>,
-    mut app_state: ResMut<State<AppState>>,
+    mut flow: EventWriter<FlowEvent>,
     mut ui_helper: ResMut<UIHelper>,
-    next_encounter: Option<Res<NextEncounter>>,
+    mut journey: ResMut<Journey>,
+    mut player: ResMut<Player>,
+    (time, actions, config, clock): Pacing,
+    next_encounter: Option<ResMut<NextEncounter>>,
 ) {
-    if let Some(next) = next_encounter {
-        if time.seconds_since_startup() > next.start_at {
-            commands.remove_resource::<NextEncounter>();
-            commands.insert_resource(OngoingEncounter(next.encounter.clone()));
-            app_state.push(AppState::Encounter).unwrap()
-        }
+    let mut next = if let Some(next) = next_encounter {
+        next
     } else {
-        ui_helper.show_line("You start looking for trouble");
+        return;
+    };
+
+    if actions.just_pressed(InputAction::Skip) {
+        next.remaining = 0.0;
+    }
+    let speed = if actions.held(InputAction::Hurry) {
+        config.fast_forward
+    } else {
+        1.0
+    };
+    let elapsed = time.delta_seconds_f64() * speed;
+    next.remaining -= elapsed;
+    next.until_event -= elapsed;
+
+    if next.until_event <= 0.0 && next.remaining > 0.0 {
+        next.until_event += config.event_interval;
+        let mut rng = thread_rng();
+        if rng.gen_range(0..100) < config.event_chance {
+            let event = ambient_event_with(&clock, &mut rng);
+            ui_helper.show_line(tr(event.line));
+            match event.effect {
+                EventEffect::Nothing => {}
+                EventEffect::Gain(resources) => player.gain(resources),
+                EventEffect::Lose(resources) => {
+                    for line in player.resources.force_remove(resources).lines() {
+                        ui_helper.show_line(line);
+                    }
+                }
+            }
+        }
+    }
+
+    if next.remaining <= 0.0 {
+        commands.remove_resource::<NextEncounter>();
+        let upkeep = player.condition.walk();
+        player.resources.stamina = (player.resources.stamina - upkeep).max(0);
+        ui_helper.show_line(t!("travel-upkeep", stamina = upkeep));
+        if player.has_lost() {
+            flow.send(FlowEvent::PlayerDied);
+            return;
+        }
+        if player.condition.is_exhausted() {
+            ui_helper.show_line(t!("travel-exhausted"));
+        }
+        if player.condition.is_hungry() {
+            ui_helper.show_line(t!("travel-hungry"));
+        }
+
+        journey.encounters_started += 1;
+        commands.insert_resource(OngoingEncounter(next.encounter.clone()));
+        flow.send(FlowEvent::EncounterStarted);
+    } else if player.has_lost() {
+        // Something on the road did the player in
+        commands.remove_resource::<NextEncounter>();
+        flow.send(FlowEvent::PlayerDied);
+    }
+}
 
-        commands.insert_resource(NextEncounter {
-            encounter: get_random_encounter(),
-            start_at: time.seconds_since_startup() + WAIT_BEFORE_ENCOUNTER_STARTS,
-        });
+/// Between legs, lets the player rest and pick the next stop on the map
+fn choose_road(
+    mut commands: Commands,
+    mut ui_helper: ResMut<UIHelper>,
+    mut player: ResMut<Player>,
+    mut route: ResMut<RouteMap>,
+    mut clock: ResMut<Clock>,
+    (config, journey): (Res<TravelConfig>, Res<Journey>),
+    (next_encounter, at_crossroads): (Option<Res<NextEncounter>>, Option<ResMut<AtCrossroads>>),
+) {
+    if next_encounter.is_some() {
+        return;
+    }
+    if let Some(mut crossroads) = at_crossroads {
+        let decision = if let Some(decision) = player.drain_decision() {
+            decision
+        } else {
+            return;
+        };
+        if let Some(rest) = crossroads.rest {
+            let (next, kind) = route.choices()[decision];
+            route.travel_to(next);
+            clock.advance(rest.hours() + HOURS_PER_LEG);
+            ui_helper.show_line(t!("travel-set-off", place = kind.to_string()));
+            if let Some(weather) = clock.change_weather_with(&mut thread_rng()) {
+                ui_helper.show_line(t!("travel-weather", weather = weather.to_string()));
+            }
+            ui_helper.show_line(t!("travel-looking-for-trouble"));
+
+            let duration = config.leg_duration + rest.hours() as f64 * config.rest_hour_duration;
+            commands.remove_resource::<AtCrossroads>();
+            commands.insert_resource(NextEncounter {
+                encounter: get_random_encounter(kind),
+                remaining: duration,
+                duration,
+                until_event: config.event_interval,
+            });
+        } else {
+            let rest = REST_OPTIONS[decision];
+            let recovered = player.condition.rest(rest);
+            if recovered > 0 {
+                player.gain(PlayerResources {
+                    stamina: recovered,
+                    ..default()
+                });
+                ui_helper.show_line(t!(rest.recovery_line(), stamina = recovered));
+            }
+            if rest == Rest::Camp {
+                // Camp is where the food gets eaten, when there's any
+                if let Some(meal) = player.inventory.take_food() {
+                    meal.effect.apply(&mut player);
+                    ui_helper.show_line(t!("rest-meal", food = tr(meal.name)));
+                } else {
+                    ui_helper.show_line(t!("rest-no-meal"));
+                }
+            }
+            crossroads.rest = Some(rest);
+            prompt_for_route(&mut ui_helper, &route);
+        }
+    } else {
+        if route.is_finished() {
+            *route = RouteMap::generate();
+            ui_helper.show_line(t!("travel-new-road"));
+        }
+        let saved = SavedRun::capture(&player, &route, &clock, &journey);
+        saved.store();
+        commands.insert_resource(saved);
+        commands.insert_resource(AtCrossroads::default());
+        prompt_for_rest(&mut ui_helper, &player);
     }
 }
+
+const REST_OPTIONS: [Rest; 3] = [Rest::PressOn, Rest::Breather, Rest::Camp];
+
+fn prompt_for_rest(ui_helper: &mut ResMut<UIHelper>, player: &Player) {
+    ui_helper.prompt(
+        t!("travel-rest-prompt", stamina = player.condition.upkeep()),
+        REST_OPTIONS.iter().map(|rest| rest.to_string()).collect(),
+    );
+}
+
+fn prompt_for_route(ui_helper: &mut ResMut<UIHelper>, route: &RouteMap) {
+    ui_helper.prompt(
+        t!("travel-route-prompt"),
+        route
+            .choices()
+            .iter()
+            .map(|(index, kind)| {
+                // Numbered the way the map panel numbers the stops
+                t!("travel-towards", place = kind.to_string(), stop = index + 1)
+            })
+            .collect(),
+    );
+}
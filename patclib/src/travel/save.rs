@@ -0,0 +1,379 @@
+use std::{iter::Peekable, str::SplitWhitespace};
+
+use bevy::prelude::*;
+use regex::Regex;
+
+use super::{
+    events::Weather,
+    map::{Node, NodeKind},
+    Clock, Condition, Journey, RouteMap,
+};
+use crate::{
+    battle::Weapon,
+    dice_value::DiceValue,
+    files::{read_user_file, remove_user_file, write_user_file},
+    inventory::{Inventory, Item, ItemEffect},
+    player::{Player, PlayerResources},
+};
+
+/// Where the run is kept between launches, next to the controls file
+const SAVE_FILE: &str = "run.sav";
+
+/// The run as it stood at the last crossroads, what Continue picks back up
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavedRun {
+    player: Player,
+    route: RouteMap,
+    clock: Clock,
+    journey: Journey,
+}
+impl SavedRun {
+    /// A run nobody has set foot on yet
+    pub fn fresh() -> Self {
+        Self {
+            player: Player::new(),
+            route: RouteMap::generate(),
+            clock: Clock::default(),
+            journey: Journey::default(),
+        }
+    }
+
+    pub(super) fn capture(
+        player: &Player,
+        route: &RouteMap,
+        clock: &Clock,
+        journey: &Journey,
+    ) -> Self {
+        Self {
+            player: Player {
+                decision: None,
+                selected_action: None,
+                selected_weapon: None,
+                position: 0,
+                ..player.clone()
+            },
+            route: route.clone(),
+            clock: *clock,
+            journey: journey.clone(),
+        }
+    }
+
+    /// Puts the player back at the crossroads the run was saved at
+    pub fn restore(self, world: &mut World) {
+        world.insert_resource(self.player);
+        world.insert_resource(self.route);
+        world.insert_resource(self.clock);
+        world.insert_resource(self.journey);
+    }
+
+    /// The run saved the last time the game was played, if it left one
+    pub fn load() -> Option<Self> {
+        let text = read_user_file(SAVE_FILE)?;
+        match Self::parse(&text) {
+            Ok(run) => Some(run),
+            Err(problem) => {
+                warn!("{}: {}", SAVE_FILE, problem);
+                None
+            }
+        }
+    }
+
+    /// Keeps the run for the next launch
+    pub fn store(&self) {
+        if let Err(error) = write_user_file(SAVE_FILE, &self.render()) {
+            warn!("Couldn't save {}: {}", SAVE_FILE, error);
+        }
+    }
+
+    /// Throws the save file away, there's nothing left to continue
+    pub fn forget() {
+        if let Err(error) = remove_user_file(SAVE_FILE) {
+            warn!("Couldn't remove {}: {}", SAVE_FILE, error);
+        }
+    }
+
+    /// The save file, one `key = values` line per thing worth keeping
+    pub fn render(&self) -> String {
+        let mut text = String::from("# The run as it stood at the last crossroads\n");
+        let mut line = |key: &str, value: String| text.push_str(&format!("{} = {}\n", key, value));
+
+        let player = &self.player;
+        line("resources", resources(&player.resources));
+        let condition = &player.condition;
+        line(
+            "condition",
+            format!(
+                "{} {} {}",
+                condition.max_stamina, condition.fatigue, condition.hunger
+            ),
+        );
+        line("dog", format!("{} {}", player.dog.health, player.dog.trust));
+        for weapon in &player.inventory.weapons {
+            let cost = weapon
+                .cost
+                .map_or_else(|| "none".to_string(), |cost| resources(&cost));
+            line(
+                "weapon",
+                format!(
+                    "{} {} {} {}",
+                    weapon.name,
+                    dice(weapon.damage),
+                    weapon.range,
+                    cost
+                ),
+            );
+        }
+        for item in &player.inventory.items {
+            let effect = match item.effect {
+                ItemEffect::Restore(restored) => format!("restore {}", resources(&restored)),
+                ItemEffect::Feed(stamina) => format!("feed {}", stamina),
+                ItemEffect::Smoke => "smoke".to_string(),
+                ItemEffect::Explode(damage) => format!("explode {}", dice(damage)),
+            };
+            line("item", format!("{} {}", item.name, effect));
+        }
+
+        let weather = WEATHERS
+            .iter()
+            .find(|(_, weather)| *weather == self.clock.weather)
+            .map_or("clear", |(name, _)| name);
+        line(
+            "clock",
+            format!("{} {} {}", self.clock.day, self.clock.hour, weather),
+        );
+        line("encounters", self.journey.encounters_started.to_string());
+
+        for node in &self.route.nodes {
+            let kind = NODE_KINDS
+                .iter()
+                .find(|(_, kind)| *kind == node.kind)
+                .map_or("wilds", |(name, _)| name);
+            let next: Vec<String> = node.next.iter().map(usize::to_string).collect();
+            line(
+                "node",
+                format!("{} {} {}", kind, node.depth, next.join(" "))
+                    .trim_end()
+                    .to_string(),
+            );
+        }
+        line("current", self.route.current.to_string());
+        text
+    }
+
+    /// Reads a save file back. Unlike the controls there's no sensible default
+    /// for half a run, so anything it gets wrong throws the whole save out
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut run = Self {
+            player: Player {
+                inventory: Inventory::default(),
+                ..Player::new()
+            },
+            route: RouteMap {
+                nodes: vec![],
+                current: 0,
+            },
+            clock: Clock::default(),
+            journey: Journey::default(),
+        };
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            run.read_line(line)
+                .map_err(|problem| format!("line {}: {}", number + 1, problem))?;
+        }
+
+        let nodes = run.route.nodes.len();
+        if run.route.current >= nodes
+            || run
+                .route
+                .nodes
+                .iter()
+                .any(|node| node.next.iter().any(|next| *next >= nodes))
+        {
+            return Err("the road leads nowhere".to_string());
+        }
+        Ok(run)
+    }
+
+    fn read_line(&mut self, line: &str) -> Result<(), String> {
+        let (key, values) = line
+            .split_once('=')
+            .ok_or_else(|| "expected `key = values`".to_string())?;
+        let mut values = Values(values.split_whitespace().peekable());
+        let player = &mut self.player;
+        match key.trim() {
+            "resources" => player.resources = values.resources()?,
+            "condition" => {
+                player.condition = Condition {
+                    max_stamina: values.number()?,
+                    fatigue: values.number()?,
+                    hunger: values.number()?,
+                }
+            }
+            "dog" => {
+                player.dog.health = values.number()?;
+                player.dog.trust = values.number()?;
+            }
+            "weapon" => player.inventory.weapons.push(Weapon {
+                name: values.name()?,
+                damage: values.dice()?,
+                range: values.number()?,
+                cost: if values.0.next_if_eq(&"none").is_some() {
+                    None
+                } else {
+                    Some(values.resources()?)
+                },
+            }),
+            "item" => {
+                let name = values.name()?;
+                let effect = match values.word()? {
+                    "restore" => ItemEffect::Restore(values.resources()?),
+                    "feed" => ItemEffect::Feed(values.number()?),
+                    "smoke" => ItemEffect::Smoke,
+                    "explode" => ItemEffect::Explode(values.dice()?),
+                    other => return Err(format!("`{}` isn't an item effect", other)),
+                };
+                player.inventory.items.push(Item { name, effect });
+            }
+            "clock" => {
+                self.clock = Clock {
+                    day: values.number()?,
+                    hour: values.number()?,
+                    weather: values.one_of(&WEATHERS)?,
+                }
+            }
+            "encounters" => self.journey.encounters_started = values.number()?,
+            "node" => {
+                let kind = values.one_of(&NODE_KINDS)?;
+                let depth = values.number()?;
+                let mut next = vec![];
+                while values.0.peek().is_some() {
+                    next.push(values.number()?);
+                }
+                self.route.nodes.push(Node { kind, depth, next });
+            }
+            "current" => self.route.current = values.number()?,
+            other => return Err(format!("`{}` isn't something a run keeps", other)),
+        }
+        Ok(())
+    }
+}
+
+/// How the weather and the kinds of places are written in the save file
+const WEATHERS: [(&str, Weather); 4] = [
+    ("clear", Weather::Clear),
+    ("rain", Weather::Rain),
+    ("dust", Weather::Dust),
+    ("heat", Weather::Heat),
+];
+const NODE_KINDS: [(&str, NodeKind); 4] = [
+    ("town", NodeKind::Town),
+    ("wilds", NodeKind::Wilds),
+    ("merchant", NodeKind::Merchant),
+    ("danger", NodeKind::Danger),
+];
+
+/// Written out in full, `as_string` leaves out what's zero
+fn dice(value: DiceValue) -> String {
+    format!("{}d6{:+}", value.dice, value.constant)
+}
+
+fn resources(resources: &PlayerResources) -> String {
+    format!(
+        "{} {} {} {}",
+        resources.stamina,
+        dice(resources.money),
+        resources.bullets,
+        dice(resources.batteries)
+    )
+}
+
+/// What's left of a line after the key
+struct Values<'a>(Peekable<SplitWhitespace<'a>>);
+impl<'a> Values<'a> {
+    fn word(&mut self) -> Result<&'a str, String> {
+        self.0.next().ok_or_else(|| "too few values".to_string())
+    }
+
+    fn number<T: std::str::FromStr>(&mut self) -> Result<T, String> {
+        let word = self.word()?;
+        word.parse()
+            .map_err(|_| format!("`{}` isn't a number", word))
+    }
+
+    /// Written out in full the way `dice` writes it, `DiceValue::from` lets anything through
+    fn dice(&mut self) -> Result<DiceValue, String> {
+        let word = self.word()?;
+        let broken = || format!("`{}` isn't a dice value", word);
+        let dice = Regex::new(r"^(\d+)d6([+-]\d+)$").unwrap();
+        let captures = dice.captures(word).ok_or_else(broken)?;
+        Ok(DiceValue {
+            dice: captures[1].parse().map_err(|_| broken())?,
+            constant: captures[2].parse().map_err(|_| broken())?,
+        })
+    }
+
+    /// A message id. The game only ever holds those as `&'static str`, and a save
+    /// is read once per launch, so the few bytes are leaked rather than threaded through
+    fn name(&mut self) -> Result<&'static str, String> {
+        Ok(Box::leak(self.word()?.to_string().into_boxed_str()))
+    }
+
+    fn one_of<T: Copy>(&mut self, names: &[(&str, T)]) -> Result<T, String> {
+        let word = self.word()?;
+        names
+            .iter()
+            .find(|(name, _)| *name == word)
+            .map(|(_, value)| *value)
+            .ok_or_else(|| format!("`{}` isn't one of the choices", word))
+    }
+
+    fn resources(&mut self) -> Result<PlayerResources, String> {
+        Ok(PlayerResources {
+            stamina: self.number()?,
+            money: self.dice()?,
+            bullets: self.number()?,
+            batteries: self.dice()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SavedRun;
+    use crate::{
+        inventory::{dynamite, jerky, smoke_bomb},
+        travel::events::Weather,
+    };
+
+    #[test]
+    fn a_run_survives_the_save_file() {
+        let mut run = SavedRun::fresh();
+        run.player
+            .inventory
+            .items
+            .extend([jerky(), smoke_bomb(), dynamite()]);
+        run.player.inventory.weapons[0].damage.constant = -2;
+        run.player.condition.hunger = 3;
+        run.player.dog.trust = -4;
+        run.clock.weather = Weather::Dust;
+        run.clock.day = 4;
+        run.route.current = 1;
+        run.journey.encounters_started = 7;
+
+        assert_eq!(SavedRun::parse(&run.render()), Ok(run));
+    }
+
+    #[test]
+    fn a_broken_save_is_thrown_out() {
+        let text = SavedRun::fresh().render();
+        assert!(SavedRun::parse(&text.replace("clock = 1", "clock = x")).is_err());
+        assert!(SavedRun::parse(&text.replace("current = 0", "current = 99")).is_err());
+        assert!(SavedRun::parse("stash = 5").is_err());
+        // The money is the first dice value written
+        assert!(SavedRun::parse(&text.replacen("5d6+0", "x", 1)).is_err());
+        assert!(SavedRun::parse(&text.replacen("5d6+0", "99999999999d6+0", 1)).is_err());
+    }
+}
@@ -0,0 +1,199 @@
+use core::fmt;
+
+use crate::locale::tr;
+
+pub const MAX_STAMINA: i32 = 100;
+/// What walking from one encounter to the next costs
+const LEG_UPKEEP: i32 = 3;
+/// Legs walked without a proper rest before exhaustion sets in
+const EXHAUSTED_AT: u32 = 4;
+/// Legs walked without a meal before hunger sets in
+const HUNGRY_AT: u32 = 6;
+/// Every leg walked while exhausted lowers the stamina cap by this much
+const EXHAUSTION_CAP_PENALTY: i32 = 5;
+/// Exhaustion never takes more than half of the cap
+const MIN_STAMINA_CAP: i32 = MAX_STAMINA / 2;
+/// Resting on its own never makes up for a leg of walking, that takes food
+const BREATHER_RECOVERY: i32 = 1;
+const CAMP_RECOVERY: i32 = 2;
+
+/// Ways to spend time on the road before looking for the next encounter
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Rest {
+    PressOn,
+    Breather,
+    Camp,
+}
+impl fmt::Display for Rest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let id = match self {
+            Rest::PressOn => "rest-press-on",
+            Rest::Breather => "rest-breather",
+            Rest::Camp => "rest-camp",
+        };
+        write!(f, "{}", tr(id))
+    }
+}
+impl Rest {
+    /// Hours spent before setting off again
+    pub fn hours(&self) -> u32 {
+        match self {
+            Rest::PressOn => 0,
+            Rest::Breather => 2,
+            Rest::Camp => 8,
+        }
+    }
+
+    /// Message id of how it went, given the stamina recovered
+    pub fn recovery_line(&self) -> &'static str {
+        match self {
+            Rest::PressOn => "rest-recovered-press-on",
+            Rest::Breather => "rest-recovered-breather",
+            Rest::Camp => "rest-recovered-camp",
+        }
+    }
+}
+
+/// How worn down the player is, separate from how much stamina they have left
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Condition {
+    pub max_stamina: i32,
+    /// Legs walked since the last proper rest
+    pub fatigue: u32,
+    /// Legs walked since the last meal
+    pub hunger: u32,
+}
+impl Default for Condition {
+    fn default() -> Self {
+        Self {
+            max_stamina: MAX_STAMINA,
+            fatigue: 0,
+            hunger: 0,
+        }
+    }
+}
+impl Condition {
+    pub fn is_exhausted(&self) -> bool {
+        self.fatigue >= EXHAUSTED_AT
+    }
+
+    pub fn is_hungry(&self) -> bool {
+        self.hunger >= HUNGRY_AT
+    }
+
+    pub fn eat(&mut self) {
+        self.hunger = 0;
+    }
+
+    /// Stamina can't go over this, worn out bodies don't fully recover
+    pub fn stamina_cap(&self) -> i32 {
+        let worn = self.fatigue.saturating_sub(EXHAUSTED_AT) as i32;
+        (self.max_stamina - worn * EXHAUSTION_CAP_PENALTY)
+            .max(MIN_STAMINA_CAP.min(self.max_stamina))
+    }
+
+    /// Stamina the next leg will cost, exhausted travellers burn through twice as much
+    /// and hungry ones a leg's worth more on top
+    pub fn upkeep(&self) -> i32 {
+        let walking = if self.is_exhausted() {
+            2 * LEG_UPKEEP
+        } else {
+            LEG_UPKEEP
+        };
+        if self.is_hungry() {
+            walking + LEG_UPKEEP
+        } else {
+            walking
+        }
+    }
+
+    /// Returns the stamina the leg cost
+    pub fn walk(&mut self) -> i32 {
+        let upkeep = self.upkeep();
+        self.fatigue += 1;
+        self.hunger += 1;
+        upkeep
+    }
+
+    /// Returns the stamina recovered, a meal eaten at camp comes on top of this
+    pub fn rest(&mut self, rest: Rest) -> i32 {
+        match rest {
+            Rest::PressOn => 0,
+            Rest::Breather => {
+                self.fatigue = self.fatigue.saturating_sub(2);
+                BREATHER_RECOVERY
+            }
+            Rest::Camp => {
+                self.fatigue = 0;
+                CAMP_RECOVERY
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        Condition, Rest, EXHAUSTED_AT, HUNGRY_AT, LEG_UPKEEP, MAX_STAMINA, MIN_STAMINA_CAP,
+    };
+
+    #[test]
+    fn exhaustion_doubles_upkeep() {
+        let mut condition = Condition::default();
+        for _ in 0..EXHAUSTED_AT {
+            assert_eq!(condition.walk(), LEG_UPKEEP);
+        }
+        assert!(condition.is_exhausted());
+        assert_eq!(condition.walk(), 2 * LEG_UPKEEP);
+    }
+
+    #[test]
+    fn exhaustion_lowers_the_cap() {
+        let mut condition = Condition::default();
+        for _ in 0..EXHAUSTED_AT {
+            condition.walk();
+        }
+        assert_eq!(condition.stamina_cap(), MAX_STAMINA);
+        condition.walk();
+        assert!(condition.stamina_cap() < MAX_STAMINA);
+        for _ in 0..100 {
+            condition.walk();
+        }
+        assert_eq!(condition.stamina_cap(), MIN_STAMINA_CAP);
+    }
+
+    #[test]
+    fn hunger_adds_to_upkeep() {
+        let mut condition = Condition::default();
+        for _ in 0..HUNGRY_AT {
+            condition.walk();
+            condition.rest(Rest::Camp);
+        }
+        assert!(condition.is_hungry());
+        assert_eq!(condition.walk(), 2 * LEG_UPKEEP);
+        condition.eat();
+        assert_eq!(condition.upkeep(), LEG_UPKEEP);
+    }
+
+    #[test]
+    fn resting_never_outruns_upkeep() {
+        for rest in [Rest::PressOn, Rest::Breather, Rest::Camp] {
+            let mut condition = Condition::default();
+            assert!(condition.rest(rest) < condition.walk());
+        }
+    }
+
+    #[test]
+    fn camping_clears_fatigue() {
+        let mut condition = Condition::default();
+        for _ in 0..10 {
+            condition.walk();
+        }
+        assert_eq!(condition.rest(Rest::PressOn), 0);
+        condition.rest(Rest::Breather);
+        assert_eq!(condition.fatigue, 8);
+        assert!(condition.rest(Rest::Camp) > 0);
+        assert_eq!(condition.fatigue, 0);
+        assert_eq!(condition.stamina_cap(), MAX_STAMINA);
+    }
+}
@@ -1,24 +1,46 @@
 use bevy::prelude::*;
 
-use crate::assets::AssetHandles;
+use crate::{
+    actions::DecisionOption,
+    assets::{AssetHandles, Images},
+};
 
-use super::utils::{div, div_style, FULL};
+use super::{
+    layout::{
+        UiLayout, BOTTOM_BAR_HEIGHT, BOTTOM_BOX_PORTRAIT_WIDTH, JOURNAL_PADDING, MESSAGE_MARGIN,
+    },
+    markup,
+    speaker::Side,
+    stats::{Stat, StatBox, StatText, StatTooltip},
+    utils::{div, div_style, FULL},
+    Speaker,
+};
 
 // Markers
 #[derive(Debug, Component)]
+pub struct TopBar;
+#[derive(Debug, Component)]
 pub struct ChatBox;
 #[derive(Debug, Component)]
-pub struct StaminaText;
+pub struct DogText;
+#[derive(Debug, Component)]
+pub struct JourneyText;
+#[derive(Debug, Component)]
+pub struct JournalPanel;
+#[derive(Debug, Component)]
+pub struct JournalText;
 #[derive(Debug, Component)]
-pub struct MoneyText;
+pub struct MapPanel;
 #[derive(Debug, Component)]
-pub struct BulletText;
+pub struct MapText;
+/// The text on either panel, sized to fit the window
 #[derive(Debug, Component)]
-pub struct BatteryText;
+pub struct PanelText;
+/// The portrait on one side of the chat box
 #[derive(Debug, Component)]
-pub struct ActiveDecision;
+pub struct Portrait(pub Side);
 
-pub(super) fn spawn_gui(commands: &mut Commands, assets: Res<AssetHandles>) {
+pub(super) fn spawn_gui(commands: &mut Commands, assets: Res<AssetHandles>, layout: &UiLayout) {
     commands
         .spawn_bundle(NodeBundle {
             style: Style {
@@ -31,32 +53,69 @@ pub(super) fn spawn_gui(commands: &mut Commands, assets: Res<AssetHandles>) {
             ..div()
         })
         .with_children(|root| {
-            top_bar(root, &assets);
+            top_bar(root, &assets, layout);
             bottom_bar(root, &assets);
         });
+    // The journal goes last so it can be read over the map
+    panel(commands, &assets, layout, MapPanel, MapText);
+    panel(commands, &assets, layout, JournalPanel, JournalText);
 }
 
-fn top_bar(root: &mut ChildBuilder, assets: &Res<AssetHandles>) {
+/// Covers everything else while open, hidden until then
+fn panel(
+    commands: &mut Commands,
+    assets: &Res<AssetHandles>,
+    layout: &UiLayout,
+    panel_marker: impl Component,
+    text_marker: impl Component,
+) {
+    commands
+        .spawn_bundle(NodeBundle {
+            color: assets.colors.dark_background,
+            style: Style {
+                display: Display::None,
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::ColumnReverse,
+                padding: Rect::all(Val::Px(JOURNAL_PADDING)),
+                ..div_style()
+            },
+            ..default()
+        })
+        .insert(panel_marker)
+        .with_children(|panel| {
+            panel
+                .spawn_bundle(wrapped(
+                    text_bundle(assets, layout, String::new()),
+                    layout.journal_width,
+                ))
+                .insert(text_marker)
+                .insert(PanelText);
+        });
+}
+
+fn top_bar(root: &mut ChildBuilder, assets: &Res<AssetHandles>, layout: &UiLayout) {
     root.spawn_bundle(NodeBundle {
         color: assets.colors.dark_background,
         style: Style {
-            size: Size::new(FULL, Val::Percent(3.0)),
+            size: Size::new(FULL, Val::Px(layout.top_bar_height)),
             ..div_style()
         },
         ..div()
     })
+    .insert(TopBar)
     .with_children(|bar| {
-        stat_text(bar, assets, "Stamina: 0", StaminaText);
-        stat_text(bar, assets, "Money: 0", MoneyText);
-        stat_text(bar, assets, "Ammo: 0", BulletText);
-        stat_text(bar, assets, "Batteries: 0", BatteryText);
+        for stat in Stat::ALL {
+            stat_box(bar, assets, layout, stat);
+        }
+        stat_text(bar, assets, layout, DogText);
+        stat_text(bar, assets, layout, JourneyText);
     });
 }
 
 fn stat_text(
     root: &mut ChildBuilder,
     assets: &Res<AssetHandles>,
-    initial_text: &'static str,
+    layout: &UiLayout,
     marker: impl Component,
 ) {
     root.spawn_bundle(NodeBundle {
@@ -72,36 +131,114 @@ fn stat_text(
     })
     .with_children(|container| {
         container
-            .spawn_bundle(text_bundle(&assets, initial_text.into()))
+            // Filled in once there's something to show
+            .spawn_bundle(text_bundle(assets, layout, String::new()))
             .insert(marker);
     });
 }
 
+/// A resource, along with the tooltip shown while it's hovered over
+fn stat_box(root: &mut ChildBuilder, assets: &Res<AssetHandles>, layout: &UiLayout, stat: Stat) {
+    root.spawn_bundle(NodeBundle {
+        style: Style {
+            align_self: AlignSelf::Center,
+            margin: Rect {
+                right: Val::Px(20.0),
+                ..default()
+            },
+            ..default()
+        },
+        ..div()
+    })
+    .insert(StatBox(stat))
+    .insert(Interaction::default())
+    .with_children(|container| {
+        container
+            .spawn_bundle(text_bundle(assets, layout, String::new()))
+            .insert(StatText(stat));
+        container
+            .spawn_bundle(NodeBundle {
+                style: Style {
+                    display: Display::None,
+                    position_type: PositionType::Absolute,
+                    position: Rect {
+                        top: Val::Px(layout.top_bar_height),
+                        left: Val::Px(0.0),
+                        ..default()
+                    },
+                    padding: Rect::all(Val::Px(MESSAGE_MARGIN)),
+                    ..default()
+                },
+                color: assets.colors.dark_background,
+                ..default()
+            })
+            .insert(StatTooltip)
+            .with_children(|tooltip| {
+                tooltip.spawn_bundle(text_bundle(assets, layout, String::new()));
+            });
+    });
+}
+
 fn bottom_bar(root: &mut ChildBuilder, assets: &Res<AssetHandles>) {
     root.spawn_bundle(NodeBundle {
         color: assets.colors.gray_background,
         style: Style {
-            size: Size::new(FULL, Val::Percent(40.0)),
+            size: Size::new(FULL, Val::Percent(BOTTOM_BAR_HEIGHT)),
             ..div_style()
         },
         ..div()
     })
     .with_children(|bar| {
+        spawn_portrait_panel(bar, Side::Left);
         spawn_chat_box(bar, assets);
+        spawn_portrait_panel(bar, Side::Right);
+    });
+}
+
+/// Width over height, the portraits are squeezed into this whatever the image
+const PORTRAIT_ASPECT: f32 = 0.75;
+
+/// Empty until someone on that side speaks up
+fn spawn_portrait_panel(root: &mut ChildBuilder, side: Side) {
+    root.spawn_bundle(NodeBundle {
+        style: Style {
+            size: Size::new(Val::Percent(BOTTOM_BOX_PORTRAIT_WIDTH), FULL),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..div_style()
+        },
+        ..div()
+    })
+    .with_children(|panel| {
+        panel
+            .spawn_bundle(ImageBundle {
+                style: Style {
+                    display: Display::None,
+                    size: Size::new(Val::Auto, Val::Percent(90.0)),
+                    max_size: Size::new(FULL, Val::Auto),
+                    aspect_ratio: Some(PORTRAIT_ASPECT),
+                    ..default()
+                },
+                ..default()
+            })
+            .insert(Portrait(side));
     });
 }
 
-const BOTTOM_BOX_PORTRAIT_WIDTH: f32 = 15.0;
+/// The face that goes with the name, tinted by the expression when it's drawn
+pub fn portrait(images: &Images, speaker: Speaker) -> Handle<Image> {
+    match speaker {
+        Speaker::Cowboy => images.cowboy.clone(),
+        Speaker::Dog => images.dog.clone(),
+        Speaker::Merchant | Speaker::Dandy => images.townsfolk.clone(),
+        Speaker::Stranger => images.open_hand.clone(),
+    }
+}
 
 fn spawn_chat_box(root: &mut ChildBuilder, assets: &Res<AssetHandles>) {
     root.spawn_bundle(NodeBundle {
         color: assets.colors.dark_background,
         style: Style {
-            margin: Rect {
-                left: Val::Percent(BOTTOM_BOX_PORTRAIT_WIDTH),
-                right: Val::Percent(BOTTOM_BOX_PORTRAIT_WIDTH),
-                ..default()
-            },
             size: Size {
                 width: Val::Percent(100.0 - 2.0 * BOTTOM_BOX_PORTRAIT_WIDTH),
                 height: FULL,
@@ -120,10 +257,15 @@ fn spawn_chat_box(root: &mut ChildBuilder, assets: &Res<AssetHandles>) {
     .insert(ChatBox);
 }
 
-pub fn spawn_line(root: &mut ChildBuilder, assets: &Res<AssetHandles>, text: String) -> Entity {
+pub fn spawn_line(
+    root: &mut ChildBuilder,
+    assets: &Res<AssetHandles>,
+    layout: &UiLayout,
+    text: String,
+) -> Entity {
     root.spawn_bundle(spawn_message_container())
         .with_children(|container| {
-            container.spawn_bundle(text_bundle(&assets, text));
+            container.spawn_bundle(chat_text(assets, layout, text, assets.colors.basic_text));
         })
         .id()
 }
@@ -131,11 +273,59 @@ pub fn spawn_line(root: &mut ChildBuilder, assets: &Res<AssetHandles>, text: Str
 pub fn spawn_highlighted_line(
     root: &mut ChildBuilder,
     assets: &Res<AssetHandles>,
+    layout: &UiLayout,
     text: String,
 ) -> Entity {
     root.spawn_bundle(spawn_message_container())
         .with_children(|container| {
-            container.spawn_bundle(colored_text(&assets, text, assets.colors.crucial_text));
+            container.spawn_bundle(chat_text(assets, layout, text, assets.colors.crucial_text));
+        })
+        .id()
+}
+
+/// A line someone says, their name in front of it
+pub fn spawn_speech(
+    root: &mut ChildBuilder,
+    assets: &Res<AssetHandles>,
+    layout: &UiLayout,
+    speaker: String,
+    line: String,
+) -> Entity {
+    let mut sections = vec![TextSection {
+        value: format!("{}: ", speaker),
+        style: TextStyle {
+            font: assets.font.clone(),
+            font_size: layout.font_size,
+            color: assets.colors.highlight_text,
+        },
+    }];
+    sections.extend(marked_up(assets, layout, &line, assets.colors.basic_text));
+    root.spawn_bundle(spawn_message_container())
+        .with_children(|container| {
+            container.spawn_bundle(wrapped(
+                TextBundle {
+                    style: div_style(),
+                    text: Text {
+                        sections,
+                        alignment: default(),
+                    },
+                    ..default()
+                },
+                layout.chat_width,
+            ));
+        })
+        .id()
+}
+
+pub fn spawn_faded_line(
+    root: &mut ChildBuilder,
+    assets: &Res<AssetHandles>,
+    layout: &UiLayout,
+    text: String,
+) -> Entity {
+    root.spawn_bundle(spawn_message_container())
+        .with_children(|container| {
+            container.spawn_bundle(chat_text(assets, layout, text, assets.colors.faded_text));
         })
         .id()
 }
@@ -143,39 +333,67 @@ pub fn spawn_highlighted_line(
 pub fn spawn_decision(
     root: &mut ChildBuilder,
     assets: &Res<AssetHandles>,
+    layout: &UiLayout,
     prompt: String,
     options: Vec<String>,
+    selected: usize,
 ) -> Entity {
     root.spawn_bundle(spawn_message_container())
         .with_children(|container| {
-            container.spawn_bundle(text_bundle(&assets, prompt));
+            container.spawn_bundle(chat_text(assets, layout, prompt, assets.colors.basic_text));
 
             container
-                .spawn_bundle(div())
-                .insert(ActiveDecision)
+                .spawn_bundle(NodeBundle {
+                    style: Style {
+                        flex_wrap: FlexWrap::Wrap,
+                        ..div_style()
+                    },
+                    ..div()
+                })
                 .with_children(|option_wrapper| {
-                    let mut first = true;
-                    for option in options {
-                        if first {
-                            option_wrapper.spawn_bundle(colored_text(
-                                &assets,
-                                option,
-                                assets.colors.highlight_text,
-                            ));
-                            first = false;
+                    for (index, option) in options.into_iter().enumerate() {
+                        let color = if index == selected {
+                            assets.colors.highlight_text
                         } else {
-                            option_wrapper.spawn_bundle(text_bundle(&assets, option));
-                        }
+                            assets.colors.basic_text
+                        };
+                        let mut option =
+                            option_wrapper.spawn_bundle(chat_text(assets, layout, option, color));
+                        // Hovering and clicking work on the text itself
+                        option
+                            .insert(Interaction::default())
+                            .insert(DecisionOption(index));
                     }
                 });
         })
         .id()
 }
 
+/// A decision that has been made, or passed over
+pub fn spawn_answered_decision(
+    root: &mut ChildBuilder,
+    assets: &Res<AssetHandles>,
+    layout: &UiLayout,
+    prompt: String,
+    answer: Option<String>,
+) -> Entity {
+    root.spawn_bundle(spawn_message_container())
+        .with_children(|container| {
+            container.spawn_bundle(chat_text(assets, layout, prompt, assets.colors.basic_text));
+            container.spawn_bundle(chat_text(
+                assets,
+                layout,
+                answer.map_or_else(|| "-".to_string(), |answer| format!("> {}", answer)),
+                assets.colors.faded_text,
+            ));
+        })
+        .id()
+}
+
 fn spawn_message_container() -> NodeBundle {
     NodeBundle {
         style: Style {
-            margin: Rect::all(Val::Px(3.0)),
+            margin: Rect::all(Val::Px(MESSAGE_MARGIN)),
             flex_direction: FlexDirection::ColumnReverse,
             align_content: AlignContent::FlexStart,
             justify_content: JustifyContent::FlexStart,
@@ -185,21 +403,68 @@ fn spawn_message_container() -> NodeBundle {
     }
 }
 
-fn text_bundle(assets: &Res<AssetHandles>, text: String) -> TextBundle {
-    colored_text(assets, text, assets.colors.basic_text)
+fn text_bundle(assets: &Res<AssetHandles>, layout: &UiLayout, text: String) -> TextBundle {
+    colored_text(assets, layout, text, assets.colors.basic_text)
 }
-fn colored_text(assets: &Res<AssetHandles>, text: String, color: Color) -> TextBundle {
+fn colored_text(
+    assets: &Res<AssetHandles>,
+    layout: &UiLayout,
+    text: String,
+    color: Color,
+) -> TextBundle {
     TextBundle {
         style: Style { ..div_style() },
-        text: Text::with_section(
-            text,
-            TextStyle {
-                font: assets.font.clone(),
-                font_size: 18.0,
-                color,
-            },
-            default(),
-        ),
+        text: Text {
+            sections: marked_up(assets, layout, &text, color),
+            alignment: default(),
+        },
         ..default()
     }
 }
+/// Text in the chat box, wrapped before it runs out of room
+fn chat_text(
+    assets: &Res<AssetHandles>,
+    layout: &UiLayout,
+    text: String,
+    color: Color,
+) -> TextBundle {
+    wrapped(colored_text(assets, layout, text, color), layout.chat_width)
+}
+
+/// Text only wraps when it has a width in pixels to stay within
+fn wrapped(bundle: TextBundle, width: f32) -> TextBundle {
+    TextBundle {
+        style: Style {
+            max_size: Size::new(Val::Px(width), Val::Auto),
+            ..bundle.style
+        },
+        ..bundle
+    }
+}
+
+/// There's only the bold font, so bold text stands out by size instead
+const BOLD_SCALE: f32 = 7.0 / 6.0;
+
+/// A section for each span of the markup, unmarked text in `color`
+pub(super) fn marked_up(
+    assets: &Res<AssetHandles>,
+    layout: &UiLayout,
+    text: &str,
+    color: Color,
+) -> Vec<TextSection> {
+    markup::parse(text)
+        .into_iter()
+        .map(|span| TextSection {
+            value: span.text,
+            style: TextStyle {
+                font: assets.font.clone(),
+                font_size: if span.bold {
+                    layout.font_size * BOLD_SCALE
+                } else {
+                    layout.font_size
+                },
+                color: span.tint.map_or(color, |tint| tint.color(&assets.colors)),
+            },
+        })
+        .collect()
+}
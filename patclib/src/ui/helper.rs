@@ -1,137 +1,483 @@
-use bevy::prelude::*;
+use bevy::{input::mouse::MouseWheel, prelude::*};
 
-use crate::{assets::AssetHandles, player::Player};
-
-use super::components::{
-    spawn_decision, spawn_highlighted_line, spawn_line, ActiveDecision, ChatBox,
+use crate::{
+    actions::{Actions, Bindings, InputAction},
+    assets::AssetHandles,
+    flow::AppState,
+    locale::t,
+    player::Player,
 };
 
-#[derive(Debug)]
-enum ChatEvent {
-    Line(String),
-    HighlightedLine(String),
-    Prompt {
-        prompt: String,
-        options: Vec<String>,
+use super::{
+    components::{
+        spawn_answered_decision, spawn_decision, spawn_faded_line, spawn_highlighted_line,
+        spawn_line, spawn_speech, ChatBox,
     },
-}
+    layout::UiLayout,
+    log::{ChatEntry, ChatLog},
+    Expression, Speaker, TextPacing,
+};
 
-const MAX_SPAWNED_EVENTS: i32 = 6;
+/// Entries PageUp and PageDown scroll by
+const SCROLL_PAGE: usize = 4;
+
+/// A prompt and its options, kept around in case it needs to be asked again
+type Prompt = (String, Vec<String>);
 
 #[derive(Debug, Default)]
 pub struct UIHelper {
-    to_spawn: Vec<ChatEvent>,
-    spawned: Vec<Entity>,
+    log: ChatLog,
+    /// Entities in the chat box right now
+    shown: Vec<Entity>,
+    /// How much of the log had been drawn the last time the chat box was
+    drawn: usize,
+    needs_redraw: bool,
+    /// Where in the log the prompt waiting for an answer is
+    active_prompt: Option<usize>,
     selected_option: Option<usize>,
     available_options: Option<usize>,
+    waiting_prompt: Option<Prompt>,
+    /// The last prompt answered, in case the next one follows up on it
+    answered: Option<Prompt>,
+    /// The prompts followed up on to get to the waiting one, so Back can walk up them
+    asked_before: Vec<Prompt>,
+    interrupted: Vec<(Option<Prompt>, Vec<Prompt>)>,
 }
 
 impl UIHelper {
     // Interface for public use
     pub fn show_line(&mut self, line: impl Into<String>) {
-        self.to_spawn.push(ChatEvent::Line(line.into()));
+        self.push(ChatEntry::Line(line.into()));
     }
     pub fn show_highlighted_line(&mut self, line: impl Into<String>) {
-        self.to_spawn.push(ChatEvent::HighlightedLine(line.into()));
+        self.push(ChatEntry::HighlightedLine(line.into()));
+    }
+    pub fn say(&mut self, speaker: Speaker, expression: Expression, line: impl Into<String>) {
+        self.push(ChatEntry::Speech {
+            speaker,
+            expression,
+            line: line.into(),
+        });
+    }
+
+    /// Whose portrait is up, and with what face
+    pub(super) fn speaking(&self) -> Option<(Speaker, Expression)> {
+        self.log.speaking()
+    }
+
+    /// Waits for the player to continue before typing out anything shown from now on
+    pub fn hold(&mut self) {
+        self.log.hold();
+    }
+
+    /// Whether everything shown so far is out for the player to read
+    pub fn is_caught_up(&self) -> bool {
+        self.log.is_caught_up()
     }
 
     pub fn prompt(&mut self, prompt: impl Into<String>, options: Vec<impl Into<String>>) {
+        self.asked_before.clear();
+        self.ask(prompt.into(), options);
+    }
+
+    /// Asks something that follows on from the prompt just answered, Back returns to that one
+    pub fn follow_up(&mut self, prompt: impl Into<String>, options: Vec<impl Into<String>>) {
+        if let Some(answered) = self.answered.take() {
+            self.asked_before.push(answered);
+        }
+        self.ask(prompt.into(), options);
+    }
+
+    /// Asks the prompt the waiting one followed up on again.
+    /// Returns false if there was nothing to go back to
+    pub fn back(&mut self) -> bool {
+        if let Some((prompt, options)) = self.asked_before.pop() {
+            self.ask(prompt, options);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn ask(&mut self, prompt: String, options: Vec<impl Into<String>>) {
+        let options: Vec<String> = options.into_iter().map(|option| option.into()).collect();
+        // Only one prompt can be answered at a time, the newest one wins
+        self.withdraw_prompt();
+        self.answered = None;
         self.available_options = Some(options.len());
         self.selected_option = Some(0);
-        self.to_spawn.push(ChatEvent::Prompt {
-            prompt: prompt.into(),
-            options: options.into_iter().map(|option| option.into()).collect(),
-        });
+        self.waiting_prompt = Some((prompt.clone(), options.clone()));
+        self.log.scroll_to_bottom();
+        self.active_prompt = Some(self.push(ChatEntry::Prompt {
+            prompt,
+            options,
+            answer: None,
+        }));
+    }
+
+    /// Sets aside the prompt waiting for an answer, if any, so another screen can ask its own
+    pub fn interrupt(&mut self) {
+        // It's asked again on resume, once is enough for the log
+        self.withdraw_prompt();
+        self.interrupted
+            .push((self.waiting_prompt.take(), self.asked_before.split_off(0)));
+        self.clear_decision();
+    }
+
+    /// Asks the prompt set aside by the matching `interrupt` again
+    pub fn resume(&mut self) {
+        if let Some((Some((prompt, options)), asked_before)) = self.interrupted.pop() {
+            self.ask(prompt, options);
+            self.asked_before = asked_before;
+        }
+    }
+
+    /// Takes the waiting prompt back out of the log if nothing was said after it,
+    /// so it doesn't stay there unanswered once something else is asked
+    fn withdraw_prompt(&mut self) {
+        let entries = self.log.entries().len();
+        if self.active_prompt.is_some_and(|index| index + 1 == entries) {
+            self.log.unpush();
+            self.drawn = self.drawn.min(self.log.entries().len());
+            self.needs_redraw = true;
+        }
+    }
+
+    fn push(&mut self, entry: ChatEntry) -> usize {
+        self.needs_redraw = true;
+        self.log.push(entry)
+    }
+
+    /// Whether the waiting prompt is out for the player to see and answer
+    fn takes_answers(&self, reading: bool) -> bool {
+        reading
+            && self
+                .active_prompt
+                .is_some_and(|index| self.log.is_revealed(index))
+    }
+
+    /// Answers the waiting prompt with `choice`
+    fn accept(&mut self, choice: usize) {
+        if let Some(index) = self.active_prompt {
+            self.log.answer(index, choice);
+        }
+        self.answered = self.waiting_prompt.take();
+        self.clear_decision();
     }
 
     fn clear_decision(&mut self) {
+        self.needs_redraw = true;
+        self.active_prompt = None;
         self.available_options = None;
         self.selected_option = None;
+        self.waiting_prompt = None;
     }
 
     // Interface for internal use
-    pub(super) fn new() -> Self {
-        let mut item = Self::default();
-        // Aight so hear me out
-        // At the moment, the system looks weird if there is a low number of items shown
-        // Flood it with a lot of empty items to make adding the second item look fine.
-        for _ in 0..10 {
-            item.show_line("");
+    pub(super) fn log(&self) -> &ChatLog {
+        &self.log
+    }
+
+    /// Forgets everything shown so far and returns what needs despawning.
+    /// Anything not drawn yet belongs to whatever comes next, so it stays
+    pub(super) fn clear(&mut self) -> Vec<Entity> {
+        let undrawn = self.log.split_off(self.drawn);
+        let shown = self.shown.drain(..).collect();
+        let mut fresh = Self::default();
+        if let Some(index) = self.active_prompt.filter(|index| *index >= self.drawn) {
+            fresh.active_prompt = Some(index - self.drawn);
+            fresh.selected_option = self.selected_option;
+            fresh.available_options = self.available_options;
+            fresh.waiting_prompt = self.waiting_prompt.take();
+            fresh.asked_before = self.asked_before.split_off(0);
         }
-        item
+        for entry in undrawn {
+            fresh.push(entry);
+        }
+        *self = fresh;
+        shown
     }
 }
 
+/// Scrolling through the chat, reading along and making decisions
+type ChatInputs<'w, 's> = (
+    Res<'w, Actions>,
+    Res<'w, Input<MouseButton>>,
+    EventReader<'w, 's, MouseWheel>,
+    Res<'w, State<AppState>>,
+);
+
+/// How fast the chat is typed out, what to call the continue key and how much fits
+type ChatLook<'w> = (
+    Res<'w, Time>,
+    Res<'w, TextPacing>,
+    Res<'w, Bindings>,
+    Res<'w, UiLayout>,
+);
+
 pub(super) fn update_helper(
     mut commands: Commands,
     assets: Res<AssetHandles>,
     chatbox_query: Query<Entity, With<ChatBox>>,
-    decision_query: Query<(Entity, &Children), With<ActiveDecision>>,
-    mut text_query: Query<&mut Text>,
     mut helper: ResMut<UIHelper>,
-    kb_inputs: Res<Input<KeyCode>>,
+    (actions, mouse_buttons, mut mouse_wheel, app_state): ChatInputs,
+    (time, pacing, bindings, layout): ChatLook,
     mut player: ResMut<Player>,
 ) {
-    let queue: Vec<ChatEvent> = helper.to_spawn.drain(..).collect();
-
-    commands
-        .entity(chatbox_query.single())
-        .with_children(|container| {
-            helper
-                .spawned
-                .extend(queue.into_iter().map(|spawnable| match spawnable {
-                    ChatEvent::Line(line) => spawn_line(container, &assets, line),
-                    ChatEvent::HighlightedLine(line) => {
-                        spawn_highlighted_line(container, &assets, line)
-                    }
-                    ChatEvent::Prompt { prompt, options } => {
-                        spawn_decision(container, &assets, prompt, options)
-                    }
-                }));
-        });
+    // Nothing gets answered from behind the journal or the map
+    let reading = !matches!(app_state.current(), AppState::Journal | AppState::Map);
+    let progress = (helper.log.typing(), helper.log.is_caught_up());
+    if reading && !helper.log.is_caught_up() {
+        // Confirm, or a click, hurries the text along instead of answering anything
+        if actions.just_pressed(InputAction::Confirm)
+            || mouse_buttons.just_pressed(MouseButton::Left)
+        {
+            if helper.log.is_held() {
+                helper.log.release();
+            } else {
+                helper.log.skip();
+            }
+            helper.needs_redraw = true;
+            return;
+        }
+    }
+    helper
+        .log
+        .type_out(time.delta_seconds() * pacing.chars_per_second);
+    if progress != (helper.log.typing(), helper.log.is_caught_up()) {
+        helper.needs_redraw = true;
+    }
 
-    let to_despawn = helper.spawned.len() as i32 - MAX_SPAWNED_EVENTS;
-    if to_despawn > 0 {
-        // Too many events
-        for entity in helper.spawned.drain(..to_despawn as usize) {
-            commands.entity(entity).despawn_recursive();
+    // The journal has its own use for scrolling
+    if reading {
+        let wheel: f32 = mouse_wheel.iter().map(|scroll| scroll.y).sum();
+        if actions.just_pressed(InputAction::ScrollUp) || wheel > 0.0 {
+            let by = if wheel > 0.0 { 1 } else { SCROLL_PAGE };
+            helper.log.scroll_up(by);
+            helper.needs_redraw = true;
+        }
+        if actions.just_pressed(InputAction::ScrollDown) || wheel < 0.0 {
+            let by = if wheel < 0.0 { 1 } else { SCROLL_PAGE };
+            helper.log.scroll_down(by);
+            helper.needs_redraw = true;
         }
     }
 
-    if let (Some(selected), Some(option_count), Ok((active_decision, options))) = (
+    // Prev and Next turn the journal's pages, nothing gets answered behind it
+    if let (Some(selected), Some(option_count), true) = (
         helper.selected_option,
         helper.available_options,
-        decision_query.get_single(),
+        helper.takes_answers(reading),
     ) {
-        let mut new_index = None;
         // A decision is happening
-        if kb_inputs.just_pressed(KeyCode::Left) {
+        let mut selection = selected;
+        if actions.just_pressed(InputAction::Prev) {
             // Select the option to the left
-            new_index = Some(0.max(selected as i32 - 1) as usize);
+            selection = selected.saturating_sub(1);
         }
-        if kb_inputs.just_pressed(KeyCode::Right) {
+        if actions.just_pressed(InputAction::Next) {
             // Select the option to the right
-            new_index = Some((option_count - 1).min(selected + 1));
+            selection = (option_count - 1).min(selected + 1);
         }
-
-        if let Some(index) = new_index {
-            text_query.get_mut(options[selected]).unwrap().sections[0]
-                .style
-                .color = assets.colors.basic_text;
-            text_query.get_mut(options[index]).unwrap().sections[0]
-                .style
-                .color = assets.colors.highlight_text;
-
-            helper.selected_option = Some(index);
+        if let Some(pointed) = actions.pointed().filter(|pointed| *pointed < option_count) {
+            selection = pointed;
+        }
+        if selection != selected {
+            helper.selected_option = Some(selection);
+            helper.needs_redraw = true;
         }
 
-        if kb_inputs.any_just_pressed(vec![KeyCode::Space, KeyCode::Return]) {
+        // Number keys and clicks pick an option outright
+        let chosen = actions.chosen().filter(|chosen| *chosen < option_count);
+        if chosen.is_some() || actions.just_pressed(InputAction::Confirm) {
             // Accept the choice
-            player.choose(selected);
-            helper.clear_decision();
+            let choice = chosen.unwrap_or(selection);
+            player.choose(choice);
+            helper.accept(choice);
+        }
+    }
+
+    if helper.needs_redraw || layout.is_changed() {
+        redraw(
+            &mut commands,
+            &assets,
+            (&bindings, &layout),
+            chatbox_query.single(),
+            &mut helper,
+        );
+    }
+}
+
+fn redraw(
+    commands: &mut Commands,
+    assets: &Res<AssetHandles>,
+    (bindings, layout): (&Bindings, &UiLayout),
+    chat_box: Entity,
+    helper: &mut ResMut<UIHelper>,
+) {
+    for entity in helper.shown.drain(..) {
+        commands.entity(entity).despawn_recursive();
+    }
+    let scrolled = helper.log.is_scrolled();
+    let held = helper.log.is_held();
+    // Leave a row to say there's more below, or to wait for the player
+    let rows = if scrolled || held {
+        layout.chat_rows - 1
+    } else {
+        layout.chat_rows
+    };
+    let visible = helper.log.visible(rows, layout.chars_per_row);
+    let typing = helper.log.typing();
 
-            // Previously active decision is no longer active
-            commands.entity(active_decision).remove::<ActiveDecision>();
+    let mut shown = vec![];
+    commands.entity(chat_box).with_children(|container| {
+        for index in visible {
+            let entry = match typing {
+                Some((typing, chars)) if typing == index => {
+                    helper.log.entries()[index].typed(chars)
+                }
+                _ => helper.log.entries()[index].clone(),
+            };
+            shown.push(match &entry {
+                ChatEntry::Line(line) => spawn_line(container, assets, layout, line.clone()),
+                ChatEntry::HighlightedLine(line) => {
+                    spawn_highlighted_line(container, assets, layout, line.clone())
+                }
+                ChatEntry::Speech { speaker, line, .. } => {
+                    spawn_speech(container, assets, layout, speaker.to_string(), line.clone())
+                }
+                ChatEntry::Prompt {
+                    prompt, options, ..
+                } if helper.active_prompt == Some(index) => spawn_decision(
+                    container,
+                    assets,
+                    layout,
+                    prompt.clone(),
+                    options.clone(),
+                    helper.selected_option.unwrap_or_default(),
+                ),
+                ChatEntry::Prompt {
+                    prompt,
+                    options,
+                    answer,
+                } => spawn_answered_decision(
+                    container,
+                    assets,
+                    layout,
+                    prompt.clone(),
+                    answer.map(|answer| options[answer].clone()),
+                ),
+            });
         }
+        if scrolled {
+            shown.push(spawn_faded_line(
+                container,
+                assets,
+                layout,
+                t!("chat-more-below"),
+            ));
+        } else if held {
+            let key = bindings
+                .bindings(InputAction::Confirm)
+                .first()
+                .map_or_else(|| t!("chat-click"), |key| key.to_string());
+            shown.push(spawn_faded_line(
+                container,
+                assets,
+                layout,
+                t!("chat-continue", key = key),
+            ));
+        }
+    });
+
+    helper.shown = shown;
+    helper.drawn = helper.log.entries().len();
+    helper.needs_redraw = false;
+}
+
+#[cfg(test)]
+mod test {
+    use super::UIHelper;
+
+    fn waiting(helper: &UIHelper) -> &str {
+        &helper.waiting_prompt.as_ref().unwrap().0
+    }
+
+    #[test]
+    fn walking_back_up_the_chain() {
+        let mut helper = UIHelper::default();
+        helper.prompt("Action", vec!["attack", "move"]);
+        assert!(!helper.back());
+        helper.accept(0);
+        helper.follow_up("Weapon", vec!["shotgun"]);
+        helper.accept(0);
+        helper.follow_up("Target", vec!["bandit"]);
+
+        assert!(helper.back());
+        assert_eq!(waiting(&helper), "Weapon");
+        assert!(helper.back());
+        assert_eq!(waiting(&helper), "Action");
+        assert!(!helper.back());
+        assert_eq!(helper.available_options, Some(2));
+    }
+
+    #[test]
+    fn a_fresh_prompt_starts_a_fresh_chain() {
+        let mut helper = UIHelper::default();
+        helper.prompt("Action", vec!["attack"]);
+        helper.accept(0);
+        helper.follow_up("Weapon", vec!["shotgun"]);
+        helper.accept(0);
+        helper.prompt("Action", vec!["attack"]);
+        assert!(!helper.back());
+    }
+
+    #[test]
+    fn no_answers_from_behind_the_journal() {
+        let mut helper = UIHelper::default();
+        helper.prompt("Action", vec!["attack"]);
+        assert!(!helper.takes_answers(true));
+        helper.log.skip();
+        assert!(helper.takes_answers(true));
+        assert!(!helper.takes_answers(false));
+    }
+
+    #[test]
+    fn asking_again_leaves_one_copy() {
+        let mut helper = UIHelper::default();
+        helper.prompt("Action", vec!["attack"]);
+        helper.prompt("Action", vec!["attack", "move"]);
+        assert_eq!(helper.log.entries().len(), 1);
+        helper.show_line("The bandit draws");
+        helper.prompt("Action", vec!["attack"]);
+        // Something was said in between, the first one stays where it was
+        assert_eq!(helper.log.entries().len(), 3);
+
+        helper.interrupt();
+        helper.prompt("Paused", vec!["Resume"]);
+        helper.accept(0);
+        helper.resume();
+        // The pause menu is in the log, the prompt it interrupted only once after it
+        assert_eq!(helper.log.entries().len(), 4);
+        assert_eq!(helper.log.entries()[2].journal_text(), "Paused > Resume");
+        assert_eq!(helper.active_prompt, Some(3));
+    }
+
+    #[test]
+    fn interruptions_keep_the_chain() {
+        let mut helper = UIHelper::default();
+        helper.prompt("Action", vec!["attack"]);
+        helper.accept(0);
+        helper.follow_up("Weapon", vec!["shotgun"]);
+
+        helper.interrupt();
+        helper.prompt("Paused", vec!["Resume"]);
+        assert!(!helper.back());
+        helper.accept(0);
+        helper.resume();
+
+        assert_eq!(waiting(&helper), "Weapon");
+        assert!(helper.back());
+        assert_eq!(waiting(&helper), "Action");
     }
 }
@@ -0,0 +1,88 @@
+use bevy::{input::mouse::MouseWheel, prelude::*};
+
+use crate::{
+    actions::{Actions, InputAction},
+    flow::FlowEvent,
+    locale::t,
+};
+
+use super::{
+    components::{JournalPanel, JournalText},
+    UIHelper,
+};
+
+const JOURNAL_LINES: usize = 30;
+
+/// Which page of the journal is open, 0 being the most recent
+#[derive(Debug, Default)]
+pub(super) struct JournalPage(usize);
+
+pub(super) fn open_journal(actions: Res<Actions>, mut flow: EventWriter<FlowEvent>) {
+    if actions.just_pressed(InputAction::Journal) {
+        // Dropped in the menus, there's no run to look back on there
+        flow.send(FlowEvent::JournalOpened);
+    }
+}
+
+pub(super) fn show_journal(
+    mut commands: Commands,
+    helper: Res<UIHelper>,
+    mut panel_query: Query<&mut Style, With<JournalPanel>>,
+    mut text_query: Query<&mut Text, With<JournalText>>,
+) {
+    commands.insert_resource(JournalPage::default());
+    panel_query.single_mut().display = Display::Flex;
+    text_query.single_mut().sections[0].value = render(&helper, 0);
+}
+
+pub(super) fn update_journal(
+    (actions, mut mouse_wheel): (Res<Actions>, EventReader<MouseWheel>),
+    mut flow: EventWriter<FlowEvent>,
+    helper: Res<UIHelper>,
+    mut page: ResMut<JournalPage>,
+    mut text_query: Query<&mut Text, With<JournalText>>,
+) {
+    if actions.just_pressed(InputAction::Journal)
+        || actions.just_pressed(InputAction::Pause)
+        || actions.just_pressed(InputAction::Back)
+    {
+        flow.send(FlowEvent::JournalClosed);
+        return;
+    }
+    let wheel: f32 = mouse_wheel.iter().map(|scroll| scroll.y).sum();
+    let pages = helper.log().journal_pages(JOURNAL_LINES);
+    // Prev and Next let a gamepad leaf through it too
+    let older =
+        actions.just_pressed(InputAction::ScrollUp) || actions.just_pressed(InputAction::Prev);
+    let newer =
+        actions.just_pressed(InputAction::ScrollDown) || actions.just_pressed(InputAction::Next);
+    let turned_to = if older || wheel > 0.0 {
+        (pages - 1).min(page.0 + 1)
+    } else if newer || wheel < 0.0 {
+        page.0.saturating_sub(1)
+    } else {
+        return;
+    };
+    page.0 = turned_to;
+    text_query.single_mut().sections[0].value = render(&helper, turned_to);
+}
+
+pub(super) fn hide_journal(
+    mut commands: Commands,
+    mut panel_query: Query<&mut Style, With<JournalPanel>>,
+) {
+    commands.remove_resource::<JournalPage>();
+    panel_query.single_mut().display = Display::None;
+}
+
+fn render(helper: &UIHelper, page: usize) -> String {
+    let log = helper.log();
+    let mut text = t!(
+        "journal-header",
+        page = log.journal_pages(JOURNAL_LINES) - page,
+        pages = log.journal_pages(JOURNAL_LINES)
+    );
+    text.push_str("\n\n");
+    text.push_str(&log.journal_page(page, JOURNAL_LINES).join("\n"));
+    text
+}
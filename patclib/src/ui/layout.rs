@@ -0,0 +1,130 @@
+use bevy::prelude::*;
+
+use super::components::{PanelText, TopBar};
+
+/// The window size the UI was drawn for, everything scales from there
+const BASE_WIDTH: f32 = 1280.0;
+const BASE_HEIGHT: f32 = 720.0;
+const BASE_FONT_SIZE: f32 = 18.0;
+
+/// Percent of the window the chat takes up at the bottom
+pub(super) const BOTTOM_BAR_HEIGHT: f32 = 40.0;
+/// Percent of the bottom bar each portrait takes up
+pub(super) const BOTTOM_BOX_PORTRAIT_WIDTH: f32 = 15.0;
+/// Space around each message in the chat box
+pub(super) const MESSAGE_MARGIN: f32 = 3.0;
+/// Room the journal leaves around its page
+pub(super) const JOURNAL_PADDING: f32 = 20.0;
+
+/// How wide a character is next to how tall, erring wide so lines are never longer than counted
+const CHAR_WIDTH: f32 = 0.6;
+/// Height of a row of chat next to the font size, spacing between messages included
+const ROW_HEIGHT: f32 = 2.0;
+
+/// Sizes worked out from the window. They're in logical pixels, so the DPI is already taken care of
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct UiLayout {
+    pub scale: f32,
+    pub font_size: f32,
+    pub top_bar_height: f32,
+    pub bottom_bar_height: f32,
+    /// How wide a line of chat gets before it wraps
+    pub chat_width: f32,
+    pub journal_width: f32,
+    pub chat_rows: usize,
+    /// Roughly how many characters fit in a row of chat
+    pub chars_per_row: usize,
+}
+impl Default for UiLayout {
+    fn default() -> Self {
+        Self::fit(BASE_WIDTH, BASE_HEIGHT)
+    }
+}
+impl UiLayout {
+    pub fn fit(width: f32, height: f32) -> Self {
+        // Whichever way the window is smaller decides, text has to fit both ways
+        let scale = (width / BASE_WIDTH)
+            .min(height / BASE_HEIGHT)
+            .clamp(0.5, 3.0);
+        let font_size = BASE_FONT_SIZE * scale;
+        let chat_width =
+            width * (100.0 - 2.0 * BOTTOM_BOX_PORTRAIT_WIDTH) / 100.0 - 2.0 * MESSAGE_MARGIN;
+        let bottom_bar_height = height * BOTTOM_BAR_HEIGHT / 100.0;
+        Self {
+            scale,
+            font_size,
+            top_bar_height: font_size * 1.6,
+            bottom_bar_height,
+            chat_width,
+            journal_width: width - 2.0 * JOURNAL_PADDING,
+            chat_rows: ((bottom_bar_height / (font_size * ROW_HEIGHT)) as usize).max(2),
+            chars_per_row: ((chat_width / (font_size * CHAR_WIDTH)) as usize).max(1),
+        }
+    }
+}
+
+/// Lays the UI out again whenever the window changes size or moves to a screen with another DPI
+pub(super) fn fit_layout(
+    windows: Option<Res<Windows>>,
+    mut layout: ResMut<UiLayout>,
+    mut texts: Query<&mut Text>,
+    mut top_bar: Query<&mut Style, (With<TopBar>, Without<PanelText>)>,
+    mut panels: Query<&mut Style, (With<PanelText>, Without<TopBar>)>,
+) {
+    let window = match windows.as_deref().and_then(|windows| windows.get_primary()) {
+        Some(window) => window,
+        None => return,
+    };
+    let fitted = UiLayout::fit(window.width(), window.height());
+    if fitted == *layout {
+        return;
+    }
+    let ratio = fitted.scale / layout.scale;
+    if ratio != 1.0 {
+        for mut text in texts.iter_mut() {
+            for section in text.sections.iter_mut() {
+                section.style.font_size *= ratio;
+            }
+        }
+    }
+    for mut style in top_bar.iter_mut() {
+        style.size.height = Val::Px(fitted.top_bar_height);
+    }
+    for mut style in panels.iter_mut() {
+        style.max_size.width = Val::Px(fitted.journal_width);
+    }
+    *layout = fitted;
+}
+
+#[cfg(test)]
+mod test {
+    use super::UiLayout;
+
+    #[test]
+    fn the_usual_window_looks_as_it_always_did() {
+        let layout = UiLayout::default();
+        assert_eq!(layout.font_size, 18.0);
+        assert_eq!(layout.chat_rows, 8);
+        assert!(layout.chars_per_row > 60);
+    }
+
+    #[test]
+    fn text_grows_with_the_window() {
+        let small = UiLayout::fit(800.0, 600.0);
+        let big = UiLayout::fit(2560.0, 1440.0);
+        assert!(small.font_size < UiLayout::default().font_size);
+        assert_eq!(big.font_size, 36.0);
+        // Twice as big both ways, the same text fits
+        assert_eq!(big.chat_rows, UiLayout::default().chat_rows);
+    }
+
+    #[test]
+    fn a_wide_window_fits_longer_lines() {
+        let wide = UiLayout::fit(2560.0, 720.0);
+        assert_eq!(wide.font_size, 18.0);
+        assert!(wide.chars_per_row > 2 * UiLayout::default().chars_per_row - 2);
+        // Even a sliver of a window gets something
+        let tiny = UiLayout::fit(10.0, 10.0);
+        assert!(tiny.chat_rows >= 2 && tiny.chars_per_row >= 1);
+    }
+}
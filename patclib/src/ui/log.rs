@@ -0,0 +1,488 @@
+use std::ops::Range;
+
+use super::{markup, Expression, Speaker};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub(super) enum ChatEntry {
+    Line(String),
+    HighlightedLine(String),
+    /// A line someone says, their name shown in front of it
+    Speech {
+        speaker: Speaker,
+        expression: Expression,
+        line: String,
+    },
+    Prompt {
+        prompt: String,
+        options: Vec<String>,
+        answer: Option<usize>,
+    },
+}
+impl ChatEntry {
+    /// Rows of text it takes up in the chat box, wrapped at `chars_per_row`
+    pub fn rows(&self, chars_per_row: usize) -> usize {
+        let rows = |text: &str| wrapped_rows(&markup::plain(text), chars_per_row);
+        match self {
+            ChatEntry::Line(line) | ChatEntry::HighlightedLine(line) => rows(line),
+            ChatEntry::Speech { speaker, line, .. } => rows(&format!("{}: {}", speaker, line)),
+            ChatEntry::Prompt {
+                prompt,
+                options,
+                answer: None,
+            } => rows(prompt) + rows(&options.join("   ")),
+            ChatEntry::Prompt {
+                prompt,
+                options,
+                answer: Some(answer),
+            } => rows(prompt) + rows(&format!("> {}", options[*answer])),
+        }
+    }
+
+    /// Characters the typewriter goes through, prompts show up all at once
+    fn typed_length(&self) -> usize {
+        match self {
+            ChatEntry::Line(line)
+            | ChatEntry::HighlightedLine(line)
+            | ChatEntry::Speech { line, .. } => markup::plain(line).chars().count(),
+            ChatEntry::Prompt { .. } => 0,
+        }
+    }
+
+    /// Only the first `chars` characters of it
+    pub fn typed(&self, chars: usize) -> ChatEntry {
+        let cut = |line: &String| markup::truncate(line, chars);
+        match self {
+            ChatEntry::Line(line) => ChatEntry::Line(cut(line)),
+            ChatEntry::HighlightedLine(line) => ChatEntry::HighlightedLine(cut(line)),
+            ChatEntry::Speech {
+                speaker,
+                expression,
+                line,
+            } => ChatEntry::Speech {
+                speaker: *speaker,
+                expression: *expression,
+                line: cut(line),
+            },
+            ChatEntry::Prompt { .. } => self.clone(),
+        }
+    }
+
+    /// How it reads looking back on it
+    pub fn journal_text(&self) -> String {
+        match self {
+            ChatEntry::Line(line) | ChatEntry::HighlightedLine(line) => markup::plain(line),
+            ChatEntry::Speech { speaker, line, .. } => {
+                format!("{}: {}", speaker, markup::plain(line))
+            }
+            ChatEntry::Prompt {
+                prompt,
+                options,
+                answer: Some(answer),
+            } => format!(
+                "{} > {}",
+                markup::plain(prompt),
+                markup::plain(&options[*answer])
+            ),
+            ChatEntry::Prompt { prompt, .. } => markup::plain(prompt),
+        }
+    }
+}
+
+/// Everything said during the run, how much of it has been typed out,
+/// and how far back the player has scrolled
+#[derive(Debug, Default)]
+pub(super) struct ChatLog {
+    entries: Vec<ChatEntry>,
+    /// Entries typed out in full
+    revealed: usize,
+    /// Characters typed of the entry after those
+    typed: f32,
+    /// Nothing from this entry on is typed until the player says to continue
+    hold: Option<usize>,
+    /// Entries hidden below the bottom of the chat box
+    scroll: usize,
+}
+impl ChatLog {
+    pub fn entries(&self) -> &[ChatEntry] {
+        &self.entries
+    }
+
+    /// Returns the index of the new entry, it shows up once the typewriter gets to it
+    pub fn push(&mut self, entry: ChatEntry) -> usize {
+        self.entries.push(entry);
+        self.entries.len() - 1
+    }
+
+    /// Takes back the last entry as if it was never pushed
+    pub fn unpush(&mut self) -> Option<ChatEntry> {
+        let shown = self.shown();
+        let entry = self.entries.pop()?;
+        let length = self.entries.len();
+        if self.revealed >= length {
+            self.revealed = length;
+            self.typed = 0.0;
+        }
+        self.hold = self.hold.map(|hold| hold.min(length));
+        if self.scroll > 0 && shown > length {
+            self.scroll -= 1;
+        }
+        Some(entry)
+    }
+
+    /// Entries showing at least in part
+    fn shown(&self) -> usize {
+        if self.typed > 0.0 {
+            self.revealed + 1
+        } else {
+            self.revealed
+        }
+    }
+
+    /// Types out `chars` more characters, stopping at a hold.
+    /// Someone scrolled back stays looking at the same thing
+    pub fn type_out(&mut self, mut chars: f32) {
+        let shown = self.shown();
+        while self.revealed < self.entries.len() && self.hold != Some(self.revealed) {
+            let length = self.entries[self.revealed].typed_length() as f32;
+            if self.typed + chars < length {
+                self.typed += chars;
+                break;
+            }
+            chars -= length - self.typed;
+            self.typed = 0.0;
+            self.revealed += 1;
+        }
+        if self.scroll > 0 {
+            self.scroll += self.shown() - shown;
+        }
+    }
+
+    /// Types out everything up to the next hold
+    pub fn skip(&mut self) {
+        self.type_out(f32::INFINITY);
+    }
+
+    /// Waits for the player before typing anything pushed from now on
+    pub fn hold(&mut self) {
+        self.hold = Some(self.entries.len());
+    }
+
+    /// Whether everything before the hold is out and the player is being waited on
+    pub fn is_held(&self) -> bool {
+        self.hold == Some(self.revealed)
+    }
+
+    pub fn release(&mut self) {
+        self.hold = None;
+    }
+
+    /// Everything is typed out and nobody is waiting on the player to continue
+    pub fn is_caught_up(&self) -> bool {
+        self.revealed == self.entries.len() && self.hold.is_none()
+    }
+
+    pub fn is_revealed(&self, index: usize) -> bool {
+        index < self.revealed
+    }
+
+    /// The entry being typed and how many of its characters are out
+    pub fn typing(&self) -> Option<(usize, usize)> {
+        (self.typed > 0.0).then_some((self.revealed, self.typed as usize))
+    }
+
+    /// Who's talking, from the last thing said up until the narration takes over again
+    pub fn speaking(&self) -> Option<(Speaker, Expression)> {
+        self.entries[..self.shown()]
+            .iter()
+            .rev()
+            .find_map(|entry| match entry {
+                ChatEntry::Speech {
+                    speaker,
+                    expression,
+                    ..
+                } => Some(Some((*speaker, *expression))),
+                ChatEntry::Line(_) | ChatEntry::HighlightedLine(_) => Some(None),
+                ChatEntry::Prompt { .. } => None,
+            })
+            .flatten()
+    }
+
+    /// Returns false if there was no unanswered prompt there
+    pub fn answer(&mut self, index: usize, choice: usize) -> bool {
+        match self.entries.get_mut(index) {
+            Some(ChatEntry::Prompt { answer, .. }) if answer.is_none() => {
+                *answer = Some(choice);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Drops everything from `index` onwards and returns it
+    pub fn split_off(&mut self, index: usize) -> Vec<ChatEntry> {
+        self.scroll = 0;
+        self.hold = None;
+        if self.revealed >= index {
+            self.revealed = index;
+            self.typed = 0.0;
+        }
+        self.entries.split_off(index.min(self.entries.len()))
+    }
+
+    pub fn scroll_up(&mut self, by: usize) {
+        self.scroll = (self.scroll + by).min(self.shown().saturating_sub(1));
+    }
+
+    pub fn scroll_down(&mut self, by: usize) {
+        self.scroll = self.scroll.saturating_sub(by);
+    }
+
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll = 0;
+    }
+
+    pub fn is_scrolled(&self) -> bool {
+        self.scroll > 0
+    }
+
+    /// The newest entries showing that fit in `rows`, going back as far as the player scrolled.
+    /// The newest one always shows, even if it's too long to fit
+    pub fn visible(&self, rows: usize, chars_per_row: usize) -> Range<usize> {
+        let end = self.shown() - self.scroll;
+        let mut start = end;
+        let mut used = 0;
+        while start > 0 {
+            let needed = self.entries[start - 1].rows(chars_per_row);
+            if start < end && used + needed > rows {
+                break;
+            }
+            start -= 1;
+            used += needed;
+        }
+        start..end
+    }
+
+    /// How many journal pages the run fills, at least one even if nothing has happened
+    pub fn journal_pages(&self, lines_per_page: usize) -> usize {
+        self.shown().div_ceil(lines_per_page).max(1)
+    }
+
+    /// A page of the journal, oldest line first. Page 0 is the most recent one
+    pub fn journal_page(&self, page: usize, lines_per_page: usize) -> Vec<String> {
+        let end = self.shown().saturating_sub(page * lines_per_page);
+        let start = end.saturating_sub(lines_per_page);
+        self.entries[start..end]
+            .iter()
+            .map(|entry| entry.journal_text())
+            .collect()
+    }
+}
+
+/// Rows `text` takes wrapped a word at a time, words longer than a row get broken up
+fn wrapped_rows(text: &str, chars_per_row: usize) -> usize {
+    let chars_per_row = chars_per_row.max(1);
+    let mut rows = 1;
+    let mut used = 0;
+    for word in text.split_whitespace() {
+        let length = word.chars().count();
+        if used > 0 && used + 1 + length <= chars_per_row {
+            used += 1 + length;
+            continue;
+        }
+        if used > 0 {
+            rows += 1;
+        }
+        // Whatever doesn't fit in a row spills over onto the next ones
+        rows += (length.max(1) - 1) / chars_per_row;
+        used = (length.max(1) - 1) % chars_per_row + 1;
+    }
+    rows
+}
+
+#[cfg(test)]
+mod test {
+    use super::{wrapped_rows, ChatEntry, ChatLog};
+    use crate::ui::{Expression, Speaker};
+
+    fn line(text: &str) -> ChatEntry {
+        ChatEntry::Line(text.to_string())
+    }
+
+    fn prompt() -> ChatEntry {
+        ChatEntry::Prompt {
+            prompt: "Which way?".to_string(),
+            options: vec!["Left".to_string(), "Right".to_string()],
+            answer: None,
+        }
+    }
+
+    fn log_of(count: usize) -> ChatLog {
+        let mut log = ChatLog::default();
+        for i in 0..count {
+            log.push(line(&i.to_string()));
+        }
+        log.skip();
+        log
+    }
+
+    #[test]
+    fn shows_what_fits() {
+        let mut log = log_of(10);
+        assert_eq!(log.visible(4, 80), 6..10);
+        log.push(prompt());
+        log.skip();
+        // The prompt takes two rows
+        assert_eq!(log.visible(4, 80), 8..11);
+        assert_eq!(log_of(2).visible(4, 80), 0..2);
+    }
+
+    #[test]
+    fn scrolling_back() {
+        let mut log = log_of(10);
+        log.scroll_up(3);
+        assert_eq!(log.visible(4, 80), 3..7);
+        // Reading old stuff isn't interrupted by new stuff
+        log.push(line("new"));
+        log.skip();
+        assert_eq!(log.visible(4, 80), 3..7);
+        log.scroll_up(100);
+        assert_eq!(log.visible(4, 80), 0..1);
+        log.scroll_down(100);
+        assert!(!log.is_scrolled());
+        assert_eq!(log.visible(4, 80), 7..11);
+    }
+
+    #[test]
+    fn answers_are_remembered() {
+        let mut log = log_of(1);
+        let index = log.push(prompt());
+        assert!(!log.answer(0, 0));
+        assert!(log.answer(index, 1));
+        assert!(!log.answer(index, 0));
+        assert_eq!(log.entries()[index].journal_text(), "Which way? > Right");
+    }
+
+    #[test]
+    fn taking_back_the_last_entry() {
+        let mut log = log_of(3);
+        log.push(prompt());
+        log.skip();
+        log.scroll_up(1);
+        assert!(matches!(log.unpush(), Some(ChatEntry::Prompt { .. })));
+        assert!(log.is_caught_up());
+        // Only the prompt was scrolled out of sight
+        assert!(!log.is_scrolled());
+        assert_eq!(log.visible(4, 80), 0..3);
+        log.push(line("new"));
+        assert!(!log.is_caught_up());
+    }
+
+    #[test]
+    fn journal_pages() {
+        let log = log_of(10);
+        assert_eq!(log.journal_pages(4), 3);
+        assert_eq!(log.journal_page(0, 4), vec!["6", "7", "8", "9"]);
+        assert_eq!(log.journal_page(2, 4), vec!["0", "1"]);
+        assert!(log.journal_page(3, 4).is_empty());
+        assert_eq!(ChatLog::default().journal_pages(4), 1);
+    }
+
+    #[test]
+    fn typing_out() {
+        let mut log = ChatLog::default();
+        log.push(line("Howdy"));
+        log.push(prompt());
+        assert_eq!(log.visible(4, 80), 0..0);
+        log.type_out(2.5);
+        assert_eq!(log.typing(), Some((0, 2)));
+        assert_eq!(log.visible(4, 80), 0..1);
+        assert_eq!(log.entries()[0].typed(2), line("Ho"));
+        // The prompt comes straight after the line, all at once
+        log.type_out(3.0);
+        assert!(log.is_revealed(1));
+        assert!(log.is_caught_up());
+        assert_eq!(log.typing(), None);
+    }
+
+    #[test]
+    fn holding_for_the_player() {
+        let mut log = log_of(1);
+        log.hold();
+        log.push(line("Wait for it"));
+        log.skip();
+        assert!(log.is_held());
+        assert!(!log.is_caught_up());
+        assert_eq!(log.visible(4, 80), 0..1);
+        log.release();
+        log.skip();
+        assert!(log.is_caught_up());
+        assert_eq!(log.visible(4, 80), 0..2);
+    }
+
+    #[test]
+    fn speech_reads_with_the_speaker() {
+        let speech = ChatEntry::Speech {
+            speaker: Speaker::Stranger,
+            expression: Expression::Neutral,
+            line: "Pardner".to_string(),
+        };
+        assert_eq!(speech.journal_text(), "Stranger: Pardner");
+        assert_eq!(
+            speech.typed(4),
+            ChatEntry::Speech {
+                speaker: Speaker::Stranger,
+                expression: Expression::Neutral,
+                line: "Pard".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn portraits_follow_whoever_talks() {
+        let say = |speaker, expression| ChatEntry::Speech {
+            speaker,
+            expression,
+            line: "Howdy".to_string(),
+        };
+        let mut log = log_of(1);
+        assert_eq!(log.speaking(), None);
+
+        log.push(say(Speaker::Merchant, Expression::Pleased));
+        log.push(prompt());
+        log.skip();
+        // Still waiting on an answer to what they asked
+        assert_eq!(
+            log.speaking(),
+            Some((Speaker::Merchant, Expression::Pleased))
+        );
+
+        // Not until the line has started typing
+        log.push(say(Speaker::Dog, Expression::Worried));
+        assert_eq!(
+            log.speaking(),
+            Some((Speaker::Merchant, Expression::Pleased))
+        );
+        log.type_out(1.0);
+        assert_eq!(log.speaking(), Some((Speaker::Dog, Expression::Worried)));
+
+        log.push(line("The road goes on"));
+        log.skip();
+        assert_eq!(log.speaking(), None);
+    }
+
+    #[test]
+    fn long_lines_wrap() {
+        assert_eq!(wrapped_rows("", 10), 1);
+        assert_eq!(wrapped_rows("a fine day", 10), 1);
+        assert_eq!(wrapped_rows("a fine day out", 10), 2);
+        assert_eq!(wrapped_rows("tumbleweeds", 5), 3);
+        assert_eq!(wrapped_rows("go tumbleweeds", 5), 4);
+
+        let mut log = log_of(3);
+        log.push(line("a line long enough to take up three rows"));
+        log.skip();
+        assert_eq!(log.visible(4, 15), 2..4);
+        // Too long to fit at all, it still shows
+        assert_eq!(log.visible(2, 10), 3..4);
+    }
+}
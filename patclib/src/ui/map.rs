@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+
+use crate::{
+    actions::{Actions, InputAction},
+    flow::FlowEvent,
+    locale::t,
+    travel::RouteMap,
+};
+
+use super::components::{MapPanel, MapText};
+
+pub(super) fn open_map(actions: Res<Actions>, mut flow: EventWriter<FlowEvent>) {
+    if actions.just_pressed(InputAction::Map) {
+        // Dropped anywhere but out on the road
+        flow.send(FlowEvent::MapOpened);
+    }
+}
+
+pub(super) fn show_map(
+    route: Res<RouteMap>,
+    mut panel_query: Query<&mut Style, With<MapPanel>>,
+    mut text_query: Query<&mut Text, With<MapText>>,
+) {
+    panel_query.single_mut().display = Display::Flex;
+    let mut text = t!("map-header");
+    text.push_str("\n\n");
+    text.push_str(&route.render().join("\n"));
+    text_query.single_mut().sections[0].value = text;
+}
+
+pub(super) fn update_map(actions: Res<Actions>, mut flow: EventWriter<FlowEvent>) {
+    if actions.just_pressed(InputAction::Map)
+        || actions.just_pressed(InputAction::Pause)
+        || actions.just_pressed(InputAction::Back)
+    {
+        flow.send(FlowEvent::MapClosed);
+    }
+}
+
+pub(super) fn hide_map(mut panel_query: Query<&mut Style, With<MapPanel>>) {
+    panel_query.single_mut().display = Display::None;
+}
@@ -0,0 +1,278 @@
+use bevy::prelude::Color;
+
+use crate::assets::Colors;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Tint {
+    Gold,
+    Red,
+    Gray,
+    Green,
+    Blue,
+}
+impl Tint {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "gold" => Some(Tint::Gold),
+            "red" => Some(Tint::Red),
+            "gray" => Some(Tint::Gray),
+            "green" => Some(Tint::Green),
+            "blue" => Some(Tint::Blue),
+            _ => None,
+        }
+    }
+
+    pub fn color(self, colors: &Colors) -> Color {
+        match self {
+            Tint::Gold => colors.highlight_text,
+            Tint::Red => colors.crucial_text,
+            Tint::Gray => colors.faded_text,
+            Tint::Green => colors.green_text,
+            Tint::Blue => colors.blue_text,
+        }
+    }
+}
+
+/// A resource drawn as a single coloured character
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+enum Icon {
+    Money,
+    Bullet,
+    Battery,
+    Stamina,
+}
+impl Icon {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "money" => Some(Icon::Money),
+            "bullet" => Some(Icon::Bullet),
+            "battery" => Some(Icon::Battery),
+            "stamina" => Some(Icon::Stamina),
+            _ => None,
+        }
+    }
+
+    /// Only what the font has a glyph for
+    fn glyph(self) -> char {
+        match self {
+            Icon::Money => '¤',
+            Icon::Bullet => '•',
+            Icon::Battery => '◊',
+            Icon::Stamina => '+',
+        }
+    }
+
+    fn tint(self) -> Tint {
+        match self {
+            Icon::Money => Tint::Gold,
+            Icon::Bullet => Tint::Gray,
+            Icon::Battery => Tint::Blue,
+            Icon::Stamina => Tint::Green,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Tag {
+    Tint(Tint),
+    Bold,
+}
+impl Tag {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "b" => Some(Tag::Bold),
+            _ => Tint::from_name(name).map(Tag::Tint),
+        }
+    }
+}
+
+/// A piece of the source text, along with the text itself
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Token<'a> {
+    /// One character that shows up as it is
+    Char(&'a str),
+    Icon(Icon, &'a str),
+    Open(Tag, &'a str),
+    Close(Tag, &'a str),
+}
+impl<'a> Token<'a> {
+    fn source(self) -> &'a str {
+        match self {
+            Token::Char(source)
+            | Token::Icon(_, source)
+            | Token::Open(_, source)
+            | Token::Close(_, source) => source,
+        }
+    }
+
+    fn is_visible(self) -> bool {
+        matches!(self, Token::Char(_) | Token::Icon(..))
+    }
+}
+
+fn tokens(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = text;
+    while let Some(first) = rest.chars().next() {
+        let special = match first {
+            '[' => rest.find(']').and_then(|end| {
+                let name = &rest[1..end];
+                let token = match name.strip_prefix('/') {
+                    Some(name) => Tag::from_name(name).map(|tag| Token::Close(tag, &rest[..=end])),
+                    None => Tag::from_name(name).map(|tag| Token::Open(tag, &rest[..=end])),
+                };
+                token.map(|token| (token, end + 1))
+            }),
+            '{' => rest.find('}').and_then(|end| {
+                Icon::from_name(&rest[1..end])
+                    .map(|icon| (Token::Icon(icon, &rest[..=end]), end + 1))
+            }),
+            _ => None,
+        };
+        let (token, length) = special.unwrap_or_else(|| {
+            let length = first.len_utf8();
+            (Token::Char(&rest[..length]), length)
+        });
+        tokens.push(token);
+        rest = &rest[length..];
+    }
+    tokens
+}
+
+/// A run of text that looks the same all the way through
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Span {
+    pub text: String,
+    /// None keeps whatever colour the line has
+    pub tint: Option<Tint>,
+    pub bold: bool,
+}
+
+/// Splits marked up text into spans, always at least one even if there's no text.
+/// `[gold]10 dice[/gold]` colours, `[b]` bolds and `{bullet}` is an icon,
+/// anything that isn't a known tag or icon is left as it is
+pub fn parse(text: &str) -> Vec<Span> {
+    let mut spans: Vec<Span> = vec![];
+    let mut tints = vec![];
+    let mut bold = 0;
+    for token in tokens(text) {
+        let (piece, tint) = match token {
+            Token::Char(piece) => (piece.to_string(), tints.last().copied()),
+            Token::Icon(icon, _) => (icon.glyph().to_string(), Some(icon.tint())),
+            Token::Open(Tag::Tint(tint), _) => {
+                tints.push(tint);
+                continue;
+            }
+            Token::Close(Tag::Tint(tint), _) => {
+                if let Some(index) = tints.iter().rposition(|open| *open == tint) {
+                    tints.remove(index);
+                }
+                continue;
+            }
+            Token::Open(Tag::Bold, _) => {
+                bold += 1;
+                continue;
+            }
+            Token::Close(Tag::Bold, _) => {
+                bold = (bold - 1).max(0);
+                continue;
+            }
+        };
+        let bold = bold > 0;
+        match spans.last_mut() {
+            Some(last) if last.tint == tint && last.bold == bold => last.text.push_str(&piece),
+            _ => spans.push(Span {
+                text: piece,
+                tint,
+                bold,
+            }),
+        }
+    }
+    if spans.is_empty() {
+        spans.push(Span {
+            text: String::new(),
+            tint: None,
+            bold: false,
+        });
+    }
+    spans
+}
+
+/// The text as it reads with the markup taken out
+pub fn plain(text: &str) -> String {
+    parse(text).into_iter().map(|span| span.text).collect()
+}
+
+/// The first `chars` characters that show, markup and all
+pub fn truncate(text: &str, chars: usize) -> String {
+    let mut shown = 0;
+    let mut cut = String::new();
+    for token in tokens(text) {
+        if token.is_visible() {
+            if shown == chars {
+                break;
+            }
+            shown += 1;
+        }
+        cut.push_str(token.source());
+    }
+    cut
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse, plain, truncate, Span, Tint};
+
+    fn span(text: &str, tint: Option<Tint>, bold: bool) -> Span {
+        Span {
+            text: text.to_string(),
+            tint,
+            bold,
+        }
+    }
+
+    #[test]
+    fn tags_split_the_line() {
+        assert_eq!(
+            parse("You find [gold]10 dice[/gold] and [b]a [red]gun[/red][/b]"),
+            vec![
+                span("You find ", None, false),
+                span("10 dice", Some(Tint::Gold), false),
+                span(" and ", None, false),
+                span("a ", None, true),
+                span("gun", Some(Tint::Red), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn icons_bring_their_own_colour() {
+        assert_eq!(
+            parse("3{bullet}"),
+            vec![span("3", None, false), span("•", Some(Tint::Gray), false)]
+        );
+        assert_eq!(plain("{money} 5"), "¤ 5");
+    }
+
+    #[test]
+    fn anything_unknown_is_left_alone() {
+        let text = "[Pardner] {tips} [/hat] [gold";
+        assert_eq!(parse(text), vec![span(text, None, false)]);
+        assert_eq!(parse(""), vec![span("", None, false)]);
+        // An unclosed tag runs to the end of the line
+        assert_eq!(
+            parse("[blue]cold"),
+            vec![span("cold", Some(Tint::Blue), false)]
+        );
+    }
+
+    #[test]
+    fn typing_out_skips_over_the_markup() {
+        let text = "A [gold]10[/gold] {money} bet";
+        assert_eq!(plain(text).chars().count(), 10);
+        assert_eq!(truncate(text, 3), "A [gold]1");
+        assert_eq!(truncate(text, 6), "A [gold]10[/gold] {money}");
+        assert_eq!(truncate(text, 100), text);
+        assert_eq!(truncate("Ünïcode", 2), "Ün");
+    }
+}
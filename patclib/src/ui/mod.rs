@@ -2,44 +2,163 @@ use bevy::prelude::*;
 
 mod components;
 mod helper;
+mod journal;
+mod layout;
+mod log;
+mod map;
+mod markup;
+mod speaker;
+mod stats;
 mod utils;
 
 pub use helper::UIHelper;
+pub use layout::UiLayout;
+pub use speaker::{Expression, Speaker};
 
-use crate::{assets::AssetHandles, player::Player};
+use crate::{
+    assets::AssetHandles,
+    flow::AppState,
+    locale::{t, Language},
+    player::Player,
+    travel::{travel_status, Clock, NextEncounter, RouteMap},
+};
 
-use self::components::{spawn_gui, BatteryText, BulletText, MoneyText, StaminaText};
+use self::components::{portrait, spawn_gui, DogText, JourneyText, Portrait};
+
+/// How the chat is paced. Insert one before the plugins to override the defaults
+#[derive(Debug, Copy, Clone)]
+pub struct TextPacing {
+    /// Characters typed out a second, infinity shows everything at once
+    pub chars_per_second: f32,
+    /// Whether encounters wait for the player to continue after each line
+    pub wait_for_continue: bool,
+}
+impl Default for TextPacing {
+    fn default() -> Self {
+        Self {
+            chars_per_second: 60.0,
+            wait_for_continue: false,
+        }
+    }
+}
 
 pub struct UIPlugin;
 
 impl Plugin for UIPlugin {
     fn build(&self, app: &mut App) {
-        app.add_startup_system(init)
+        app.init_resource::<TextPacing>()
+            .init_resource::<Language>()
+            .init_resource::<UiLayout>()
+            .add_system_to_stage(CoreStage::PreUpdate, layout::fit_layout)
+            .add_startup_system(init)
             .add_system(update_top)
-            .add_system(helper::update_helper);
+            .add_system(stats::update_stats)
+            .add_system(stats::float_deltas)
+            .add_system(stats::show_tooltips)
+            .add_system(helper::update_helper)
+            .add_system(update_portraits.after(helper::update_helper))
+            .add_system(journal::open_journal)
+            .add_system_set(
+                SystemSet::on_enter(AppState::Journal).with_system(journal::show_journal),
+            )
+            .add_system_set(
+                SystemSet::on_update(AppState::Journal).with_system(journal::update_journal),
+            )
+            .add_system_set(
+                SystemSet::on_exit(AppState::Journal).with_system(journal::hide_journal),
+            )
+            .add_system(map::open_map)
+            .add_system_set(SystemSet::on_enter(AppState::Map).with_system(map::show_map))
+            .add_system_set(SystemSet::on_update(AppState::Map).with_system(map::update_map))
+            .add_system_set(SystemSet::on_exit(AppState::Map).with_system(map::hide_map));
     }
 }
 
-fn init(mut commands: Commands, assets: Res<AssetHandles>) {
-    commands.insert_resource(UIHelper::new());
+/// Wipes the chat clean for a new run
+pub(crate) fn reset(world: &mut World) {
+    let spawned = match world.get_resource_mut::<UIHelper>() {
+        Some(mut helper) => helper.clear(),
+        None => return,
+    };
+    for entity in spawned {
+        if let Some(entity) = world.get_entity_mut(entity) {
+            entity.despawn_recursive();
+        }
+    }
+}
+
+fn init(mut commands: Commands, assets: Res<AssetHandles>, layout: Res<UiLayout>) {
+    commands.insert_resource(UIHelper::default());
     commands.spawn_bundle(UiCameraBundle::default());
     commands.spawn_bundle(OrthographicCameraBundle::new_2d());
-    spawn_gui(&mut commands, assets);
+    spawn_gui(&mut commands, assets, &layout);
 }
 
+/// The dog and the journey, the player's resources have their own stats
 fn update_top(
     player: Res<Player>,
+    (clock, route, next_encounter): (Res<Clock>, Res<RouteMap>, Option<Res<NextEncounter>>),
     mut queries: ParamSet<(
-        Query<&mut Text, With<StaminaText>>,
-        Query<&mut Text, With<MoneyText>>,
-        Query<&mut Text, With<BulletText>>,
-        Query<&mut Text, With<BatteryText>>,
+        Query<&mut Text, With<DogText>>,
+        Query<&mut Text, With<JourneyText>>,
     )>,
+    language: Res<Language>,
+) {
+    // Switching languages relabels everything
+    let relabel = language.is_changed();
+    if player.is_changed() || relabel {
+        let dog = t!(
+            "top-dog",
+            health = player.dog.health,
+            trust = player.dog.trust
+        );
+        set_text(&mut queries.p0().single_mut(), dog);
+    }
+    let journey_changed = relabel
+        || clock.is_changed()
+        || route.is_changed()
+        || next_encounter
+            .as_ref()
+            .is_some_and(|next| next.is_changed());
+    if journey_changed {
+        let journey = travel_status(&clock, &route, next_encounter.as_deref());
+        set_text(&mut queries.p1().single_mut(), journey);
+    }
+}
+
+/// Only touches the text if it reads differently, so nothing is laid out again for nothing
+fn set_text(text: &mut Text, value: String) {
+    if text.sections[0].value != value {
+        text.sections[0].value = value;
+    }
+}
+
+/// Puts up the face of whoever is talking on their side of the chat box
+fn update_portraits(
+    helper: Res<UIHelper>,
+    assets: Res<AssetHandles>,
+    mut portraits: Query<(&Portrait, &mut Style, &mut UiImage, &mut UiColor)>,
 ) {
-    queries.p0().single_mut().sections[0].value = format!("Stamina: {}", player.resources.stamina);
-    queries.p1().single_mut().sections[0].value =
-        format!("Money: {}", player.resources.money.as_string());
-    queries.p2().single_mut().sections[0].value = format!("Bullets: {}", player.resources.bullets);
-    queries.p3().single_mut().sections[0].value =
-        format!("Batteries: {}", player.resources.batteries.as_string());
+    let speaking = helper.speaking();
+    for (Portrait(side), mut style, mut image, mut color) in portraits.iter_mut() {
+        let (display, shown) = match speaking {
+            Some((speaker, expression)) if speaker.side() == *side => (
+                Display::Flex,
+                Some((portrait(&assets.images, speaker), expression.tint())),
+            ),
+            _ => (Display::None, None),
+        };
+        // Only touched when it changes, so the layout isn't redone every frame
+        if style.display != display {
+            style.display = display;
+        }
+        if let Some((handle, tint)) = shown {
+            if image.0 != handle {
+                image.0 = handle;
+            }
+            if color.0 != tint {
+                color.0 = tint;
+            }
+        }
+    }
 }
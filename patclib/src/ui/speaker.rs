@@ -0,0 +1,65 @@
+use core::fmt;
+
+use bevy::prelude::Color;
+
+use crate::locale::tr;
+
+/// Someone on the road with something to say
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Speaker {
+    Cowboy,
+    Dog,
+    Merchant,
+    Dandy,
+    /// Whoever it is that shows up once it's all over
+    Stranger,
+}
+impl fmt::Display for Speaker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let id = match self {
+            Speaker::Cowboy => "speaker-cowboy",
+            Speaker::Dog => "speaker-dog",
+            Speaker::Merchant => "speaker-merchant",
+            Speaker::Dandy => "speaker-dandy",
+            Speaker::Stranger => "speaker-stranger",
+        };
+        write!(f, "{}", tr(id))
+    }
+}
+impl Speaker {
+    /// The player and their dog stand on the left, everyone they meet on the right
+    pub fn side(self) -> Side {
+        match self {
+            Speaker::Cowboy | Speaker::Dog => Side::Left,
+            Speaker::Merchant | Speaker::Dandy | Speaker::Stranger => Side::Right,
+        }
+    }
+}
+
+/// How the speaker looks while saying it
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub enum Expression {
+    #[default]
+    Neutral,
+    Pleased,
+    Stern,
+    Worried,
+}
+impl Expression {
+    /// Portraits only come in one face for now, expressions tint it
+    pub fn tint(self) -> Color {
+        match self {
+            Expression::Neutral => Color::WHITE,
+            Expression::Pleased => Color::rgb(1.0, 0.95, 0.75),
+            Expression::Stern => Color::rgb(1.0, 0.7, 0.7),
+            Expression::Worried => Color::rgb(0.75, 0.8, 1.0),
+        }
+    }
+}
+
+/// Which side of the chat box a portrait goes on
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Side {
+    Left,
+    Right,
+}
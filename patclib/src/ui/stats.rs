@@ -0,0 +1,378 @@
+use bevy::prelude::*;
+
+use crate::{
+    assets::AssetHandles,
+    dice_value::DiceValue,
+    locale::{t, tr, Language},
+    player::{Player, PlayerResources},
+};
+
+use super::{components::marked_up, UiLayout};
+
+/// Seconds a change floats below the top bar before it's gone
+const DELTA_LIFETIME: f32 = 1.5;
+/// How far it drifts down over that time, at a scale of 1
+const DELTA_DRIFT: f32 = 30.0;
+
+/// Running this low turns the number red
+const LOW_STAMINA_SHARE: i32 = 4;
+const LOW_MONEY: u32 = 6;
+const LOW_BULLETS: i32 = 2;
+
+/// One of the player's resources on the top bar
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Stat {
+    Stamina,
+    Money,
+    Bullets,
+    Batteries,
+}
+impl Stat {
+    pub const ALL: [Stat; 4] = [Stat::Stamina, Stat::Money, Stat::Bullets, Stat::Batteries];
+
+    /// Message id of the name on the top bar
+    fn label(self) -> &'static str {
+        match self {
+            Stat::Stamina => "stat-stamina",
+            Stat::Money => "stat-money",
+            Stat::Bullets => "stat-bullets",
+            Stat::Batteries => "stat-batteries",
+        }
+    }
+
+    /// Message id of the name as it follows a number
+    fn noun(self) -> &'static str {
+        match self {
+            Stat::Stamina => "stat-noun-stamina",
+            Stat::Money => "stat-noun-money",
+            Stat::Bullets => "stat-noun-bullets",
+            Stat::Batteries => "stat-noun-batteries",
+        }
+    }
+
+    fn icon(self) -> &'static str {
+        match self {
+            Stat::Stamina => "{stamina}",
+            Stat::Money => "{money}",
+            Stat::Bullets => "{bullet}",
+            Stat::Batteries => "{battery}",
+        }
+    }
+
+    fn dice(self, resources: &PlayerResources) -> Option<DiceValue> {
+        match self {
+            Stat::Money => Some(resources.money),
+            Stat::Batteries => Some(resources.batteries),
+            Stat::Stamina | Stat::Bullets => None,
+        }
+    }
+
+    fn count(self, resources: &PlayerResources) -> i32 {
+        match self {
+            Stat::Stamina => resources.stamina,
+            Stat::Bullets => resources.bullets,
+            Stat::Money | Stat::Batteries => 0,
+        }
+    }
+}
+
+/// What the top bar was last drawn from
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(super) struct Shown {
+    resources: PlayerResources,
+    stamina_cap: i32,
+    exhausted: bool,
+}
+impl Shown {
+    fn of(player: &Player) -> Self {
+        Self {
+            resources: player.resources,
+            stamina_cap: player.condition.stamina_cap(),
+            exhausted: player.condition.is_exhausted(),
+        }
+    }
+
+    /// The stat as it reads on the top bar, marked up
+    fn text(&self, stat: Stat) -> String {
+        let value = match stat.dice(&self.resources) {
+            Some(dice) if dice == DiceValue::default() => "0".to_string(),
+            Some(dice) => dice.as_string(),
+            None => stat.count(&self.resources).to_string(),
+        };
+        let cap = match stat {
+            Stat::Stamina => format!("/{}", self.stamina_cap),
+            _ => String::new(),
+        };
+        let line = t!(
+            "stat-line",
+            label = tr(stat.label()),
+            value = format!("{}{}", value, cap)
+        );
+        let line = if stat == Stat::Stamina && self.exhausted {
+            format!("{} {}", line, tr("stat-exhausted"))
+        } else {
+            line
+        };
+        // Markup stays out of the locale files where it can
+        format!("{} {}", stat.icon(), line)
+    }
+
+    /// Running low enough that the player should know about it
+    fn is_low(&self, stat: Stat) -> bool {
+        let resources = &self.resources;
+        match stat {
+            Stat::Stamina => resources.stamina * LOW_STAMINA_SHARE <= self.stamina_cap,
+            Stat::Money => resources.money.theoretical_limit() < LOW_MONEY,
+            Stat::Bullets => resources.bullets <= LOW_BULLETS,
+            Stat::Batteries => resources.batteries.theoretical_limit() == 0,
+        }
+    }
+}
+
+/// How a stat changed, like "+4" or "-1d6", nothing if it didn't
+fn delta(before: &PlayerResources, after: &PlayerResources, stat: Stat) -> Option<String> {
+    let (dice, constant) = match (stat.dice(before), stat.dice(after)) {
+        (Some(before), Some(after)) => (
+            after.dice as i64 - before.dice as i64,
+            (after.constant - before.constant) as i64,
+        ),
+        _ => (0, (stat.count(after) - stat.count(before)) as i64),
+    };
+    let mut text = String::new();
+    if dice != 0 {
+        text.push_str(&format!("{:+}d6", dice));
+    }
+    if constant != 0 {
+        text.push_str(&format!("{:+}", constant));
+    }
+    (!text.is_empty()).then_some(text)
+}
+
+/// What hovering over a stat tells the player, dice come with how they could roll
+fn tooltip(resources: &PlayerResources, stat: Stat) -> String {
+    match stat.dice(resources) {
+        Some(dice) if dice.dice > 0 => t!(
+            "tooltip-dice",
+            dice = dice.as_string(),
+            stat = tr(stat.noun()),
+            lowest = dice.minimum(),
+            highest = dice.theoretical_limit(),
+            average = dice.average()
+        ),
+        Some(dice) => t!(
+            "tooltip-no-dice",
+            count = dice.theoretical_limit(),
+            stat = tr(stat.noun())
+        ),
+        None => t!(
+            "tooltip-count",
+            count = stat.count(resources),
+            stat = tr(stat.noun())
+        ),
+    }
+}
+
+// Markers
+/// Holds a stat, what floats off it and its tooltip
+#[derive(Debug, Component)]
+pub struct StatBox(pub Stat);
+#[derive(Debug, Component)]
+pub struct StatText(pub Stat);
+#[derive(Debug, Component)]
+pub struct StatTooltip;
+
+/// A change floating away from the stat it happened to
+#[derive(Debug, Component)]
+pub(super) struct FloatingDelta {
+    age: f32,
+    color: Color,
+}
+
+/// Redraws the stats when they change, and floats off what changed
+pub(super) fn update_stats(
+    mut commands: Commands,
+    (player, assets, layout): (Res<Player>, Res<AssetHandles>, Res<UiLayout>),
+    language: Res<Language>,
+    mut shown: Local<Option<Shown>>,
+    boxes: Query<(Entity, &StatBox)>,
+    mut texts: Query<(&StatText, &mut Text)>,
+) {
+    // Switching the language or resizing the window redraws the same stats
+    let redraw = language.is_changed() || layout.is_changed();
+    if !player.is_changed() && !redraw {
+        return;
+    }
+    let now = Shown::of(&player);
+    if *shown == Some(now) && !redraw {
+        return;
+    }
+    for (StatText(stat), mut text) in texts.iter_mut() {
+        let color = if now.is_low(*stat) {
+            assets.colors.crucial_text
+        } else {
+            assets.colors.basic_text
+        };
+        text.sections = marked_up(&assets, &layout, &now.text(*stat), color);
+    }
+    if let Some(before) = *shown {
+        for (entity, StatBox(stat)) in boxes.iter() {
+            let change = match delta(&before.resources, &now.resources, *stat) {
+                Some(change) => change,
+                None => continue,
+            };
+            let color = if change.starts_with('-') {
+                assets.colors.crucial_text
+            } else {
+                assets.colors.green_text
+            };
+            commands.entity(entity).with_children(|stat_box| {
+                stat_box
+                    .spawn_bundle(TextBundle {
+                        style: Style {
+                            position_type: PositionType::Absolute,
+                            position: Rect {
+                                top: Val::Px(layout.top_bar_height),
+                                left: Val::Px(0.0),
+                                ..default()
+                            },
+                            ..default()
+                        },
+                        text: Text::with_section(
+                            change,
+                            TextStyle {
+                                font: assets.font.clone(),
+                                font_size: layout.font_size,
+                                color,
+                            },
+                            default(),
+                        ),
+                        ..default()
+                    })
+                    .insert(FloatingDelta { age: 0.0, color });
+            });
+        }
+    }
+    *shown = Some(now);
+}
+
+pub(super) fn float_deltas(
+    mut commands: Commands,
+    (time, layout): (Res<Time>, Res<UiLayout>),
+    mut deltas: Query<(Entity, &mut FloatingDelta, &mut Style, &mut Text)>,
+) {
+    for (entity, mut delta, mut style, mut text) in deltas.iter_mut() {
+        delta.age += time.delta_seconds();
+        if delta.age >= DELTA_LIFETIME {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        let progress = delta.age / DELTA_LIFETIME;
+        style.position.top = Val::Px(layout.top_bar_height + progress * DELTA_DRIFT * layout.scale);
+        let mut color = delta.color;
+        color.set_a(1.0 - progress);
+        text.sections[0].style.color = color;
+    }
+}
+
+/// Shows what a stat could roll to while it's hovered over
+pub(super) fn show_tooltips(
+    player: Res<Player>,
+    boxes: Query<(&StatBox, &Interaction, &Children)>,
+    mut tooltips: Query<(&mut Style, &Children), With<StatTooltip>>,
+    mut texts: Query<&mut Text>,
+) {
+    for (StatBox(stat), interaction, children) in boxes.iter() {
+        let hovered = *interaction != Interaction::None;
+        let display = if hovered {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        for child in children.iter() {
+            let (mut style, lines) = match tooltips.get_mut(*child) {
+                Ok(tooltip) => tooltip,
+                Err(_) => continue,
+            };
+            if style.display != display {
+                style.display = display;
+            }
+            if !hovered {
+                continue;
+            }
+            let tip = tooltip(&player.resources, *stat);
+            for line in lines.iter() {
+                if let Ok(mut text) = texts.get_mut(*line) {
+                    if text.sections[0].value != tip {
+                        text.sections[0].value = tip.clone();
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{delta, tooltip, Shown, Stat};
+    use crate::player::PlayerResources;
+
+    fn resources(stamina: i32, money: &str, bullets: i32, batteries: &str) -> PlayerResources {
+        PlayerResources {
+            stamina,
+            money: money.into(),
+            bullets,
+            batteries: batteries.into(),
+        }
+    }
+
+    #[test]
+    fn changes_read_with_their_sign() {
+        let before = resources(10, "2d6+3", 6, "1d6");
+        let after = resources(14, "1d6+1", 6, "2d6");
+        assert_eq!(
+            delta(&before, &after, Stat::Stamina),
+            Some("+4".to_string())
+        );
+        assert_eq!(
+            delta(&before, &after, Stat::Money),
+            Some("-1d6-2".to_string())
+        );
+        assert_eq!(delta(&before, &after, Stat::Bullets), None);
+        assert_eq!(
+            delta(&before, &after, Stat::Batteries),
+            Some("+1d6".to_string())
+        );
+    }
+
+    #[test]
+    fn tooltips_give_the_range() {
+        let resources = resources(10, "2d6+3", 6, "");
+        assert_eq!(
+            tooltip(&resources, Stat::Money),
+            "2d6+3 money: anywhere from 5 to 15, 10 on average"
+        );
+        assert_eq!(
+            tooltip(&resources, Stat::Batteries),
+            "0 batteries, no dice to roll"
+        );
+        assert_eq!(tooltip(&resources, Stat::Bullets), "6 bullets");
+    }
+
+    #[test]
+    fn running_low_is_flagged() {
+        let shown = Shown {
+            resources: resources(3, "1", 2, "1d6"),
+            stamina_cap: 12,
+            exhausted: true,
+        };
+        assert!(shown.is_low(Stat::Stamina));
+        assert!(shown.is_low(Stat::Money));
+        assert!(shown.is_low(Stat::Bullets));
+        assert!(!shown.is_low(Stat::Batteries));
+        assert_eq!(
+            shown.text(Stat::Stamina),
+            "{stamina} Stamina: 3/12 (exhausted)"
+        );
+        assert_eq!(shown.text(Stat::Batteries), "{battery} Batteries: 1d6");
+    }
+}